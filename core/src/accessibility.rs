@@ -0,0 +1,190 @@
+//! Describe widgets to assistive technologies, like screen readers.
+
+/// The description of a widget for assistive technologies.
+///
+/// Widgets can report a [`Node`] when operated on with
+/// [`Operation::accessible`].
+///
+/// [`Operation::accessible`]: crate::widget::Operation::accessible
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The [`Role`] of the widget.
+    pub role: Role,
+
+    /// The label of the widget, if any.
+    pub label: Option<String>,
+
+    /// The current [`Value`] of the widget, if any.
+    pub value: Option<Value>,
+
+    /// Whether the widget is toggled on, if it can be toggled.
+    pub toggled: Option<bool>,
+
+    /// Whether the widget is expanded, if it can be expanded.
+    pub expanded: Option<bool>,
+
+    /// Whether the widget is disabled.
+    pub is_disabled: bool,
+
+    /// Whether the widget is focused.
+    pub is_focused: bool,
+
+    /// The actions supported by the widget.
+    pub actions: Vec<Action>,
+}
+
+impl Node {
+    /// Creates a new [`Node`] with the given [`Role`].
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            label: None,
+            value: None,
+            toggled: None,
+            expanded: None,
+            is_disabled: false,
+            is_focused: false,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Sets the label of the [`Node`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the [`Value`] of the [`Node`].
+    pub fn value(mut self, value: impl Into<Value>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets whether the [`Node`] is toggled on.
+    pub fn toggled(mut self, toggled: bool) -> Self {
+        self.toggled = Some(toggled);
+        self
+    }
+
+    /// Sets whether the [`Node`] is expanded.
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = Some(expanded);
+        self
+    }
+
+    /// Sets whether the [`Node`] is disabled.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets whether the [`Node`] is focused.
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
+        self
+    }
+
+    /// Adds an [`Action`] supported by the [`Node`].
+    pub fn action(mut self, action: Action) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Returns whether the [`Node`] supports the given [`Action`].
+    pub fn supports(&self, action: Action) -> bool {
+        !self.is_disabled && self.actions.contains(&action)
+    }
+}
+
+/// The role of a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A widget that can be clicked to trigger some action.
+    Button,
+
+    /// A widget that can be checked and unchecked.
+    CheckBox,
+
+    /// A widget that can be toggled on and off.
+    Switch,
+
+    /// A widget that can be used to edit a single line of text.
+    TextInput,
+
+    /// A widget that selects a value in a range.
+    Slider,
+
+    /// A widget that selects an option from a list.
+    ComboBox,
+
+    /// A widget that displays some text.
+    Label,
+
+    /// A widget that can be scrolled.
+    ScrollView,
+}
+
+/// The value of a widget.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Some text.
+    Text(String),
+
+    /// A number in a range.
+    Numeric {
+        /// The current value.
+        value: f64,
+
+        /// The minimum value.
+        min: f64,
+
+        /// The maximum value.
+        max: f64,
+
+        /// The step between values.
+        step: f64,
+    },
+}
+
+impl From<String> for Value {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+/// An action that assistive technologies can request a widget to perform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Clicks the widget.
+    Click,
+
+    /// Focuses the widget.
+    Focus,
+
+    /// Replaces the [`Value`] of the widget.
+    SetValue,
+
+    /// Increments the [`Value`] of the widget by one step.
+    Increment,
+
+    /// Decrements the [`Value`] of the widget by one step.
+    Decrement,
+
+    /// Scrolls the contents of the widget up.
+    ScrollUp,
+
+    /// Scrolls the contents of the widget down.
+    ScrollDown,
+
+    /// Scrolls the contents of the widget left.
+    ScrollLeft,
+
+    /// Scrolls the contents of the widget right.
+    ScrollRight,
+}
@@ -0,0 +1,718 @@
+//! Animate values over time with easing curves or spring physics.
+//!
+//! An [`Animation`] is a plain value that lives in your application state (or
+//! in the internal state of a widget). It remembers where it started, where it
+//! is going and when it started moving; you can ask for its current value at
+//! any [`Instant`].
+//!
+//! # Example
+//! ```
+//! use iced_core::animation::{Animation, Easing};
+//! use iced_core::time::{Duration, Instant};
+//!
+//! let now = Instant::now();
+//!
+//! let opacity = Animation::new(0.0)
+//!     .duration(Duration::from_millis(300))
+//!     .easing(Easing::EaseOut)
+//!     .go(1.0, now);
+//!
+//! assert_eq!(opacity.value(now), 0.0);
+//! assert!(opacity.is_animating(now));
+//!
+//! let later = now + Duration::from_millis(300);
+//!
+//! assert_eq!(opacity.value(later), 1.0);
+//! assert!(!opacity.is_animating(later));
+//! ```
+//!
+//! # Redrawing
+//! A widget can keep an [`Animation`] in its state and request new frames
+//! only while it is moving:
+//!
+//! ```ignore
+//! Event::Window(window::Event::RedrawRequested(now)) => {
+//!     if let Some(request) = state.animation.redraw_request(now) {
+//!         shell.request_redraw(request);
+//!     }
+//! }
+//! ```
+//!
+//! Similarly, an application can subscribe to `window::frames` only while
+//! [`Animation::is_animating`] returns `true`.
+use crate::border;
+use crate::time::{Duration, Instant};
+use crate::window;
+use crate::{
+    Background, Border, Color, Degrees, Point, Radians, Rectangle, Shadow,
+    Size, Vector,
+};
+
+/// A value that transitions smoothly to a target over time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    started_at: Option<Instant>,
+    transition: Transition,
+    momentum: Option<T>,
+}
+
+impl<T> Animation<T>
+where
+    T: Interpolable + PartialEq,
+{
+    /// The default duration of a tween.
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+    /// The time step used to sample the velocity of an interrupted
+    /// [`Spring`].
+    const MOMENTUM_STEP: Duration = Duration::from_millis(1);
+
+    /// Creates a new [`Animation`] resting at the given value.
+    ///
+    /// By default, it will tween for [`DEFAULT_DURATION`] with
+    /// [`Easing::EaseInOut`].
+    ///
+    /// [`DEFAULT_DURATION`]: Self::DEFAULT_DURATION
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value.clone(),
+            to: value,
+            started_at: None,
+            transition: Transition::Tween {
+                duration: Self::DEFAULT_DURATION,
+                easing: Easing::default(),
+            },
+            momentum: None,
+        }
+    }
+
+    /// Sets the duration of the [`Animation`], turning it into a tween.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.transition = match self.transition {
+            Transition::Tween { easing, .. } => {
+                Transition::Tween { duration, easing }
+            }
+            Transition::Spring(_) => Transition::Tween {
+                duration,
+                easing: Easing::default(),
+            },
+        };
+        self
+    }
+
+    /// Sets the [`Easing`] of the [`Animation`], turning it into a tween.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.transition = match self.transition {
+            Transition::Tween { duration, .. } => {
+                Transition::Tween { duration, easing }
+            }
+            Transition::Spring(_) => Transition::Tween {
+                duration: Self::DEFAULT_DURATION,
+                easing,
+            },
+        };
+        self
+    }
+
+    /// Makes the [`Animation`] follow the physics of the given [`Spring`]
+    /// instead of tweening.
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.transition = Transition::Spring(spring);
+        self
+    }
+
+    /// Starts moving the [`Animation`] towards the given target at the given
+    /// [`Instant`].
+    pub fn go(mut self, target: T, at: Instant) -> Self {
+        self.go_mut(target, at);
+        self
+    }
+
+    /// Starts moving the [`Animation`] towards the given target at the given
+    /// [`Instant`], in place.
+    ///
+    /// If the [`Animation`] is already moving towards the target, nothing
+    /// happens. Otherwise, it will start from its current value.
+    ///
+    /// A [`Spring`] keeps its current velocity; it smoothly blends out of its
+    /// previous motion instead of restarting from rest.
+    pub fn go_mut(&mut self, target: T, at: Instant) {
+        if self.to == target {
+            return;
+        }
+
+        // The interrupted motion is collapsed into its current value and
+        // velocity, so retargeting every frame never piles up state
+        let momentum = (matches!(self.transition, Transition::Spring(_))
+            && self.is_animating(at))
+        .then(|| self.value(at + Self::MOMENTUM_STEP));
+
+        self.from = self.value(at);
+        self.momentum = momentum;
+        self.to = target;
+        self.started_at = Some(at);
+    }
+
+    /// Returns the value of the [`Animation`] at the given [`Instant`].
+    pub fn value(&self, at: Instant) -> T {
+        let Some(started_at) = self.started_at else {
+            return self.to.clone();
+        };
+
+        let elapsed = at.saturating_duration_since(started_at);
+
+        if elapsed >= self.transition.duration() {
+            return self.to.clone();
+        }
+
+        let progress = self.transition.progress(elapsed);
+
+        match &self.momentum {
+            // The interrupted motion keeps its velocity and the new one starts
+            // at rest, so blending them carries the velocity over
+            Some(momentum) => self
+                .from
+                .interpolate(
+                    momentum,
+                    elapsed.as_secs_f32() / Self::MOMENTUM_STEP.as_secs_f32(),
+                )
+                .interpolate(&self.to, progress),
+            None => self.from.interpolate(&self.to, progress),
+        }
+    }
+
+    /// Returns the target value of the [`Animation`].
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Returns whether the [`Animation`] is still moving at the given
+    /// [`Instant`].
+    pub fn is_animating(&self, at: Instant) -> bool {
+        self.started_at.is_some_and(|started_at| {
+            at.saturating_duration_since(started_at)
+                < self.transition.duration()
+        })
+    }
+
+    /// Returns the [`window::RedrawRequest`] needed to keep the
+    /// [`Animation`] moving at the given [`Instant`], if any.
+    pub fn redraw_request(&self, at: Instant) -> Option<window::RedrawRequest> {
+        self.is_animating(at)
+            .then_some(window::RedrawRequest::NextFrame)
+    }
+}
+
+/// The way an [`Animation`] moves towards its target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transition {
+    /// Moves over a fixed duration following an [`Easing`] curve.
+    Tween {
+        /// The duration of the tween.
+        duration: Duration,
+
+        /// The [`Easing`] curve of the tween.
+        easing: Easing,
+    },
+
+    /// Follows the physics of a [`Spring`].
+    Spring(Spring),
+}
+
+impl Transition {
+    fn duration(&self) -> Duration {
+        match self {
+            Self::Tween { duration, .. } => *duration,
+            Self::Spring(spring) => spring.settling_time(),
+        }
+    }
+
+    fn progress(&self, elapsed: Duration) -> f32 {
+        match self {
+            Self::Tween { duration, easing } => {
+                easing.apply(elapsed.as_secs_f32() / duration.as_secs_f32())
+            }
+            Self::Spring(spring) => spring.progress(elapsed.as_secs_f32()),
+        }
+    }
+}
+
+/// An easing curve, mapping linear progress to eased progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Starts slow and accelerates.
+    EaseIn,
+
+    /// Starts fast and decelerates.
+    EaseOut,
+
+    /// Starts slow, accelerates and then decelerates.
+    #[default]
+    EaseInOut,
+
+    /// Pulls back slightly before accelerating.
+    EaseInBack,
+
+    /// Overshoots the target slightly before settling.
+    EaseOutBack,
+}
+
+impl Easing {
+    /// Applies the [`Easing`] to the given linear progress, in `[0, 1]`.
+    pub fn apply(self, t: f32) -> f32 {
+        const BACK: f32 = 1.70158;
+
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Self::EaseInBack => (BACK + 1.0) * t * t * t - BACK * t * t,
+            Self::EaseOutBack => {
+                let t = t - 1.0;
+
+                1.0 + (BACK + 1.0) * t * t * t + BACK * t * t
+            }
+        }
+    }
+}
+
+/// The physical properties of a spring.
+///
+/// Springs have no fixed duration; they move until they settle at their
+/// target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// The stiffness of the spring. Stiffer springs move faster.
+    pub stiffness: f32,
+
+    /// The damping of the spring. Springs with less damping oscillate
+    /// more.
+    pub damping: f32,
+
+    /// The mass attached to the spring. Heavier masses move slower.
+    pub mass: f32,
+}
+
+impl Spring {
+    /// A balanced spring with no noticeable bounce.
+    pub const DEFAULT: Self = Self {
+        stiffness: 170.0,
+        damping: 26.0,
+        mass: 1.0,
+    };
+
+    /// A slow spring with a gentle bounce.
+    pub const GENTLE: Self = Self {
+        stiffness: 120.0,
+        damping: 14.0,
+        mass: 1.0,
+    };
+
+    /// A bouncy spring.
+    pub const WOBBLY: Self = Self {
+        stiffness: 180.0,
+        damping: 12.0,
+        mass: 1.0,
+    };
+
+    /// A fast spring with little bounce.
+    pub const STIFF: Self = Self {
+        stiffness: 210.0,
+        damping: 20.0,
+        mass: 1.0,
+    };
+
+    /// The distance to the target, relative to the total distance, under
+    /// which a [`Spring`] is considered settled.
+    const THRESHOLD: f32 = 0.001;
+
+    /// The maximum time a [`Spring`] may take to settle.
+    const MAX_SETTLING_TIME: Duration = Duration::from_secs(60);
+
+    /// Returns the progress of the [`Spring`] towards its target after the
+    /// given amount of seconds, starting at rest.
+    ///
+    /// The result may go beyond `1.0` when the spring overshoots.
+    fn progress(&self, t: f32) -> f32 {
+        let (omega, zeta) = self.parameters();
+
+        // Displacement from the target, starting at -1 with no velocity
+        let displacement = if zeta < 1.0 {
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+
+            (-zeta * omega * t).exp()
+                * (-(omega_d * t).cos()
+                    - (zeta * omega / omega_d) * (omega_d * t).sin())
+        } else if zeta == 1.0 {
+            -(-omega * t).exp() * (1.0 + omega * t)
+        } else {
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+
+            let c2 = r1 / (r2 - r1);
+            let c1 = -1.0 - c2;
+
+            c1 * (r1 * t).exp() + c2 * (r2 * t).exp()
+        };
+
+        1.0 + displacement
+    }
+
+    /// Returns the time it takes for the [`Spring`] to settle.
+    fn settling_time(&self) -> Duration {
+        let (omega, zeta) = self.parameters();
+
+        if !omega.is_finite() || omega <= 0.0 || zeta <= 0.0 {
+            return Duration::ZERO;
+        }
+
+        // Bound the displacement by an exponential envelope and solve for the
+        // time it takes to fall under the threshold
+        let (amplitude, decay) = if zeta < 1.0 {
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+
+            (
+                (1.0 + (zeta * omega / omega_d).powi(2)).sqrt(),
+                zeta * omega,
+            )
+        } else if zeta == 1.0 {
+            (1.22, omega / 2.0)
+        } else {
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+            let c2 = r1 / (r2 - r1);
+
+            ((1.0 + c2).abs() + c2.abs(), -r1)
+        };
+
+        let seconds = ((amplitude / Self::THRESHOLD).ln() / decay).max(0.0);
+
+        // Springs with almost no damping may take forever to settle
+        Duration::from_secs_f32(
+            seconds.min(Self::MAX_SETTLING_TIME.as_secs_f32()),
+        )
+    }
+
+    fn parameters(&self) -> (f32, f32) {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping / (2.0 * (self.stiffness * self.mass).sqrt());
+
+        (omega, zeta)
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A value that can be interpolated.
+pub trait Interpolable: Clone {
+    /// Interpolates between `self` and `other` by the given `ratio`.
+    ///
+    /// A `ratio` of `0.0` produces `self` and a `ratio` of `1.0` produces
+    /// `other`. A [`Spring`] may produce ratios outside of this range when it
+    /// overshoots.
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self;
+}
+
+impl Interpolable for f32 {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        self + (other - self) * ratio
+    }
+}
+
+impl Interpolable for Color {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        let channel = |a: f32, b: f32| a.interpolate(&b, ratio).clamp(0.0, 1.0);
+
+        Color {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+            a: channel(self.a, other.a),
+        }
+    }
+}
+
+impl Interpolable for Point {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, ratio),
+            self.y.interpolate(&other.y, ratio),
+        )
+    }
+}
+
+impl Interpolable for Vector {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, ratio),
+            self.y.interpolate(&other.y, ratio),
+        )
+    }
+}
+
+impl Interpolable for Size {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, ratio),
+            self.height.interpolate(&other.height, ratio),
+        )
+    }
+}
+
+impl Interpolable for Rectangle {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Rectangle::new(
+            self.position().interpolate(&other.position(), ratio),
+            self.size().interpolate(&other.size(), ratio),
+        )
+    }
+}
+
+impl Interpolable for Radians {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Radians(self.0.interpolate(&other.0, ratio))
+    }
+}
+
+impl Interpolable for Degrees {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Degrees(self.0.interpolate(&other.0, ratio))
+    }
+}
+
+impl Interpolable for Background {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        match (self, other) {
+            (Self::Color(a), Self::Color(b)) => {
+                Self::Color(a.interpolate(b, ratio))
+            }
+            _ if ratio < 0.5 => *self,
+            _ => *other,
+        }
+    }
+}
+
+impl Interpolable for border::Radius {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        border::Radius {
+            top_left: self.top_left.interpolate(&other.top_left, ratio),
+            top_right: self.top_right.interpolate(&other.top_right, ratio),
+            bottom_right: self
+                .bottom_right
+                .interpolate(&other.bottom_right, ratio),
+            bottom_left: self
+                .bottom_left
+                .interpolate(&other.bottom_left, ratio),
+        }
+    }
+}
+
+impl Interpolable for Border {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Border {
+            color: self.color.interpolate(&other.color, ratio),
+            width: self.width.interpolate(&other.width, ratio).max(0.0),
+            radius: self.radius.interpolate(&other.radius, ratio),
+        }
+    }
+}
+
+impl Interpolable for Shadow {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Shadow {
+            color: self.color.interpolate(&other.color, ratio),
+            offset: self.offset.interpolate(&other.offset, ratio),
+            blur_radius: self
+                .blur_radius
+                .interpolate(&other.blur_radius, ratio)
+                .max(0.0),
+        }
+    }
+}
+
+impl<T> Interpolable for Option<T>
+where
+    T: Interpolable,
+{
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, ratio)),
+            _ if ratio < 0.5 => self.clone(),
+            _ => other.clone(),
+        }
+    }
+}
+
+impl<A, B> Interpolable for (A, B)
+where
+    A: Interpolable,
+    B: Interpolable,
+{
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        (
+            self.0.interpolate(&other.0, ratio),
+            self.1.interpolate(&other.1, ratio),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tween() {
+        let now = Instant::now();
+        let animation = Animation::new(0.0)
+            .duration(Duration::from_millis(100))
+            .easing(Easing::Linear)
+            .go(10.0, now);
+
+        assert_eq!(animation.value(now), 0.0);
+        assert_eq!(animation.value(now + Duration::from_millis(50)), 5.0);
+        assert_eq!(animation.value(now + Duration::from_millis(100)), 10.0);
+
+        assert!(animation.is_animating(now + Duration::from_millis(99)));
+        assert!(!animation.is_animating(now + Duration::from_millis(100)));
+        assert_eq!(
+            animation.redraw_request(now),
+            Some(window::RedrawRequest::NextFrame)
+        );
+    }
+
+    #[test]
+    fn retarget() {
+        let now = Instant::now();
+        let halfway = now + Duration::from_millis(50);
+
+        let mut animation = Animation::new(0.0)
+            .duration(Duration::from_millis(100))
+            .easing(Easing::Linear)
+            .go(10.0, now);
+
+        animation.go_mut(10.0, halfway);
+        assert_eq!(animation.value(halfway), 5.0);
+
+        animation.go_mut(0.0, halfway);
+        assert_eq!(animation.value(halfway), 5.0);
+        assert_eq!(animation.value(halfway + Duration::from_millis(50)), 2.5);
+    }
+
+    #[test]
+    fn retarget_spring() {
+        let now = Instant::now();
+        let step = Duration::from_millis(1);
+        let halfway = now + Duration::from_millis(100);
+
+        let mut animation =
+            Animation::new(0.0).spring(Spring::DEFAULT).go(100.0, now);
+
+        let velocity =
+            animation.value(halfway) - animation.value(halfway - step);
+
+        animation.go_mut(0.0, halfway);
+
+        let retargeted =
+            animation.value(halfway + step) - animation.value(halfway);
+
+        assert!(velocity > 0.0);
+        assert!((retargeted - velocity).abs() < velocity * 0.1);
+        assert_eq!(animation.value(halfway + Duration::from_secs(5)), 0.0);
+    }
+
+    #[test]
+    fn retarget_spring_every_frame() {
+        let now = Instant::now();
+        let frame = Duration::from_millis(16);
+
+        let mut animation =
+            Animation::new(0.0).spring(Spring::WOBBLY).go(100.0, now);
+
+        let mut at = now;
+
+        for i in 0..10_000 {
+            at += frame;
+            animation.go_mut(if i % 2 == 0 { 0.0 } else { 100.0 }, at);
+
+            assert!((0.0..=100.0).contains(&animation.value(at)));
+        }
+
+        let settled = at + Duration::from_secs(5);
+
+        assert!(!animation.is_animating(settled));
+        assert_eq!(animation.value(settled), *animation.target());
+    }
+
+    #[test]
+    fn loose_spring_settles() {
+        let spring = Spring {
+            stiffness: 100.0,
+            damping: f32::MIN_POSITIVE,
+            mass: 1.0,
+        };
+
+        assert_eq!(spring.settling_time(), Spring::MAX_SETTLING_TIME);
+    }
+
+    #[test]
+    fn easing_bounds() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::EaseInBack,
+            Easing::EaseOutBack,
+        ] {
+            assert!(easing.apply(0.0).abs() < 1e-6, "{easing:?}");
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-6, "{easing:?}");
+        }
+    }
+
+    #[test]
+    fn spring_settles() {
+        for spring in [
+            Spring::DEFAULT,
+            Spring::GENTLE,
+            Spring::WOBBLY,
+            Spring::STIFF,
+            Spring {
+                stiffness: 100.0,
+                damping: 40.0,
+                mass: 1.0,
+            },
+        ] {
+            let settling_time = spring.settling_time();
+
+            assert!(spring.progress(0.0).abs() < 1e-6, "{spring:?}");
+            assert!(settling_time > Duration::ZERO, "{spring:?}");
+            assert!(settling_time < Duration::from_secs(5), "{spring:?}");
+
+            assert!(
+                (spring.progress(settling_time.as_secs_f32()) - 1.0).abs()
+                    < Spring::THRESHOLD,
+                "{spring:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Exchange data with other applications by dragging and dropping.
+use std::path::PathBuf;
+
+/// A piece of data dragged between applications, tagged with its MIME type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Data {
+    /// The MIME type of the [`Data`].
+    pub mime_type: String,
+    /// The raw contents of the [`Data`].
+    pub bytes: Vec<u8>,
+}
+
+impl Data {
+    /// The MIME type of UTF-8 plain text.
+    pub const TEXT: &'static str = "text/plain;charset=utf-8";
+
+    /// The MIME type of a list of URIs; used for files.
+    pub const URI_LIST: &'static str = "text/uri-list";
+
+    /// Creates some [`Data`] with the given MIME type and contents.
+    pub fn new(
+        mime_type: impl Into<String>,
+        bytes: impl Into<Vec<u8>>,
+    ) -> Self {
+        Self {
+            mime_type: mime_type.into(),
+            bytes: bytes.into(),
+        }
+    }
+
+    /// Creates some [`Data`] containing UTF-8 plain text.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::new(Self::TEXT, text.into())
+    }
+
+    /// Creates some [`Data`] containing a list of files, as a list of
+    /// `file://` URIs.
+    pub fn files(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        let uris: Vec<String> = paths
+            .into_iter()
+            .map(|path| format!("file://{}", encode(&path.to_string_lossy())))
+            .collect();
+
+        Self::new(Self::URI_LIST, uris.join("\r\n"))
+    }
+
+    /// Returns the contents of the [`Data`] as text, if it is text.
+    pub fn as_text(&self) -> Option<&str> {
+        if self.mime_type.starts_with("text/") {
+            std::str::from_utf8(&self.bytes).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the local files listed in the [`Data`], if it is a list of
+    /// URIs.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        if self.mime_type != Self::URI_LIST {
+            return Vec::new();
+        }
+
+        self.as_text()
+            .unwrap_or_default()
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| line.trim().strip_prefix("file://"))
+            .filter_map(|path| {
+                // Skip the host, if any
+                let path = &path[path.find('/')?..];
+
+                decode(path).map(PathBuf::from)
+            })
+            .collect()
+    }
+}
+
+/// Percent-encodes the characters of a path that are not allowed in a URI.
+fn encode(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+
+    encoded
+}
+
+/// Decodes a percent-encoded path.
+fn decode(path: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut input = path.bytes();
+
+    while let Some(byte) = input.next() {
+        if byte == b'%' {
+            let high = char::from(input.next()?).to_digit(16)?;
+            let low = char::from(input.next()?).to_digit(16)?;
+
+            bytes.push((high * 16 + low) as u8);
+        } else {
+            bytes.push(byte);
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn files_round_trip() {
+        let paths = vec![
+            PathBuf::from("/home/iced/hello world.txt"),
+            PathBuf::from("/tmp/100%/ñ"),
+        ];
+
+        let data = Data::files(paths.clone());
+
+        assert_eq!(data.mime_type, Data::URI_LIST);
+        assert_eq!(
+            data.as_text(),
+            Some(
+                "file:///home/iced/hello%20world.txt\r\n\
+                 file:///tmp/100%25/%C3%B1"
+            )
+        );
+        assert_eq!(data.paths(), paths);
+    }
+}
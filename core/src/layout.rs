@@ -3,6 +3,7 @@ mod limits;
 mod node;
 
 pub mod flex;
+pub mod grid;
 
 pub use limits::Limits;
 pub use node::Node;
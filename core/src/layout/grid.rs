@@ -0,0 +1,546 @@
+//! Distribute elements in a grid of rows and columns.
+use crate::Element;
+
+use crate::layout::{Limits, Node};
+use crate::widget;
+use crate::{Length, Padding, Point, Size};
+
+/// The area of a grid layout occupied by an element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Area {
+    /// The first row of the [`Area`].
+    pub row: usize,
+    /// The first column of the [`Area`].
+    pub column: usize,
+    /// The amount of rows spanned by the [`Area`].
+    pub rows: usize,
+    /// The amount of columns spanned by the [`Area`].
+    pub columns: usize,
+}
+
+/// Computes the grid layout of the given items, placing each one in its
+/// [`Area`] and applying spacing between tracks.
+///
+/// Tracks are sized in the following order:
+/// - [`Length::Fixed`] tracks take their exact size.
+/// - [`Length::Shrink`] tracks fit the items inside them. Items spanning
+///   multiple tracks grow the [`Length::Shrink`] tracks they span, if needed,
+///   unless they also span a fill track.
+/// - [`Length::Fill`] and [`Length::FillPortion`] tracks share the remaining
+///   space. If the available space is infinite, they behave like
+///   [`Length::Shrink`] tracks instead.
+///
+/// Any track not present in the given `columns` or `rows` is a
+/// [`Length::Shrink`] track.
+///
+/// It returns a new layout [`Node`].
+pub fn resolve<Message, Theme, Renderer>(
+    renderer: &Renderer,
+    limits: &Limits,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    columns: &[Length],
+    rows: &[Length],
+    column_spacing: f32,
+    row_spacing: f32,
+    areas: &[Area],
+    items: &[Element<'_, Message, Theme, Renderer>],
+    trees: &mut [widget::Tree],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.width(width).height(height).shrink(padding);
+    let max = limits.max();
+
+    let column_count = areas
+        .iter()
+        .map(|area| area.column + area.columns)
+        .max()
+        .unwrap_or(0)
+        .max(columns.len());
+
+    let row_count = areas
+        .iter()
+        .map(|area| area.row + area.rows)
+        .max()
+        .unwrap_or(0)
+        .max(rows.len());
+
+    // Every item keeps its last node and the maximum size it was laid out
+    // in, so later passes only lay it out again if the result may change
+    let mut measured: Vec<Option<(Node, Size)>> = vec![None; items.len()];
+
+    let contributions: Vec<_> = items
+        .iter()
+        .zip(trees.iter_mut())
+        .zip(areas)
+        .zip(&mut measured)
+        .map(|(((item, tree), area), measured)| {
+            // Fill items take whatever is left; they contribute nothing
+            let width = if item.as_widget().size().width.is_fill() {
+                0.0
+            } else {
+                measure(item, tree, renderer, measured, max).width
+            };
+
+            (area.column, area.columns, width)
+        })
+        .collect();
+
+    let widths = tracks(
+        columns,
+        column_count,
+        column_spacing,
+        max.width,
+        &contributions,
+    );
+
+    let contributions: Vec<_> = items
+        .iter()
+        .zip(trees.iter_mut())
+        .zip(areas)
+        .zip(&mut measured)
+        .map(|(((item, tree), area), measured)| {
+            let width =
+                span(&widths, area.column, area.columns, column_spacing);
+
+            let size = measure(
+                item,
+                tree,
+                renderer,
+                measured,
+                Size::new(width, max.height),
+            );
+
+            let height = if item.as_widget().size().height.is_fill() {
+                0.0
+            } else {
+                size.height
+            };
+
+            (area.row, area.rows, height)
+        })
+        .collect();
+
+    let heights =
+        tracks(rows, row_count, row_spacing, max.height, &contributions);
+
+    let offsets = |sizes: &[f32], start: f32, spacing: f32| {
+        sizes
+            .iter()
+            .scan(start, |offset, size| {
+                let current = *offset;
+                *offset += size + spacing;
+
+                Some(current)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let x = offsets(&widths, padding.left, column_spacing);
+    let y = offsets(&heights, padding.top, row_spacing);
+
+    let nodes = items
+        .iter()
+        .zip(trees)
+        .zip(areas)
+        .zip(&mut measured)
+        .map(|(((item, tree), area), measured)| {
+            let size = Size::new(
+                span(&widths, area.column, area.columns, column_spacing),
+                span(&heights, area.row, area.rows, row_spacing),
+            );
+
+            let _ = measure(item, tree, renderer, measured, size);
+
+            let (node, _) = measured.take().expect("Measured item");
+
+            node.move_to(Point::new(
+                x.get(area.column).copied().unwrap_or(padding.left),
+                y.get(area.row).copied().unwrap_or(padding.top),
+            ))
+        })
+        .collect();
+
+    let intrinsic = Size::new(
+        span(&widths, 0, column_count, column_spacing),
+        span(&heights, 0, row_count, row_spacing),
+    );
+
+    let size = limits.resolve(width, height, intrinsic);
+
+    Node::with_children(size.expand(padding), nodes)
+}
+
+/// Lays out the item within the given maximum size, unless its measured
+/// [`Node`] would stay the same, and returns its size.
+///
+/// An item that does not fill an axis and fits in a smaller maximum size
+/// along it is laid out exactly the same.
+fn measure<Message, Theme, Renderer>(
+    item: &Element<'_, Message, Theme, Renderer>,
+    tree: &mut widget::Tree,
+    renderer: &Renderer,
+    measured: &mut Option<(Node, Size)>,
+    max: Size,
+) -> Size
+where
+    Renderer: crate::Renderer,
+{
+    let length = item.as_widget().size();
+
+    let is_valid = |length: Length, size: f32, previous: f32, max: f32| {
+        previous == max || !length.is_fill() && size <= max && max <= previous
+    };
+
+    match measured {
+        Some((node, previous))
+            if is_valid(
+                length.width,
+                node.size().width,
+                previous.width,
+                max.width,
+            ) && is_valid(
+                length.height,
+                node.size().height,
+                previous.height,
+                max.height,
+            ) => {}
+        _ => {
+            *measured = Some((
+                item.as_widget().layout(
+                    tree,
+                    renderer,
+                    &Limits::new(Size::ZERO, max),
+                ),
+                max,
+            ));
+        }
+    }
+
+    measured
+        .as_ref()
+        .map(|(node, _)| node.size())
+        .unwrap_or_default()
+}
+
+/// Computes the sizes of the given tracks, given the contributions of the
+/// items placed in them as `(start, span, size)`.
+fn tracks(
+    lengths: &[Length],
+    count: usize,
+    spacing: f32,
+    available: f32,
+    contributions: &[(usize, usize, f32)],
+) -> Vec<f32> {
+    let length = |i: usize| lengths.get(i).copied().unwrap_or(Length::Shrink);
+
+    let is_intrinsic = |i: usize| match length(i) {
+        Length::Shrink => true,
+        Length::Fill | Length::FillPortion(_) => available.is_infinite(),
+        Length::Fixed(_) => false,
+    };
+
+    let mut sizes: Vec<f32> = (0..count)
+        .map(|i| match length(i) {
+            Length::Fixed(amount) => amount,
+            _ => 0.0,
+        })
+        .collect();
+
+    for &(start, _, size) in
+        contributions.iter().filter(|(_, span, _)| *span == 1)
+    {
+        if is_intrinsic(start) {
+            sizes[start] = sizes[start].max(size);
+        }
+    }
+
+    for &(start, tracks, size) in
+        contributions.iter().filter(|(_, span, _)| *span > 1)
+    {
+        let range = start..start + tracks;
+
+        // Items spanning fill tracks will be fitted when filling
+        if available.is_finite() && range.clone().any(|i| length(i).is_fill()) {
+            continue;
+        }
+
+        let missing = size - span(&sizes, start, tracks, spacing);
+        let intrinsic = range.filter(|i| is_intrinsic(*i));
+        let amount = intrinsic.clone().count();
+
+        if missing > 0.0 && amount > 0 {
+            for i in intrinsic {
+                sizes[i] += missing / amount as f32;
+            }
+        }
+    }
+
+    let fill_sum: u16 = (0..count).map(|i| length(i).fill_factor()).sum();
+
+    if fill_sum > 0 && available.is_finite() {
+        let used = (0..count)
+            .filter(|i| !length(*i).is_fill())
+            .map(|i| sizes[i])
+            .sum::<f32>()
+            + spacing * count.saturating_sub(1) as f32;
+
+        let remaining = (available - used).max(0.0);
+
+        for (i, size) in sizes.iter_mut().enumerate() {
+            let factor = length(i).fill_factor();
+
+            if factor != 0 {
+                *size = remaining * factor as f32 / fill_sum as f32;
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the total size of the given tracks, including spacing.
+fn span(sizes: &[f32], start: usize, tracks: usize, spacing: f32) -> f32 {
+    sizes[start..start + tracks].iter().sum::<f32>()
+        + spacing * tracks.saturating_sub(1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::layout::{self, Layout};
+    use crate::mouse;
+    use crate::renderer;
+    use crate::widget::{Tree, Widget};
+    use crate::Rectangle;
+
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// A block of the given size that counts how many times it is laid out.
+    struct Block {
+        width: Length,
+        height: Length,
+        layouts: Rc<Cell<usize>>,
+    }
+
+    impl Widget<(), (), ()> for Block {
+        fn size(&self) -> Size<Length> {
+            Size::new(self.width, self.height)
+        }
+
+        fn layout(
+            &self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            limits: &Limits,
+        ) -> Node {
+            self.layouts.set(self.layouts.get() + 1);
+
+            layout::atomic(limits, self.width, self.height)
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    /// A grid with a single cell.
+    struct Nested {
+        content: Element<'static, (), (), ()>,
+    }
+
+    impl Widget<(), (), ()> for Nested {
+        fn children(&self) -> Vec<Tree> {
+            vec![Tree::new(&self.content)]
+        }
+
+        fn size(&self) -> Size<Length> {
+            Size::new(Length::Shrink, Length::Shrink)
+        }
+
+        fn layout(
+            &self,
+            tree: &mut Tree,
+            renderer: &(),
+            limits: &Limits,
+        ) -> Node {
+            resolve(
+                renderer,
+                limits,
+                Length::Shrink,
+                Length::Shrink,
+                Padding::ZERO,
+                &[],
+                &[],
+                0.0,
+                0.0,
+                &[area(0, 0, 1, 1)],
+                std::slice::from_ref(&self.content),
+                &mut tree.children,
+            )
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn block(
+        width: impl Into<Length>,
+        height: impl Into<Length>,
+    ) -> Element<'static, (), (), ()> {
+        Element::new(Block {
+            width: width.into(),
+            height: height.into(),
+            layouts: Rc::default(),
+        })
+    }
+
+    fn area(row: usize, column: usize, rows: usize, columns: usize) -> Area {
+        Area {
+            row,
+            column,
+            rows,
+            columns,
+        }
+    }
+
+    fn grid(
+        columns: &[Length],
+        rows: &[Length],
+        items: Vec<(Area, Element<'static, (), (), ()>)>,
+    ) -> Node {
+        let (areas, items): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+
+        let mut trees: Vec<_> = items.iter().map(Tree::new).collect();
+
+        resolve(
+            &(),
+            &Limits::new(Size::ZERO, Size::new(500.0, 500.0)),
+            Length::Fill,
+            Length::Shrink,
+            Padding::ZERO,
+            columns,
+            rows,
+            10.0,
+            10.0,
+            &areas,
+            &items,
+            &mut trees,
+        )
+    }
+
+    fn bounds(node: &Node) -> Vec<Rectangle> {
+        node.children().iter().map(Node::bounds).collect()
+    }
+
+    #[test]
+    fn sizes_tracks() {
+        let node = grid(
+            &[
+                Length::Fixed(50.0),
+                Length::Shrink,
+                Length::Fill,
+                Length::FillPortion(2),
+            ],
+            &[],
+            vec![
+                (area(0, 0, 1, 1), block(20, 10)),
+                (area(0, 1, 1, 1), block(30, 10)),
+                (area(0, 2, 1, 1), block(Length::Fill, 20)),
+                (area(0, 3, 1, 1), block(Length::Fill, Length::Fill)),
+            ],
+        );
+
+        // 500 - 50 - 30 - 3 * 10 is shared by the fill tracks
+        assert_eq!(
+            bounds(&node),
+            [
+                Rectangle::new(Point::new(0.0, 0.0), Size::new(20.0, 10.0)),
+                Rectangle::new(Point::new(60.0, 0.0), Size::new(30.0, 10.0)),
+                Rectangle::new(Point::new(100.0, 0.0), Size::new(130.0, 20.0)),
+                Rectangle::new(Point::new(240.0, 0.0), Size::new(260.0, 20.0)),
+            ]
+        );
+        assert_eq!(node.size(), Size::new(500.0, 20.0));
+    }
+
+    #[test]
+    fn spans_grow_shrink_tracks() {
+        let node = grid(
+            &[],
+            &[],
+            vec![
+                (area(0, 0, 1, 1), block(30, 10)),
+                (area(0, 1, 1, 1), block(20, 10)),
+                (area(1, 0, 1, 2), block(100, 10)),
+                (area(0, 2, 2, 1), block(10, 50)),
+            ],
+        );
+
+        // The spanning items are split evenly across the tracks they span
+        assert_eq!(
+            bounds(&node),
+            [
+                Rectangle::new(Point::new(0.0, 0.0), Size::new(30.0, 10.0)),
+                Rectangle::new(Point::new(60.0, 0.0), Size::new(20.0, 10.0)),
+                Rectangle::new(Point::new(0.0, 30.0), Size::new(100.0, 10.0)),
+                Rectangle::new(Point::new(110.0, 0.0), Size::new(10.0, 50.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn spans_across_fill_tracks() {
+        let node = grid(
+            &[Length::Fixed(100.0), Length::Fill],
+            &[],
+            vec![(area(0, 0, 1, 2), block(Length::Fill, 10))],
+        );
+
+        assert_eq!(
+            bounds(&node),
+            [Rectangle::new(Point::ORIGIN, Size::new(500.0, 10.0))]
+        );
+    }
+
+    #[test]
+    fn lays_out_nested_grids_once() {
+        let layouts = Rc::new(Cell::new(0));
+
+        let mut content = Element::new(Block {
+            width: Length::Fixed(10.0),
+            height: Length::Fixed(10.0),
+            layouts: layouts.clone(),
+        });
+
+        for _ in 0..5 {
+            content = Element::new(Nested { content });
+        }
+
+        let _ = grid(&[], &[], vec![(area(0, 0, 1, 1), content)]);
+
+        assert_eq!(layouts.get(), 1);
+    }
+}
@@ -9,9 +9,12 @@
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
+pub mod accessibility;
 pub mod alignment;
+pub mod animation;
 pub mod border;
 pub mod clipboard;
+pub mod drag_and_drop;
 pub mod event;
 pub mod font;
 pub mod gradient;
@@ -48,6 +51,7 @@ mod vector;
 
 pub use alignment::Alignment;
 pub use angle::{Degrees, Radians};
+pub use animation::Animation;
 pub use background::Background;
 pub use border::Border;
 pub use clipboard::Clipboard;
@@ -3,7 +3,8 @@
 mod null;
 
 use crate::{
-    Background, Border, Color, Rectangle, Shadow, Size, Transformation, Vector,
+    Background, Border, Color, Font, Pixels, Rectangle, Shadow, Size,
+    Transformation, Vector,
 };
 
 /// A component that can be used by widgets to draw themselves on a screen.
@@ -63,6 +64,25 @@ pub trait Renderer {
     fn clear(&mut self);
 }
 
+/// A [`Renderer`] that can be created without a window.
+///
+/// Headless renderers can be used to run and draw a user interface
+/// offscreen; for instance, in tests.
+pub trait Headless {
+    /// Creates a new [`Headless`] renderer with the given default
+    /// [`Font`] and text size.
+    fn new(default_font: Font, default_text_size: Pixels) -> Self;
+
+    /// Draws the recorded primitives of the [`Headless`] renderer into an
+    /// RGBA buffer of the given physical size.
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f32,
+        background_color: Color,
+    ) -> Vec<u8>;
+}
+
 /// A polygon with four sides.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Quad {
@@ -8,6 +8,19 @@ use crate::{
     Transformation,
 };
 
+impl renderer::Headless for () {
+    fn new(_default_font: Font, _default_text_size: Pixels) -> Self {}
+
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        _scale_factor: f32,
+        _background_color: Color,
+    ) -> Vec<u8> {
+        vec![0; size.width as usize * size.height as usize * 4]
+    }
+}
+
 impl Renderer for () {
     fn start_layer(&mut self, _bounds: Rectangle) {}
 
@@ -111,6 +124,22 @@ impl text::Paragraph for () {
     fn hit_span(&self, _point: Point) -> Option<usize> {
         None
     }
+
+    fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
+        vec![]
+    }
+
+    fn span_baselines(&self, _index: usize) -> Vec<f32> {
+        vec![]
+    }
+
+    fn hit_offset(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
+    fn range_bounds(&self, _range: std::ops::Range<usize>) -> Vec<Rectangle> {
+        Vec::new()
+    }
 }
 
 impl text::Editor for () {
@@ -8,7 +8,9 @@ pub use highlighter::Highlighter;
 pub use paragraph::Paragraph;
 
 use crate::alignment;
-use crate::{Color, Pixels, Point, Rectangle, Size};
+use crate::{
+    Background, Border, Color, Padding, Pixels, Point, Rectangle, Size,
+};
 
 use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
@@ -39,6 +41,17 @@ pub struct Text<Content = String, Font = crate::Font> {
 
     /// The [`Shaping`] strategy of the [`Text`].
     pub shaping: Shaping,
+
+    /// The [`Wrapping`] strategy of the [`Text`].
+    pub wrapping: Wrapping,
+
+    /// The [`Ellipsis`] strategy of the [`Text`].
+    pub ellipsis: Ellipsis,
+
+    /// The maximum amount of lines of the [`Text`], if any.
+    ///
+    /// Lines past the limit are not displayed.
+    pub max_lines: Option<usize>,
 }
 
 /// The shaping strategy of some text.
@@ -65,6 +78,40 @@ pub enum Shaping {
     Advanced,
 }
 
+/// The wrapping strategy of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Wrapping {
+    /// No wrapping.
+    None,
+    /// Wraps at the word level.
+    ///
+    /// This is the default.
+    #[default]
+    Word,
+    /// Wraps at the glyph level.
+    Glyph,
+    /// Wraps at the word level, or falls back to the glyph level if a word
+    /// can't fit on a line by itself.
+    WordOrGlyph,
+}
+
+/// The truncation strategy of some text that overflows its bounds or its
+/// maximum amount of lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Ellipsis {
+    /// No truncation; the overflowing text is clipped.
+    ///
+    /// This is the default.
+    #[default]
+    None,
+    /// The beginning of the text is replaced with an ellipsis.
+    Start,
+    /// The middle of the text is replaced with an ellipsis.
+    Middle,
+    /// The end of the text is replaced with an ellipsis.
+    End,
+}
+
 /// The height of a line of text in a paragraph.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LineHeight {
@@ -237,6 +284,34 @@ pub struct Span<'a, Link = (), Font = crate::Font> {
     pub color: Option<Color>,
     /// The link of the [`Span`].
     pub link: Option<Link>,
+    /// The [`Color`] of the [`Span`] while its link is hovered.
+    ///
+    /// Defaults to the color of the [`Span`].
+    pub hovered_color: Option<Color>,
+    /// The [`Highlight`] of the [`Span`].
+    pub highlight: Option<Highlight>,
+    /// The [`Padding`] of the [`Span`].
+    ///
+    /// Currently, it only affects the bounds of the [`Highlight`]; it does
+    /// not take any space in the layout of the text.
+    pub padding: Padding,
+    /// Whether the [`Span`] should be underlined or not.
+    pub underline: bool,
+    /// Whether the [`Span`] should be struck through or not.
+    pub strikethrough: bool,
+    /// The [`Color`] of the underline and the strikethrough of the [`Span`].
+    ///
+    /// Defaults to the color of the text.
+    pub decoration_color: Option<Color>,
+}
+
+/// A text highlight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Highlight {
+    /// The [`Background`] of the highlight.
+    pub background: Background,
+    /// The [`Border`] of the highlight.
+    pub border: Border,
 }
 
 impl<'a, Link, Font> Span<'a, Link, Font> {
@@ -249,6 +324,12 @@ impl<'a, Link, Font> Span<'a, Link, Font> {
             font: None,
             color: None,
             link: None,
+            hovered_color: None,
+            highlight: None,
+            padding: Padding::ZERO,
+            underline: false,
+            strikethrough: false,
+            decoration_color: None,
         }
     }
 
@@ -300,6 +381,98 @@ impl<'a, Link, Font> Span<'a, Link, Font> {
         self
     }
 
+    /// Sets the [`Color`] of the [`Span`] while its link is hovered.
+    pub fn hovered_color(mut self, color: impl Into<Color>) -> Self {
+        self.hovered_color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Background`] of the [`Span`].
+    pub fn background(self, background: impl Into<Background>) -> Self {
+        self.background_maybe(Some(background))
+    }
+
+    /// Sets the [`Background`] of the [`Span`], if any.
+    pub fn background_maybe(
+        mut self,
+        background: Option<impl Into<Background>>,
+    ) -> Self {
+        let Some(background) = background else {
+            return self;
+        };
+
+        match &mut self.highlight {
+            Some(highlight) => {
+                highlight.background = background.into();
+            }
+            None => {
+                self.highlight = Some(Highlight {
+                    background: background.into(),
+                    border: Border::default(),
+                });
+            }
+        }
+
+        self
+    }
+
+    /// Sets the [`Border`] of the [`Span`].
+    pub fn border(self, border: impl Into<Border>) -> Self {
+        self.border_maybe(Some(border))
+    }
+
+    /// Sets the [`Border`] of the [`Span`], if any.
+    pub fn border_maybe(mut self, border: Option<impl Into<Border>>) -> Self {
+        let Some(border) = border else {
+            return self;
+        };
+
+        match &mut self.highlight {
+            Some(highlight) => {
+                highlight.border = border.into();
+            }
+            None => {
+                self.highlight = Some(Highlight {
+                    border: border.into(),
+                    background: Background::Color(Color::TRANSPARENT),
+                });
+            }
+        }
+
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Span`].
+    ///
+    /// It only grows the bounds of its [`Highlight`]; it does not take any
+    /// space in the layout of the text. Therefore, a padded highlight
+    /// overlaps any neighboring text and may overflow the bounds of the
+    /// paragraph. Leave room for it with some spaces in the text of the
+    /// neighboring spans or some padding around the paragraph.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets whether the [`Span`] should be underlined or not.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets whether the [`Span`] should be struck through or not.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    /// Sets the [`Color`] of the underline and the strikethrough of the
+    /// [`Span`].
+    pub fn decoration_color(mut self, color: impl Into<Color>) -> Self {
+        self.decoration_color = Some(color.into());
+        self
+    }
+
     /// Turns the [`Span`] into a static one.
     pub fn to_static(self) -> Span<'static, Link, Font> {
         Span {
@@ -309,6 +482,12 @@ impl<'a, Link, Font> Span<'a, Link, Font> {
             font: self.font,
             color: self.color,
             link: self.link,
+            hovered_color: self.hovered_color,
+            highlight: self.highlight,
+            padding: self.padding,
+            underline: self.underline,
+            strikethrough: self.strikethrough,
+            decoration_color: self.decoration_color,
         }
     }
 }
@@ -326,6 +505,12 @@ impl<'a, Link, Font: PartialEq> PartialEq for Span<'a, Link, Font> {
             && self.line_height == other.line_height
             && self.font == other.font
             && self.color == other.color
+            && self.hovered_color == other.hovered_color
+            && self.highlight == other.highlight
+            && self.padding == other.padding
+            && self.underline == other.underline
+            && self.strikethrough == other.strikethrough
+            && self.decoration_color == other.decoration_color
     }
 }
 
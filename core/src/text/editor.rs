@@ -83,12 +83,18 @@ pub enum Action {
         /// The amount of lines to scroll.
         lines: i32,
     },
+    /// Undo the last [`Edit`].
+    Undo,
+    /// Redo the last undone [`Edit`].
+    Redo,
 }
 
 impl Action {
     /// Returns whether the [`Action`] is an editing action.
+    ///
+    /// Undoing and redoing are editing actions, since they change the text.
     pub fn is_edit(&self) -> bool {
-        matches!(self, Self::Edit(_))
+        matches!(self, Self::Edit(_) | Self::Undo | Self::Redo)
     }
 }
 
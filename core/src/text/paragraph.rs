@@ -1,7 +1,9 @@
 //! Draw paragraphs.
 use crate::alignment;
 use crate::text::{Difference, Hit, Span, Text};
-use crate::{Point, Size};
+use crate::{Point, Rectangle, Size};
+
+use std::ops::Range;
 
 /// A text paragraph.
 pub trait Paragraph: Sized + Default {
@@ -42,9 +44,29 @@ pub trait Paragraph: Sized + Default {
     /// that was hit.
     fn hit_span(&self, point: Point) -> Option<usize>;
 
+    /// Returns all bounds for the provided [`Span`] index of the
+    /// [`Paragraph`]; one for each visual line covered by it.
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
+
+    /// Returns the vertical position of the baseline of each bounds returned
+    /// by [`Paragraph::span_bounds`] for the same [`Span`] index.
+    fn span_baselines(&self, index: usize) -> Vec<f32>;
+
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
+    /// Tests whether the provided point is within the boundaries of the
+    /// [`Paragraph`], returning the byte offset of the nearest cursor
+    /// position in its text.
+    ///
+    /// The text of a [`Paragraph`] with many [`Span`]s is the concatenation
+    /// of the text of all of them.
+    fn hit_offset(&self, point: Point) -> Option<usize>;
+
+    /// Returns the bounds of the regions of each visual line covered by the
+    /// text in the given range of byte offsets.
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle>;
+
     /// Returns the minimum width that can fit the contents of the [`Paragraph`].
     fn min_width(&self) -> f32 {
         self.min_bounds().width
@@ -91,6 +113,9 @@ impl<P: Paragraph> Plain<P> {
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            wrapping: text.wrapping,
+            ellipsis: text.ellipsis,
+            max_lines: text.max_lines,
         }) {
             Difference::None => {}
             Difference::Bounds => {
@@ -1,12 +1,15 @@
 //! Query or update internal widget state.
 pub mod focusable;
 pub mod scrollable;
+pub mod selector;
 pub mod text_input;
 
 pub use focusable::Focusable;
 pub use scrollable::Scrollable;
+pub use selector::Selector;
 pub use text_input::TextInput;
 
+use crate::accessibility;
 use crate::widget::Id;
 use crate::{Rectangle, Vector};
 
@@ -31,6 +34,18 @@ pub trait Operation<T>: Send {
     /// Operates on a widget that can be focused.
     fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
 
+    /// Operates on a widget that can be focused, given its bounds.
+    ///
+    /// By default, it calls [`focusable`](Self::focusable).
+    fn focusable_with_bounds(
+        &mut self,
+        state: &mut dyn Focusable,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+        self.focusable(state, id);
+    }
+
     /// Operates on a widget that can be scrolled.
     fn scrollable(
         &mut self,
@@ -44,6 +59,40 @@ pub trait Operation<T>: Send {
     /// Operates on a widget that has text input.
     fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
 
+    /// Operates on a widget that has text input, given its bounds.
+    ///
+    /// By default, it calls [`text_input`](Self::text_input).
+    fn text_input_with_bounds(
+        &mut self,
+        state: &mut dyn TextInput,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+        self.text_input(state, id);
+    }
+
+    /// Operates on a widget that displays some text.
+    fn text(&mut self, _id: Option<&Id>, _bounds: Rectangle, _text: &str) {}
+
+    /// Operates on a widget that describes itself to assistive technologies.
+    ///
+    /// The `node` function produces the description of the widget. It is
+    /// only worth calling when the [`Operation`] needs it.
+    ///
+    /// The `operate_on_children` function can be called to return control to
+    /// the widget tree and keep traversing the contents of the widget, if any.
+    ///
+    /// By default, the widget is treated as a [`container`](Self::container).
+    fn accessible(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        _node: &dyn Fn() -> accessibility::Node,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.container(id, bounds, operate_on_children);
+    }
+
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
 
@@ -93,66 +142,94 @@ where
         f: Arc<dyn Fn(A) -> B + Send + Sync>,
     }
 
-    impl<A, B> Operation<B> for Map<A, B>
-    where
-        A: 'static,
-        B: 'static,
-    {
+    struct MapRef<'a, A> {
+        operation: &'a mut dyn Operation<A>,
+    }
+
+    impl<'a, A, B> Operation<B> for MapRef<'a, A> {
         fn container(
             &mut self,
             id: Option<&Id>,
             bounds: Rectangle,
             operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
         ) {
-            struct MapRef<'a, A> {
-                operation: &'a mut dyn Operation<A>,
-            }
+            let Self { operation, .. } = self;
 
-            impl<'a, A, B> Operation<B> for MapRef<'a, A> {
-                fn container(
-                    &mut self,
-                    id: Option<&Id>,
-                    bounds: Rectangle,
-                    operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
-                ) {
-                    let Self { operation, .. } = self;
-
-                    operation.container(id, bounds, &mut |operation| {
-                        operate_on_children(&mut MapRef { operation });
-                    });
-                }
+            operation.container(id, bounds, &mut |operation| {
+                operate_on_children(&mut MapRef { operation });
+            });
+        }
 
-                fn scrollable(
-                    &mut self,
-                    state: &mut dyn Scrollable,
-                    id: Option<&Id>,
-                    bounds: Rectangle,
-                    translation: Vector,
-                ) {
-                    self.operation.scrollable(state, id, bounds, translation);
-                }
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.operation.scrollable(state, id, bounds, translation);
+        }
 
-                fn focusable(
-                    &mut self,
-                    state: &mut dyn Focusable,
-                    id: Option<&Id>,
-                ) {
-                    self.operation.focusable(state, id);
-                }
+        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+            self.operation.focusable(state, id);
+        }
 
-                fn text_input(
-                    &mut self,
-                    state: &mut dyn TextInput,
-                    id: Option<&Id>,
-                ) {
-                    self.operation.text_input(state, id);
-                }
+        fn focusable_with_bounds(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.focusable_with_bounds(state, id, bounds);
+        }
 
-                fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
-                    self.operation.custom(state, id);
-                }
-            }
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            self.operation.text_input(state, id);
+        }
 
+        fn text_input_with_bounds(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.text_input_with_bounds(state, id, bounds);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &dyn Fn() -> accessibility::Node,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
+            let Self { operation, .. } = self;
+
+            operation.accessible(id, bounds, node, &mut |operation| {
+                operate_on_children(&mut MapRef { operation });
+            });
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            self.operation.custom(state, id);
+        }
+    }
+
+    impl<A, B> Operation<B> for Map<A, B>
+    where
+        A: 'static,
+        B: 'static,
+    {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
             let Self { operation, .. } = self;
 
             MapRef {
@@ -165,6 +242,15 @@ where
             self.operation.focusable(state, id);
         }
 
+        fn focusable_with_bounds(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.focusable_with_bounds(state, id, bounds);
+        }
+
         fn scrollable(
             &mut self,
             state: &mut dyn Scrollable,
@@ -179,6 +265,32 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn text_input_with_bounds(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.text_input_with_bounds(state, id, bounds);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &dyn Fn() -> accessibility::Node,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
+            MapRef {
+                operation: self.operation.as_mut(),
+            }
+            .accessible(id, bounds, node, operate_on_children);
+        }
+
         fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
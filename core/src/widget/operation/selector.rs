@@ -0,0 +1,555 @@
+//! Find widgets in a widget tree.
+use crate::accessibility;
+use crate::widget::operation::{Focusable, Outcome, Scrollable, TextInput};
+use crate::widget::{Id, Operation};
+use crate::{Point, Rectangle, Size, Vector};
+
+/// A query that can be used to find widgets in a widget tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selector {
+    /// Matches any widget with the given [`Id`].
+    Id(Id),
+
+    /// Matches any widget displaying exactly the given text.
+    Text(String),
+
+    /// Matches any widget displaying some text that contains the given
+    /// fragment.
+    Contains(String),
+
+    /// Matches any widget of the given [`Kind`].
+    Kind(Kind),
+}
+
+impl Selector {
+    /// Creates a [`Selector`] that matches any widget with the given [`Id`].
+    pub fn id(id: impl Into<Id>) -> Self {
+        Self::Id(id.into())
+    }
+
+    /// Creates a [`Selector`] that matches any widget displaying exactly the
+    /// given text.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text(text.into())
+    }
+
+    /// Creates a [`Selector`] that matches any widget displaying some text
+    /// that contains the given fragment.
+    pub fn contains(fragment: impl Into<String>) -> Self {
+        Self::Contains(fragment.into())
+    }
+
+    /// Creates a [`Selector`] that matches any widget of the given [`Kind`].
+    pub fn kind(kind: Kind) -> Self {
+        Self::Kind(kind)
+    }
+
+    fn matches(&self, kind: Kind, id: Option<&Id>, text: Option<&str>) -> bool {
+        match self {
+            Self::Id(target) => id == Some(target),
+            Self::Text(target) => text == Some(target.as_str()),
+            Self::Contains(fragment) => {
+                text.is_some_and(|text| text.contains(fragment.as_str()))
+            }
+            Self::Kind(target) => kind == *target,
+        }
+    }
+}
+
+impl From<Id> for Selector {
+    fn from(id: Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&str> for Selector {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+impl From<String> for Selector {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Kind> for Selector {
+    fn from(kind: Kind) -> Self {
+        Self::Kind(kind)
+    }
+}
+
+/// The kind of a widget, as reported when operated on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// A widget that contains other widgets.
+    Container,
+
+    /// A widget that can be scrolled.
+    Scrollable,
+
+    /// A widget that can be focused.
+    Focusable,
+
+    /// A widget that has text input.
+    TextInput,
+
+    /// A widget that displays some text.
+    Text,
+
+    /// A widget that can be clicked to trigger some action.
+    Button,
+
+    /// A widget that can be checked and unchecked.
+    Checkbox,
+
+    /// A widget that can be toggled on and off.
+    Toggler,
+
+    /// A widget that selects a value in a range.
+    Slider,
+
+    /// A widget that selects an option from a list.
+    PickList,
+}
+
+impl Kind {
+    /// Returns whether the [`Kind`] describes a widget more precisely than
+    /// the given one.
+    ///
+    /// Some widgets report themselves more than once when operated on; for
+    /// instance, a text input is also focusable.
+    fn is_more_specific_than(self, other: Self) -> bool {
+        fn rank(kind: Kind) -> u8 {
+            match kind {
+                Kind::Container => 0,
+                Kind::Focusable => 1,
+                Kind::Text => 2,
+                Kind::Button
+                | Kind::Checkbox
+                | Kind::Toggler
+                | Kind::Slider
+                | Kind::PickList => 3,
+                Kind::Scrollable => 4,
+                Kind::TextInput => 5,
+            }
+        }
+
+        rank(self) > rank(other)
+    }
+}
+
+/// A widget found by a [`Selector`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Target {
+    /// The [`Kind`] of the widget.
+    pub kind: Kind,
+
+    /// The [`Id`] of the widget, if any.
+    pub id: Option<Id>,
+
+    /// The bounds of the widget, with any scrolling applied.
+    pub bounds: Rectangle,
+
+    /// The bounds of the widget that are visible on screen, if any.
+    ///
+    /// The visible bounds are the [`bounds`](Self::bounds) clipped by the
+    /// viewport of any scrollable ancestors.
+    pub visible_bounds: Option<Rectangle>,
+
+    /// The text displayed by the widget, if any.
+    pub text: Option<String>,
+}
+
+impl Target {
+    /// Returns the center of the visible bounds of the [`Target`], or the
+    /// center of its bounds if it is not visible.
+    pub fn center(&self) -> Point {
+        self.visible_bounds.unwrap_or(self.bounds).center()
+    }
+}
+
+/// Produces an [`Operation`] that finds the first widget matching the given
+/// [`Selector`].
+pub fn find(selector: impl Into<Selector>) -> impl Operation<Option<Target>> {
+    struct Find {
+        search: Search,
+    }
+
+    impl Operation<Option<Target>> for Find {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(
+                &mut dyn Operation<Option<Target>>,
+            ),
+        ) {
+            if !self.search.targets.is_empty() {
+                return;
+            }
+
+            self.search.matched(Kind::Container, id, bounds, None);
+
+            let scope = self.search.enter();
+            operate_on_children(self);
+            self.search.leave(scope);
+        }
+
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &dyn Fn() -> accessibility::Node,
+            operate_on_children: &mut dyn FnMut(
+                &mut dyn Operation<Option<Target>>,
+            ),
+        ) {
+            if !self.search.targets.is_empty() {
+                return;
+            }
+
+            self.search.accessible(id, bounds, node);
+
+            let scope = self.search.enter();
+            operate_on_children(self);
+            self.search.leave(scope);
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.search.scrollable(id, bounds, translation);
+        }
+
+        fn focusable_with_bounds(
+            &mut self,
+            _state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.search.matched(Kind::Focusable, id, bounds, None);
+        }
+
+        fn text_input_with_bounds(
+            &mut self,
+            _state: &mut dyn TextInput,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.search.matched(Kind::TextInput, id, bounds, None);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.search.matched(Kind::Text, id, bounds, Some(text));
+        }
+
+        fn finish(&self) -> Outcome<Option<Target>> {
+            Outcome::Some(self.search.targets.first().cloned())
+        }
+    }
+
+    Find {
+        search: Search::new(selector.into()),
+    }
+}
+
+/// Produces an [`Operation`] that finds all the widgets matching the given
+/// [`Selector`].
+pub fn find_all(selector: impl Into<Selector>) -> impl Operation<Vec<Target>> {
+    struct FindAll {
+        search: Search,
+    }
+
+    impl Operation<Vec<Target>> for FindAll {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(
+                &mut dyn Operation<Vec<Target>>,
+            ),
+        ) {
+            self.search.matched(Kind::Container, id, bounds, None);
+
+            let scope = self.search.enter();
+            operate_on_children(self);
+            self.search.leave(scope);
+        }
+
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &dyn Fn() -> accessibility::Node,
+            operate_on_children: &mut dyn FnMut(
+                &mut dyn Operation<Vec<Target>>,
+            ),
+        ) {
+            self.search.accessible(id, bounds, node);
+
+            let scope = self.search.enter();
+            operate_on_children(self);
+            self.search.leave(scope);
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.search.scrollable(id, bounds, translation);
+        }
+
+        fn focusable_with_bounds(
+            &mut self,
+            _state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.search.matched(Kind::Focusable, id, bounds, None);
+        }
+
+        fn text_input_with_bounds(
+            &mut self,
+            _state: &mut dyn TextInput,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.search.matched(Kind::TextInput, id, bounds, None);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.search.matched(Kind::Text, id, bounds, Some(text));
+        }
+
+        fn finish(&self) -> Outcome<Vec<Target>> {
+            Outcome::Some(self.search.targets.clone())
+        }
+    }
+
+    FindAll {
+        search: Search::new(selector.into()),
+    }
+}
+
+/// The shared traversal state of the selector operations.
+#[derive(Debug)]
+struct Search {
+    selector: Selector,
+    translation: Vector,
+    viewport: Option<Rectangle>,
+    scrolled: Option<(Rectangle, Vector)>,
+    targets: Vec<Target>,
+}
+
+/// The state of a [`Search`] before entering a container.
+#[derive(Debug, Clone, Copy)]
+struct Scope {
+    translation: Vector,
+    viewport: Option<Rectangle>,
+}
+
+impl Search {
+    fn new(selector: Selector) -> Self {
+        Self {
+            selector,
+            translation: Vector::ZERO,
+            viewport: None,
+            scrolled: None,
+            targets: Vec::new(),
+        }
+    }
+
+    fn enter(&mut self) -> Scope {
+        let scope = Scope {
+            translation: self.translation,
+            viewport: self.viewport,
+        };
+
+        let scrolled = self.scrolled.take();
+
+        // The contents of a scrollable are laid out without any scrolling
+        // applied, so we translate and clip them while operating on them
+        if let Some((scrollable_bounds, translation)) = scrolled {
+            let scrollable_bounds = scrollable_bounds - scope.translation;
+
+            self.viewport = Some(match scope.viewport {
+                Some(viewport) => viewport
+                    .intersection(&scrollable_bounds)
+                    .unwrap_or(Rectangle::new(Point::ORIGIN, Size::ZERO)),
+                None => scrollable_bounds,
+            });
+
+            self.translation = scope.translation + translation;
+        }
+
+        scope
+    }
+
+    fn leave(&mut self, scope: Scope) {
+        self.translation = scope.translation;
+        self.viewport = scope.viewport;
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.matched(Kind::Scrollable, id, bounds, None);
+        self.scrolled = Some((bounds, translation));
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        node: &dyn Fn() -> accessibility::Node,
+    ) {
+        let node = node();
+
+        let kind = match node.role {
+            accessibility::Role::Button => Kind::Button,
+            accessibility::Role::CheckBox => Kind::Checkbox,
+            accessibility::Role::Switch => Kind::Toggler,
+            accessibility::Role::Slider => Kind::Slider,
+            accessibility::Role::ComboBox => Kind::PickList,
+            accessibility::Role::TextInput
+            | accessibility::Role::Label
+            | accessibility::Role::ScrollView => return,
+        };
+
+        self.matched(kind, id, bounds, node.label.as_deref());
+    }
+
+    fn matched(
+        &mut self,
+        kind: Kind,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        text: Option<&str>,
+    ) {
+        if !self.selector.matches(kind, id, text) {
+            return;
+        }
+
+        let bounds = bounds - self.translation;
+
+        let visible_bounds = match self.viewport {
+            Some(viewport) => viewport.intersection(&bounds),
+            None => Some(bounds),
+        };
+
+        // Merge the reports of widgets that present themselves more than once
+        if let Some(last) = self.targets.last_mut() {
+            if id.is_some() && last.id.as_ref() == id && last.bounds == bounds {
+                if kind.is_more_specific_than(last.kind) {
+                    last.kind = kind;
+                }
+
+                return;
+            }
+        }
+
+        self.targets.push(Target {
+            kind,
+            id: id.cloned(),
+            bounds,
+            visible_bounds,
+            text: text.map(str::to_owned),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::operation::scrollable::{
+        AbsoluteOffset, RelativeOffset,
+    };
+
+    #[test]
+    fn find_all_applies_scrolling() {
+        struct Scrolled;
+
+        impl Scrollable for Scrolled {
+            fn snap_to(&mut self, _offset: RelativeOffset) {}
+
+            fn scroll_to(&mut self, _offset: AbsoluteOffset) {}
+        }
+
+        let mut operation = find_all(Selector::contains("item"));
+        let viewport = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+
+        operation.container(None, viewport, &mut |operation| {
+            operation.scrollable(
+                &mut Scrolled,
+                None,
+                viewport,
+                Vector::new(0.0, 50.0),
+            );
+
+            operation.container(None, viewport, &mut |operation| {
+                operation.text(
+                    None,
+                    Rectangle::new(
+                        Point::new(0.0, 0.0),
+                        Size::new(100.0, 20.0),
+                    ),
+                    "item 1",
+                );
+
+                operation.text(
+                    None,
+                    Rectangle::new(
+                        Point::new(0.0, 80.0),
+                        Size::new(100.0, 20.0),
+                    ),
+                    "item 2",
+                );
+            });
+        });
+
+        let Outcome::Some(targets) = operation.finish() else {
+            panic!("find_all should produce some output");
+        };
+
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0].visible_bounds, None);
+        assert_eq!(targets[1].bounds.y, 30.0);
+        assert_eq!(targets[1].visible_bounds, Some(targets[1].bounds));
+    }
+
+    #[test]
+    fn find_matches_accessible_roles() {
+        let mut operation = find(Kind::Checkbox);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 20.0));
+        let id = Id::new("terms");
+
+        let node = || {
+            accessibility::Node::new(accessibility::Role::CheckBox)
+                .label("I agree")
+        };
+
+        operation.container(None, bounds, &mut |operation| {
+            operation.accessible(Some(&id), bounds, &node, &mut |_| {});
+        });
+
+        let Outcome::Some(Some(target)) = operation.finish() else {
+            panic!("find should match the checkbox");
+        };
+
+        assert_eq!(target.kind, Kind::Checkbox);
+        assert_eq!(target.id, Some(id));
+        assert_eq!(target.text.as_deref(), Some("I agree"));
+    }
+}
@@ -13,6 +13,14 @@ pub trait TextInput {
     fn move_cursor_to(&mut self, position: usize);
     /// Selects all the content of the text input.
     fn select_all(&mut self);
+    /// Clears the undo and redo history of the text input.
+    ///
+    /// By default, it does nothing.
+    fn clear_history(&mut self) {}
+    /// Replaces the whole content of the text input with the given value.
+    ///
+    /// By default, it does nothing.
+    fn set_value(&mut self, _value: &str) {}
 }
 
 /// Produces an [`Operation`] that moves the cursor of the widget with the given [`Id`] to the
@@ -134,3 +142,67 @@ pub fn select_all<T>(target: Id) -> impl Operation<T> {
 
     MoveCursor { target }
 }
+
+/// Produces an [`Operation`] that clears the undo and redo history of the widget with the given
+/// [`Id`].
+pub fn clear_history<T>(target: Id) -> impl Operation<T> {
+    struct ClearHistory {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for ClearHistory {
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            match id {
+                Some(id) if id == &self.target => {
+                    state.clear_history();
+                }
+                _ => {}
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+    }
+
+    ClearHistory { target }
+}
+
+/// Produces an [`Operation`] that replaces the content of the widget with the given [`Id`]
+/// with the given value.
+pub fn set_value<T>(target: Id, value: impl Into<String>) -> impl Operation<T> {
+    struct SetValue {
+        target: Id,
+        value: String,
+    }
+
+    impl<T> Operation<T> for SetValue {
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            match id {
+                Some(id) if id == &self.target => {
+                    state.set_value(&self.value);
+                }
+                _ => {}
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+    }
+
+    SetValue {
+        target,
+        value: value.into(),
+    }
+}
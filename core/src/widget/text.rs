@@ -1,17 +1,25 @@
 //! Write some text for your users to read.
+use crate::accessibility;
 use crate::alignment;
+use crate::clipboard::{self, Clipboard};
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::keyboard::key;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
 use crate::text::paragraph::{self, Paragraph};
 use crate::widget::tree::{self, Tree};
+use crate::widget::{Id, Operation};
 use crate::{
-    Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Theme,
-    Widget,
+    Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+    Theme, Widget,
 };
 
-pub use text::{LineHeight, Shaping};
+use std::ops::Range;
+
+pub use text::{Ellipsis, LineHeight, Shaping, Wrapping};
 
 /// A paragraph of text.
 #[allow(missing_debug_implementations)]
@@ -20,6 +28,7 @@ where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    id: Option<Id>,
     fragment: text::Fragment<'a>,
     size: Option<Pixels>,
     line_height: LineHeight,
@@ -29,6 +38,10 @@ where
     vertical_alignment: alignment::Vertical,
     font: Option<Renderer::Font>,
     shaping: Shaping,
+    wrapping: Wrapping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
+    is_selectable: bool,
     class: Theme::Class<'a>,
 }
 
@@ -40,6 +53,7 @@ where
     /// Create a new fragment of [`Text`] with the given contents.
     pub fn new(fragment: impl text::IntoFragment<'a>) -> Self {
         Text {
+            id: None,
             fragment: fragment.into_fragment(),
             size: None,
             line_height: LineHeight::default(),
@@ -49,10 +63,20 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             shaping: Shaping::Basic,
+            wrapping: Wrapping::default(),
+            ellipsis: Ellipsis::default(),
+            max_lines: None,
+            is_selectable: false,
             class: Theme::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Text`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the size of the [`Text`].
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.size = Some(size.into());
@@ -115,6 +139,36 @@ where
         self
     }
 
+    /// Sets the [`Wrapping`] strategy of the [`Text`].
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Sets the [`Ellipsis`] strategy of the [`Text`], used to truncate
+    /// it when it overflows its bounds or its maximum amount of lines.
+    pub fn ellipsis(mut self, ellipsis: Ellipsis) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Sets the maximum amount of lines of the [`Text`].
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Sets whether the [`Text`] can be selected and copied by the user.
+    ///
+    /// Selectable text is always shaped with [`Shaping::Advanced`], since
+    /// finding the text under the cursor needs it.
+    ///
+    /// By default, it cannot.
+    pub fn selectable(mut self, is_selectable: bool) -> Self {
+        self.is_selectable = is_selectable;
+        self
+    }
+
     /// Sets the style of the [`Text`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -140,7 +194,10 @@ where
     {
         let color = color.map(Into::into);
 
-        self.style(move |_theme| Style { color })
+        self.style(move |_theme| Style {
+            color,
+            ..Style::default()
+        })
     }
 
     /// Sets the style class of the [`Text`].
@@ -154,7 +211,7 @@ where
 
 /// The internal state of a [`Text`] widget.
 #[derive(Debug, Default)]
-pub struct State<P: Paragraph>(pub paragraph::Plain<P>);
+pub struct State<P: Paragraph>(pub paragraph::Plain<P>, Selection);
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Text<'a, Theme, Renderer>
@@ -169,6 +226,7 @@ where
     fn state(&self) -> tree::State {
         tree::State::new(State::<Renderer::Paragraph>(
             paragraph::Plain::default(),
+            Selection::default(),
         ))
     }
 
@@ -185,7 +243,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(
+        layout_with(
             tree.state.downcast_mut::<State<Renderer::Paragraph>>(),
             renderer,
             limits,
@@ -197,7 +255,16 @@ where
             self.font,
             self.horizontal_alignment,
             self.vertical_alignment,
-            self.shaping,
+            if self.is_selectable {
+                Shaping::Advanced
+            } else {
+                self.shaping
+            },
+            Settings {
+                wrapping: self.wrapping,
+                ellipsis: self.ellipsis,
+                max_lines: self.max_lines,
+            },
         )
     }
 
@@ -214,8 +281,88 @@ where
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let style = theme.style(&self.class);
 
+        state
+            .1
+            .draw(renderer, defaults, layout, state.0.raw(), style);
+
         draw(renderer, defaults, layout, state.0.raw(), style, viewport);
     }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if !self.is_selectable {
+            return event::Status::Ignored;
+        }
+
+        let State(paragraph, selection) =
+            tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        selection.update(
+            &event,
+            layout.bounds(),
+            cursor,
+            paragraph.raw(),
+            &self.fragment,
+            clipboard,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_selectable && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn operate(
+        &self,
+        _state: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        operation.text(self.id.as_ref(), layout.bounds(), &self.fragment);
+
+        let node = || {
+            accessibility::Node::new(accessibility::Role::Label)
+                .label(self.fragment.as_ref())
+        };
+
+        operation.accessible(
+            self.id.as_ref(),
+            layout.bounds(),
+            &node,
+            &mut |_| {},
+        );
+    }
+}
+
+/// The wrapping and truncation settings of the [`layout_with`] of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Settings {
+    /// The [`Wrapping`] strategy of the text.
+    pub wrapping: Wrapping,
+    /// The [`Ellipsis`] strategy of the text.
+    pub ellipsis: Ellipsis,
+    /// The maximum amount of lines of the text, if any.
+    pub max_lines: Option<usize>,
 }
 
 /// Produces the [`layout::Node`] of a [`Text`] widget.
@@ -233,6 +380,43 @@ pub fn layout<Renderer>(
     vertical_alignment: alignment::Vertical,
     shaping: Shaping,
 ) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    layout_with(
+        state,
+        renderer,
+        limits,
+        width,
+        height,
+        content,
+        line_height,
+        size,
+        font,
+        horizontal_alignment,
+        vertical_alignment,
+        shaping,
+        Settings::default(),
+    )
+}
+
+/// Produces the [`layout::Node`] of a [`Text`] widget with the given
+/// wrapping and truncation [`Settings`].
+pub fn layout_with<Renderer>(
+    state: &mut State<Renderer::Paragraph>,
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    width: Length,
+    height: Length,
+    content: &str,
+    line_height: LineHeight,
+    size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    shaping: Shaping,
+    settings: Settings,
+) -> layout::Node
 where
     Renderer: text::Renderer,
 {
@@ -242,7 +426,7 @@ where
         let size = size.unwrap_or_else(|| renderer.default_size());
         let font = font.unwrap_or_else(|| renderer.default_font());
 
-        let State(ref mut paragraph) = state;
+        let State(ref mut paragraph, _) = state;
 
         paragraph.update(text::Text {
             content,
@@ -253,6 +437,9 @@ where
             horizontal_alignment,
             vertical_alignment,
             shaping,
+            wrapping: settings.wrapping,
+            ellipsis: settings.ellipsis,
+            max_lines: settings.max_lines,
         });
 
         paragraph.min_bounds()
@@ -301,6 +488,302 @@ pub fn draw<Renderer>(
     );
 }
 
+/// The selection of some selectable text.
+///
+/// Both ends of a [`Selection`] are byte offsets into the text, which spans
+/// every [`Span`] of a paragraph when it has many.
+///
+/// [`Span`]: text::Span
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Selection {
+    anchor: usize,
+    head: usize,
+    is_focused: bool,
+    is_dragging: bool,
+    modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl Selection {
+    /// Returns the selected range of the text.
+    pub fn range(&self) -> Range<usize> {
+        self.anchor.min(self.head)..self.anchor.max(self.head)
+    }
+
+    /// Returns whether the [`Selection`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.anchor == self.head
+    }
+
+    /// Returns whether the [`Selection`] handles the given [`Event`].
+    ///
+    /// Widgets that build the text of the [`Selection`] on demand can use it
+    /// to skip the work for any other events.
+    pub fn handles(&self, event: &Event) -> bool {
+        match event {
+            Event::Mouse(
+                mouse::Event::ButtonPressed(mouse::Button::Left)
+                | mouse::Event::ButtonReleased(mouse::Button::Left),
+            )
+            | Event::Keyboard(keyboard::Event::ModifiersChanged(_)) => true,
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => self.is_dragging,
+            Event::Keyboard(keyboard::Event::KeyPressed { .. }) => {
+                self.is_focused
+            }
+            _ => false,
+        }
+    }
+
+    /// Processes an [`Event`] for the given text, laid out as the given
+    /// [`Paragraph`] in the given bounds.
+    ///
+    /// The [`Selection`] is changed with the mouse and, once clicked, with
+    /// the keyboard; and copied to the [`Clipboard`] on `Ctrl+C`, leaving
+    /// out any object replacement characters (U+FFFC) reserving the space
+    /// of inline objects.
+    pub fn update<P: Paragraph>(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        paragraph: &P,
+        text: &str,
+        clipboard: &mut dyn Clipboard,
+    ) -> event::Status {
+        self.anchor = char_boundary(text, self.anchor);
+        self.head = char_boundary(text, self.head);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    self.anchor = self.head;
+                    self.is_focused = false;
+
+                    return event::Status::Ignored;
+                };
+
+                let Some(offset) = hit(position, bounds, paragraph, text)
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let click = mouse::Click::new(position, self.last_click);
+
+                match click.kind() {
+                    mouse::click::Kind::Single => {
+                        if !(self.is_focused && self.modifiers.shift()) {
+                            self.anchor = offset;
+                        }
+
+                        self.head = offset;
+                        self.is_dragging = true;
+                    }
+                    mouse::click::Kind::Double => {
+                        let word = word_at(text, offset);
+
+                        self.anchor = word.start;
+                        self.head = word.end;
+                    }
+                    mouse::click::Kind::Triple => {
+                        self.anchor = 0;
+                        self.head = text.len();
+                    }
+                }
+
+                self.is_focused = true;
+                self.last_click = Some(click);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if self.is_dragging =>
+            {
+                if let Some(offset) = cursor
+                    .position()
+                    .and_then(|position| hit(position, bounds, paragraph, text))
+                {
+                    self.head = offset;
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.is_dragging = false;
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.modifiers = *modifiers;
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) if self.is_focused => {
+                let head = match key.as_ref() {
+                    keyboard::Key::Character("c") if modifiers.command() => {
+                        if let Some(selected) = text.get(self.range()) {
+                            let selected =
+                                selected.replace(OBJECT_REPLACEMENT, "");
+
+                            if !selected.is_empty() {
+                                clipboard
+                                    .write(clipboard::Kind::Standard, selected);
+                            }
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::Key::Character("a") if modifiers.command() => {
+                        self.anchor = 0;
+                        self.head = text.len();
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::Key::Named(key::Named::ArrowLeft) => text
+                        [..self.head]
+                        .char_indices()
+                        .next_back()
+                        .map_or(0, |(i, _)| i),
+                    keyboard::Key::Named(key::Named::ArrowRight) => text
+                        [self.head..]
+                        .chars()
+                        .next()
+                        .map_or(self.head, |c| self.head + c.len_utf8()),
+                    keyboard::Key::Named(key::Named::Home) => 0,
+                    keyboard::Key::Named(key::Named::End) => text.len(),
+                    keyboard::Key::Named(key::Named::Escape) => {
+                        self.anchor = self.head;
+                        self.is_focused = false;
+
+                        return event::Status::Captured;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                self.head = head;
+
+                if !modifiers.shift() {
+                    self.anchor = head;
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// Draws the highlight of the [`Selection`] of the given [`Paragraph`],
+    /// laid out like the [`Text`] widget would.
+    pub fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        paragraph: &Renderer::Paragraph,
+        appearance: Style,
+    ) where
+        Renderer: text::Renderer,
+    {
+        if self.is_empty() {
+            return;
+        }
+
+        let color = appearance.selection.unwrap_or_else(|| {
+            appearance
+                .color
+                .unwrap_or(style.text_color)
+                .scale_alpha(0.3)
+        });
+
+        let translation = origin(layout.bounds(), paragraph) - Point::ORIGIN;
+
+        for region in paragraph.range_bounds(self.range()) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: region + translation,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+}
+
+/// The character reserving the space of an inline object in some text.
+const OBJECT_REPLACEMENT: char = '\u{FFFC}';
+
+/// Returns the top-left corner of the given [`Paragraph`] when drawn in the
+/// given bounds by [`draw`], taking its alignment into account.
+pub fn origin(bounds: Rectangle, paragraph: &impl Paragraph) -> Point {
+    let size = paragraph.min_bounds();
+
+    let x = match paragraph.horizontal_alignment() {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => bounds.center_x() - size.width / 2.0,
+        alignment::Horizontal::Right => bounds.x + bounds.width - size.width,
+    };
+
+    let y = match paragraph.vertical_alignment() {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => bounds.center_y() - size.height / 2.0,
+        alignment::Vertical::Bottom => bounds.y + bounds.height - size.height,
+    };
+
+    Point::new(x, y)
+}
+
+/// Returns the byte offset of the text nearest to the given position.
+fn hit(
+    position: Point,
+    bounds: Rectangle,
+    paragraph: &impl Paragraph,
+    text: &str,
+) -> Option<usize> {
+    let size = paragraph.min_bounds();
+    let origin = origin(bounds, paragraph);
+
+    let offset = paragraph.hit_offset(Point::new(
+        (position.x - origin.x).clamp(0.0, size.width),
+        (position.y - origin.y).clamp(0.0, (size.height - 1.0).max(0.0)),
+    ))?;
+
+    Some(char_boundary(text, offset))
+}
+
+/// Returns the nearest char boundary of the text at or before the given
+/// byte offset.
+fn char_boundary(text: &str, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    offset
+}
+
+/// Returns the range of the word around the given byte offset.
+fn word_at(text: &str, offset: usize) -> Range<usize> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    let start = text[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_word(*c))
+        .last()
+        .map_or(offset, |(i, _)| i);
+
+    let end = text[offset..]
+        .char_indices()
+        .find(|(_, c)| !is_word(*c))
+        .map_or(text.len(), |(i, _)| offset + i);
+
+    start..end
+}
+
 impl<'a, Message, Theme, Renderer> From<Text<'a, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -342,6 +825,14 @@ pub struct Style {
     ///
     /// The default, `None`, means using the inherited color.
     pub color: Option<Color>,
+    /// The [`Color`] of the highlight of selected text.
+    ///
+    /// The default, `None`, means using a translucent text color.
+    pub selection: Option<Color>,
+    /// The [`Color`] of the underline of a hovered link.
+    ///
+    /// The default, `None`, means using the color of the link.
+    pub hovered_link: Option<Color>,
 }
 
 /// The theme catalog of a [`Text`].
@@ -365,7 +856,7 @@ impl Catalog for Theme {
     type Class<'a> = StyleFn<'a, Self>;
 
     fn default<'a>() -> Self::Class<'a> {
-        Box::new(|_theme| Style::default())
+        Box::new(default)
     }
 
     fn style(&self, class: &Self::Class<'_>) -> Style {
@@ -374,14 +865,19 @@ impl Catalog for Theme {
 }
 
 /// The default text styling; color is inherited.
-pub fn default(_theme: &Theme) -> Style {
-    Style { color: None }
+pub fn default(theme: &Theme) -> Style {
+    Style {
+        color: None,
+        selection: Some(theme.extended_palette().primary.weak.color),
+        hovered_link: None,
+    }
 }
 
 /// Text with the default base color.
 pub fn base(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().text),
+        ..default(theme)
     }
 }
 
@@ -389,6 +885,7 @@ pub fn base(theme: &Theme) -> Style {
 pub fn primary(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().primary),
+        ..default(theme)
     }
 }
 
@@ -396,6 +893,7 @@ pub fn primary(theme: &Theme) -> Style {
 pub fn secondary(theme: &Theme) -> Style {
     Style {
         color: Some(theme.extended_palette().secondary.strong.color),
+        ..default(theme)
     }
 }
 
@@ -403,6 +901,7 @@ pub fn secondary(theme: &Theme) -> Style {
 pub fn success(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().success),
+        ..default(theme)
     }
 }
 
@@ -410,5 +909,6 @@ pub fn success(theme: &Theme) -> Style {
 pub fn danger(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().danger),
+        ..default(theme)
     }
 }
@@ -1,3 +1,4 @@
+use crate::drag_and_drop;
 use crate::time::Instant;
 use crate::{Point, Size};
 
@@ -59,4 +60,39 @@ pub enum Event {
     /// There will be a single `FilesHoveredLeft` event triggered even if
     /// multiple files were hovered.
     FilesHoveredLeft,
+
+    /// Some data from another application is being dragged into the window.
+    ///
+    /// Like every window event, it is delivered to all the widgets; each
+    /// one decides whether the drag targets it.
+    ///
+    /// The current windowing backend only supports dragging files, offered
+    /// as a [`drag_and_drop::Data::URI_LIST`].
+    DragEntered {
+        /// The position of the drag, in logical pixels, if known.
+        ///
+        /// The windowing backend does not report the position of a drag,
+        /// and most platforms do not move the cursor during one; so this is
+        /// only an approximation: the last known position of the cursor in
+        /// the window.
+        position: Option<Point>,
+        /// The MIME types the dragged data is offered as.
+        mime_types: Vec<String>,
+    },
+
+    /// The data dragged over the window has been dropped.
+    ///
+    /// All the dropped files are delivered together in a single event.
+    DragDropped {
+        /// The position of the drop, in logical pixels, if known.
+        ///
+        /// Like the position of [`Event::DragEntered`], this is only an
+        /// approximation.
+        position: Option<Point>,
+        /// The dropped data.
+        data: drag_and_drop::Data,
+    },
+
+    /// The data dragged over the window has left it without being dropped.
+    DragLeft,
 }
@@ -11,11 +11,12 @@ pub use cosmic_text;
 
 use crate::core::alignment;
 use crate::core::font::{self, Font};
-use crate::core::text::Shaping;
+use crate::core::text::{Ellipsis, Shaping, Wrapping};
 use crate::core::{Color, Pixels, Point, Rectangle, Size, Transformation};
 
 use once_cell::sync::OnceCell;
 use std::borrow::Cow;
+use std::ops::Range;
 use std::sync::{Arc, RwLock, Weak};
 
 /// A text primitive.
@@ -59,6 +60,12 @@ pub enum Text {
         vertical_alignment: alignment::Vertical,
         /// The shaping strategy of the text.
         shaping: Shaping,
+        /// The wrapping strategy of the text.
+        wrapping: Wrapping,
+        /// The ellipsis strategy of the text.
+        ellipsis: Ellipsis,
+        /// The maximum amount of lines of the text.
+        max_lines: Option<usize>,
         /// The clip bounds of the text.
         clip_bounds: Rectangle,
     },
@@ -242,6 +249,205 @@ pub fn measure(buffer: &cosmic_text::Buffer) -> Size {
     Size::new(width, height)
 }
 
+/// Sets the spans of text of the given [`cosmic_text::Buffer`], truncating
+/// them with the given [`Ellipsis`] if they overflow the bounds of the buffer
+/// or the given maximum amount of lines.
+///
+/// Returns the range of byte offsets of the text replaced by the ellipsis,
+/// if the spans were truncated.
+pub fn set_spans<'a>(
+    buffer: &mut cosmic_text::Buffer,
+    font_system: &mut cosmic_text::FontSystem,
+    spans: &[(&'a str, cosmic_text::Attrs<'a>)],
+    attrs: cosmic_text::Attrs<'a>,
+    shaping: Shaping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
+) -> Option<Range<usize>> {
+    buffer.set_rich_text(
+        font_system,
+        spans.iter().copied(),
+        attrs,
+        to_shaping(shaping),
+    );
+
+    let mut hidden = None;
+
+    if ellipsis != Ellipsis::None && overflows(buffer, max_lines) {
+        let length: usize =
+            spans.iter().map(|(text, _)| text.chars().count()).sum();
+
+        let mut fits = |kept: usize| {
+            let removed = match ellipsis {
+                Ellipsis::None | Ellipsis::End => kept..length,
+                Ellipsis::Start => 0..length - kept,
+                Ellipsis::Middle => {
+                    let half = kept / 2;
+
+                    kept - half..length - half
+                }
+            };
+
+            let (spans, replaced) = truncate(spans, removed);
+            hidden = replaced;
+
+            buffer.set_rich_text(
+                font_system,
+                spans.iter().map(|(text, attrs)| (text.as_str(), *attrs)),
+                attrs,
+                to_shaping(shaping),
+            );
+
+            !overflows(buffer, max_lines)
+        };
+
+        // Find the longest truncation that fits
+        let mut min = 0;
+        let mut max = length.saturating_sub(1);
+
+        while min < max {
+            let kept = (min + max).div_ceil(2);
+
+            if fits(kept) {
+                min = kept;
+            } else {
+                max = kept - 1;
+            }
+        }
+
+        let _ = fits(min);
+    }
+
+    limit_lines(buffer, font_system, max_lines);
+
+    hidden
+}
+
+/// Limits the visible lines of the given [`cosmic_text::Buffer`] to the
+/// given maximum amount, if any.
+pub fn limit_lines(
+    buffer: &mut cosmic_text::Buffer,
+    font_system: &mut cosmic_text::FontSystem,
+    max_lines: Option<usize>,
+) {
+    let Some(max_lines) = max_lines else {
+        return;
+    };
+
+    let line_height = buffer.metrics().line_height;
+
+    let height: f32 = buffer
+        .lines
+        .iter()
+        .filter_map(|line| line.layout_opt().as_ref())
+        .flatten()
+        .take(max_lines)
+        .map(|line| line.line_height_opt.unwrap_or(line_height))
+        .sum();
+
+    let (width, bounds_height) = buffer.size();
+    let bounds_height = bounds_height.unwrap_or(f32::INFINITY);
+
+    if height < bounds_height {
+        buffer.set_size(font_system, width, Some(height));
+    }
+}
+
+/// Returns whether the laid out text of the given [`cosmic_text::Buffer`]
+/// overflows its bounds or the given maximum amount of lines.
+///
+/// The first line never overflows vertically.
+fn overflows(buffer: &cosmic_text::Buffer, max_lines: Option<usize>) -> bool {
+    let (width, height) = buffer.size();
+    let width = width.unwrap_or(f32::INFINITY);
+    let height = height.unwrap_or(f32::INFINITY);
+    let line_height = buffer.metrics().line_height;
+
+    let mut lines = 0;
+    let mut bottom = 0.0;
+
+    for line in &buffer.lines {
+        // Lines past the bounds of the buffer are not laid out
+        let Some(layout) = line.layout_opt() else {
+            return true;
+        };
+
+        for layout_line in layout {
+            lines += 1;
+            bottom += layout_line.line_height_opt.unwrap_or(line_height);
+
+            if layout_line.w > width
+                || max_lines.is_some_and(|max_lines| lines > max_lines)
+                || (lines > 1 && bottom > height)
+            {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// The ellipsis replacing the truncated text.
+pub(crate) const ELLIPSIS: char = '…';
+
+/// Removes the given range of characters from the spans of text, replacing
+/// it with an [`ELLIPSIS`].
+///
+/// Returns the truncated spans, together with the range of byte offsets of
+/// the replaced text, including any whitespace trimmed before the ellipsis.
+fn truncate<'a>(
+    spans: &[(&str, cosmic_text::Attrs<'a>)],
+    removed: Range<usize>,
+) -> (Vec<(String, cosmic_text::Attrs<'a>)>, Option<Range<usize>>) {
+    let mut truncated = Vec::with_capacity(spans.len());
+    let mut start = 0;
+    let mut offset = 0;
+    let mut ellipsis = None;
+
+    for (text, attrs) in spans {
+        let length = text.chars().count();
+        let end = start + length;
+
+        let before = slice(text, 0..removed.start.clamp(start, end) - start);
+        let after = slice(text, removed.end.clamp(start, end) - start..length);
+
+        let text = if ellipsis.is_none() && removed.start <= end {
+            let before = before.trim_end();
+            ellipsis = Some(offset + before.len());
+
+            format!("{before}{ELLIPSIS}{after}")
+        } else {
+            format!("{before}{after}")
+        };
+
+        offset += text.len();
+        truncated.push((text, *attrs));
+        start = end;
+    }
+
+    // The text after the ellipsis is kept verbatim, so the replaced text
+    // ends where the original text is as long as the truncated one
+    let hidden = ellipsis.map(|ellipsis| {
+        let length: usize = spans.iter().map(|(text, _)| text.len()).sum();
+
+        ellipsis..length - (offset - ellipsis - ELLIPSIS.len_utf8())
+    });
+
+    (truncated, hidden)
+}
+
+/// Returns the given range of characters of some text.
+fn slice(text: &str, range: Range<usize>) -> &str {
+    let offset = |index| {
+        text.char_indices()
+            .nth(index)
+            .map_or(text.len(), |(offset, _)| offset)
+    };
+
+    &text[offset(range.start)..offset(range.end)]
+}
+
 /// Returns the attributes of the given [`Font`].
 pub fn to_attributes(font: Font) -> cosmic_text::Attrs<'static> {
     cosmic_text::Attrs::new()
@@ -306,6 +512,16 @@ pub fn to_shaping(shaping: Shaping) -> cosmic_text::Shaping {
     }
 }
 
+/// Converts some [`Wrapping`] strategy to a [`cosmic_text::Wrap`] strategy.
+pub fn to_wrap(wrapping: Wrapping) -> cosmic_text::Wrap {
+    match wrapping {
+        Wrapping::None => cosmic_text::Wrap::None,
+        Wrapping::Word => cosmic_text::Wrap::Word,
+        Wrapping::Glyph => cosmic_text::Wrap::Glyph,
+        Wrapping::WordOrGlyph => cosmic_text::Wrap::WordOrGlyph,
+    }
+}
+
 /// Converts some [`Color`] to a [`cosmic_text::Color`].
 pub fn to_color(color: Color) -> cosmic_text::Color {
     let [r, g, b, a] = color.into_rgba8();
@@ -51,11 +51,18 @@ impl Cache {
                 Some(key.bounds.width),
                 Some(key.bounds.height.max(key.line_height)),
             );
-            buffer.set_text(
+            buffer.set_wrap(font_system, text::to_wrap(key.wrapping));
+
+            let attributes = text::to_attributes(key.font);
+
+            let _ = text::set_spans(
+                &mut buffer,
                 font_system,
-                key.content,
-                text::to_attributes(key.font),
-                text::to_shaping(key.shaping),
+                &[(key.content, attributes)],
+                attributes,
+                key.shaping,
+                key.ellipsis,
+                key.max_lines,
             );
 
             let bounds = text::measure(&buffer);
@@ -114,6 +121,12 @@ pub struct Key<'a> {
     pub bounds: Size,
     /// The shaping strategy of the text.
     pub shaping: text::Shaping,
+    /// The wrapping strategy of the text.
+    pub wrapping: text::Wrapping,
+    /// The ellipsis strategy of the text.
+    pub ellipsis: text::Ellipsis,
+    /// The maximum amount of lines of the text.
+    pub max_lines: Option<usize>,
 }
 
 impl Key<'_> {
@@ -125,6 +138,9 @@ impl Key<'_> {
         self.bounds.width.to_bits().hash(&mut hasher);
         self.bounds.height.to_bits().hash(&mut hasher);
         self.shaping.hash(&mut hasher);
+        self.wrapping.hash(&mut hasher);
+        self.ellipsis.hash(&mut hasher);
+        self.max_lines.hash(&mut hasher);
 
         hasher.finish()
     }
@@ -22,6 +22,7 @@ struct Internal {
     bounds: Size,
     topmost_line_changed: Option<usize>,
     version: text::Version,
+    history: History,
 }
 
 impl Editor {
@@ -243,6 +244,10 @@ impl editor::Editor for Editor {
         let mut internal = Arc::try_unwrap(editor)
             .expect("Editor cannot have multiple strong references");
 
+        if !matches!(action, Action::Edit(_) | Action::Scroll { .. }) {
+            internal.history.group = None;
+        }
+
         let editor = &mut internal.editor;
 
         match action {
@@ -338,6 +343,11 @@ impl editor::Editor for Editor {
 
             // Editing events
             Action::Edit(edit) => {
+                let group = Group::of(&edit);
+                let before = Position::of(editor);
+
+                editor.start_change();
+
                 match edit {
                     Edit::Insert(c) => {
                         editor.action(
@@ -368,6 +378,15 @@ impl editor::Editor for Editor {
                     }
                 }
 
+                if let Some(change) = editor.finish_change() {
+                    internal.history.record(
+                        change,
+                        group,
+                        before,
+                        Position::of(editor),
+                    );
+                }
+
                 let cursor = editor.cursor();
                 let selection_start = editor
                     .selection_bounds()
@@ -376,6 +395,34 @@ impl editor::Editor for Editor {
 
                 internal.topmost_line_changed = Some(selection_start.line);
             }
+            Action::Undo => {
+                if let Some(mut entry) = internal.history.undo.pop() {
+                    entry.change.reverse();
+
+                    editor.set_selection(cosmic_text::Selection::None);
+                    let _ = editor.apply_change(&entry.change);
+                    entry.before.restore(editor);
+
+                    entry.change.reverse();
+
+                    internal.topmost_line_changed = topmost_line(&entry.change);
+                    internal.history.redo.push(entry);
+                }
+
+                internal.history.group = None;
+            }
+            Action::Redo => {
+                if let Some(entry) = internal.history.redo.pop() {
+                    editor.set_selection(cosmic_text::Selection::None);
+                    let _ = editor.apply_change(&entry.change);
+                    entry.after.restore(editor);
+
+                    internal.topmost_line_changed = topmost_line(&entry.change);
+                    internal.history.undo.push(entry);
+                }
+
+                internal.history.group = None;
+            }
 
             // Mouse events
             Action::Click(position) => {
@@ -620,6 +667,7 @@ impl Default for Internal {
             bounds: Size::ZERO,
             topmost_line_changed: None,
             version: text::Version::default(),
+            history: History::default(),
         }
     }
 }
@@ -742,6 +790,114 @@ fn to_motion(motion: Motion) -> cosmic_text::Motion {
     }
 }
 
+/// The undo and redo stacks of an [`Editor`].
+#[derive(Debug, Default)]
+struct History {
+    undo: Vec<Entry>,
+    redo: Vec<Entry>,
+    group: Option<Group>,
+}
+
+/// A change in the [`History`] of an [`Editor`], together with the cursor
+/// positions around it.
+#[derive(Debug)]
+struct Entry {
+    change: cosmic_text::Change,
+    before: Position,
+    after: Position,
+}
+
+/// The cursor and selection of an [`Editor`].
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    cursor: cosmic_text::Cursor,
+    selection: cosmic_text::Selection,
+}
+
+impl Position {
+    fn of(editor: &cosmic_text::Editor<'_>) -> Self {
+        Self {
+            cursor: editor.cursor(),
+            selection: editor.selection(),
+        }
+    }
+
+    fn restore(self, editor: &mut cosmic_text::Editor<'_>) {
+        editor.set_cursor(self.cursor);
+        editor.set_selection(self.selection);
+    }
+}
+
+impl History {
+    /// The maximum amount of changes that can be undone.
+    const LIMIT: usize = 1_000;
+
+    fn record(
+        &mut self,
+        mut change: cosmic_text::Change,
+        group: Group,
+        before: Position,
+        after: Position,
+    ) {
+        if change.items.is_empty() {
+            return;
+        }
+
+        self.redo.clear();
+
+        match self.undo.last_mut() {
+            Some(last) if self.group == Some(group) && group.coalesces() => {
+                last.change.items.append(&mut change.items);
+                last.after = after;
+            }
+            _ => {
+                if self.undo.len() == Self::LIMIT {
+                    let _ = self.undo.remove(0);
+                }
+
+                self.undo.push(Entry {
+                    change,
+                    before,
+                    after,
+                });
+            }
+        }
+
+        self.group = Some(group);
+    }
+}
+
+/// The kind of an [`Edit`], used to merge consecutive edits into a single
+/// undo step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Group {
+    Word,
+    Whitespace,
+    Backspace,
+    Delete,
+    Other,
+}
+
+impl Group {
+    fn of(edit: &Edit) -> Self {
+        match edit {
+            Edit::Insert(c) if c.is_whitespace() => Self::Whitespace,
+            Edit::Insert(_) => Self::Word,
+            Edit::Backspace => Self::Backspace,
+            Edit::Delete => Self::Delete,
+            Edit::Paste(_) | Edit::Enter => Self::Other,
+        }
+    }
+
+    fn coalesces(self) -> bool {
+        self != Self::Other
+    }
+}
+
+fn topmost_line(change: &cosmic_text::Change) -> Option<usize> {
+    change.items.iter().map(|item| item.start.line).min()
+}
+
 fn buffer_from_editor<'a, 'b>(
     editor: &'a impl cosmic_text::Edit<'b>,
 ) -> &'a cosmic_text::Buffer
@@ -767,3 +923,79 @@ where
         cosmic_text::BufferRef::Arc(_buffer) => unreachable!(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::text::editor::Editor as _;
+
+    fn text(editor: &Editor) -> String {
+        (0..editor.line_count())
+            .filter_map(|line| editor.line(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn type_in(editor: &mut Editor, text: &str) {
+        for c in text.chars() {
+            editor.perform(Action::Edit(Edit::Insert(c)));
+        }
+    }
+
+    #[test]
+    fn groups_consecutive_inserts() {
+        let mut editor = Editor::with_text("");
+
+        type_in(&mut editor, "hello world");
+        assert_eq!(text(&editor), "hello world");
+
+        editor.perform(Action::Undo);
+        assert_eq!(text(&editor), "hello ");
+
+        editor.perform(Action::Undo);
+        assert_eq!(text(&editor), "hello");
+
+        editor.perform(Action::Undo);
+        assert_eq!(text(&editor), "");
+    }
+
+    #[test]
+    fn undoes_a_selection_replace() {
+        let mut editor = Editor::with_text("hello world");
+
+        editor.perform(Action::Move(Motion::DocumentStart));
+        editor.perform(Action::Select(Motion::WordRight));
+        assert_eq!(editor.selection().as_deref(), Some("hello"));
+
+        type_in(&mut editor, "Hi");
+        assert_eq!(text(&editor), "Hi world");
+
+        // The replaced selection comes back, selected
+        editor.perform(Action::Undo);
+        assert_eq!(text(&editor), "hello world");
+        assert_eq!(editor.selection().as_deref(), Some("hello"));
+
+        editor.perform(Action::Redo);
+        assert_eq!(text(&editor), "Hi world");
+        assert_eq!(editor.cursor_position(), (0, 2));
+    }
+
+    #[test]
+    fn invalidates_redo_after_an_edit() {
+        let mut editor = Editor::with_text("");
+
+        type_in(&mut editor, "ab");
+        editor.perform(Action::Undo);
+        assert_eq!(text(&editor), "");
+
+        editor.perform(Action::Redo);
+        assert_eq!(text(&editor), "ab");
+
+        editor.perform(Action::Undo);
+        type_in(&mut editor, "c");
+
+        editor.perform(Action::Redo);
+        assert_eq!(text(&editor), "c");
+    }
+}
@@ -1,11 +1,12 @@
 //! Draw paragraphs.
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Hit, Shaping, Span, Text};
-use crate::core::{Font, Point, Size};
+use crate::core::text::{Ellipsis, Hit, Shaping, Span, Text, Wrapping};
+use crate::core::{Font, Point, Rectangle, Size};
 use crate::text;
 
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc};
 
 /// A bunch of text.
@@ -17,6 +18,10 @@ struct Internal {
     buffer: cosmic_text::Buffer,
     font: Font,
     shaping: Shaping,
+    wrapping: Wrapping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
+    hidden: Option<Range<usize>>,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     bounds: Size,
@@ -79,11 +84,18 @@ impl core::text::Paragraph for Paragraph {
             Some(text.bounds.height),
         );
 
-        buffer.set_text(
+        buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrapping));
+
+        let attributes = text::to_attributes(text.font);
+
+        let hidden = text::set_spans(
+            &mut buffer,
             font_system.raw(),
-            text.content,
-            text::to_attributes(text.font),
-            text::to_shaping(text.shaping),
+            &[(text.content, attributes)],
+            attributes,
+            text.shaping,
+            text.ellipsis,
+            text.max_lines,
         );
 
         let min_bounds = text::measure(&buffer);
@@ -94,6 +106,10 @@ impl core::text::Paragraph for Paragraph {
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            wrapping: text.wrapping,
+            ellipsis: text.ellipsis,
+            max_lines: text.max_lines,
+            hidden,
             bounds: text.bounds,
             min_bounds,
             version: font_system.version(),
@@ -120,9 +136,13 @@ impl core::text::Paragraph for Paragraph {
             Some(text.bounds.height),
         );
 
-        buffer.set_rich_text(
-            font_system.raw(),
-            text.content.iter().enumerate().map(|(i, span)| {
+        buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrapping));
+
+        let spans: Vec<_> = text
+            .content
+            .iter()
+            .enumerate()
+            .map(|(i, span)| {
                 let attrs = text::to_attributes(span.font.unwrap_or(text.font));
 
                 let attrs = match (span.size, span.line_height) {
@@ -147,9 +167,17 @@ impl core::text::Paragraph for Paragraph {
                 };
 
                 (span.text.as_ref(), attrs.metadata(i))
-            }),
+            })
+            .collect();
+
+        let hidden = text::set_spans(
+            &mut buffer,
+            font_system.raw(),
+            &spans,
             text::to_attributes(text.font),
-            text::to_shaping(text.shaping),
+            text.shaping,
+            text.ellipsis,
+            text.max_lines,
         );
 
         let min_bounds = text::measure(&buffer);
@@ -160,6 +188,10 @@ impl core::text::Paragraph for Paragraph {
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            wrapping: text.wrapping,
+            ellipsis: text.ellipsis,
+            max_lines: text.max_lines,
+            hidden,
             bounds: text.bounds,
             min_bounds,
             version: font_system.version(),
@@ -178,6 +210,12 @@ impl core::text::Paragraph for Paragraph {
             Some(new_bounds.height),
         );
 
+        text::limit_lines(
+            &mut paragraph.buffer,
+            font_system.raw(),
+            paragraph.max_lines,
+        );
+
         paragraph.bounds = new_bounds;
         paragraph.min_bounds = text::measure(&paragraph.buffer);
     }
@@ -192,12 +230,20 @@ impl core::text::Paragraph for Paragraph {
             || metrics.line_height != text.line_height.to_absolute(text.size).0
             || paragraph.font != text.font
             || paragraph.shaping != text.shaping
+            || paragraph.wrapping != text.wrapping
+            || paragraph.ellipsis != text.ellipsis
+            || paragraph.max_lines != text.max_lines
             || paragraph.horizontal_alignment != text.horizontal_alignment
             || paragraph.vertical_alignment != text.vertical_alignment
         {
             core::text::Difference::Shape
         } else if paragraph.bounds != text.bounds {
-            core::text::Difference::Bounds
+            // Truncated text needs to be truncated again from scratch
+            if paragraph.ellipsis == Ellipsis::None {
+                core::text::Difference::Bounds
+            } else {
+                core::text::Difference::Shape
+            }
         } else {
             core::text::Difference::None
         }
@@ -251,6 +297,20 @@ impl core::text::Paragraph for Paragraph {
         Some(glyph.metadata)
     }
 
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle> {
+        span_lines(&self.internal().buffer, index)
+            .into_iter()
+            .map(|(bounds, _baseline)| bounds)
+            .collect()
+    }
+
+    fn span_baselines(&self, index: usize) -> Vec<f32> {
+        span_lines(&self.internal().buffer, index)
+            .into_iter()
+            .map(|(_bounds, baseline)| baseline)
+            .collect()
+    }
+
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
         use unicode_segmentation::UnicodeSegmentation;
 
@@ -292,6 +352,67 @@ impl core::text::Paragraph for Paragraph {
             glyph.y - glyph.y_offset * glyph.font_size,
         ))
     }
+
+    fn hit_offset(&self, point: Point) -> Option<usize> {
+        let internal = self.internal();
+        let cursor = internal.buffer.hit(point.x, point.y)?;
+        let offset = line_offset(&internal.buffer, cursor.line) + cursor.index;
+
+        // Offsets past the ellipsis of truncated text are shifted back to
+        // their place in the original text
+        Some(match &internal.hidden {
+            Some(hidden) if offset > hidden.start => {
+                offset.saturating_sub(hidden.start + text::ELLIPSIS.len_utf8())
+                    + hidden.end
+            }
+            _ => offset,
+        })
+    }
+
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle> {
+        let internal = self.internal();
+        let buffer = &internal.buffer;
+
+        // Any part of the range hidden by the ellipsis of truncated text
+        // highlights the ellipsis instead
+        let (start, end) = match &internal.hidden {
+            Some(hidden) => {
+                let ellipsis = hidden.start + text::ELLIPSIS.len_utf8();
+
+                let shift = |offset: usize, is_end: bool| {
+                    if offset <= hidden.start {
+                        offset
+                    } else if offset >= hidden.end {
+                        offset - hidden.end + ellipsis
+                    } else if is_end {
+                        ellipsis
+                    } else {
+                        hidden.start
+                    }
+                };
+
+                (shift(range.start, false), shift(range.end, true))
+            }
+            None => (range.start, range.end),
+        };
+
+        let start = cursor_at(buffer, start);
+        let end = cursor_at(buffer, end);
+
+        buffer
+            .layout_runs()
+            .filter_map(|run| {
+                let (x, width) = run.highlight(start, end)?;
+
+                (width > 0.0).then_some(Rectangle {
+                    x,
+                    y: run.line_top,
+                    width,
+                    height: run.line_height,
+                })
+            })
+            .collect()
+    }
 }
 
 impl Default for Paragraph {
@@ -307,6 +428,9 @@ impl fmt::Debug for Paragraph {
         f.debug_struct("Paragraph")
             .field("font", &paragraph.font)
             .field("shaping", &paragraph.shaping)
+            .field("wrapping", &paragraph.wrapping)
+            .field("ellipsis", &paragraph.ellipsis)
+            .field("max_lines", &paragraph.max_lines)
             .field("horizontal_alignment", &paragraph.horizontal_alignment)
             .field("vertical_alignment", &paragraph.vertical_alignment)
             .field("bounds", &paragraph.bounds)
@@ -319,6 +443,9 @@ impl PartialEq for Internal {
     fn eq(&self, other: &Self) -> bool {
         self.font == other.font
             && self.shaping == other.shaping
+            && self.wrapping == other.wrapping
+            && self.ellipsis == other.ellipsis
+            && self.max_lines == other.max_lines
             && self.horizontal_alignment == other.horizontal_alignment
             && self.vertical_alignment == other.vertical_alignment
             && self.bounds == other.bounds
@@ -336,6 +463,10 @@ impl Default for Internal {
             }),
             font: Font::default(),
             shaping: Shaping::default(),
+            wrapping: Wrapping::default(),
+            ellipsis: Ellipsis::default(),
+            max_lines: None,
+            hidden: None,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             bounds: Size::ZERO,
@@ -372,3 +503,77 @@ impl PartialEq for Weak {
         }
     }
 }
+
+/// Returns the bounds and the baseline of each visual line of the buffer
+/// covered by the span with the given index.
+fn span_lines(
+    buffer: &cosmic_text::Buffer,
+    index: usize,
+) -> Vec<(Rectangle, f32)> {
+    let mut lines = Vec::new();
+
+    for run in buffer.layout_runs() {
+        let mut current: Option<Rectangle> = None;
+
+        for glyph in run.glyphs {
+            if glyph.metadata != index {
+                lines.extend(current.take().map(|bounds| (bounds, run.line_y)));
+                continue;
+            }
+
+            match &mut current {
+                Some(current) => {
+                    current.width = glyph.x + glyph.w - current.x;
+                }
+                None => {
+                    current = Some(Rectangle {
+                        x: glyph.x,
+                        y: run.line_top,
+                        width: glyph.w,
+                        height: run.line_height,
+                    });
+                }
+            }
+        }
+
+        lines.extend(current.map(|bounds| (bounds, run.line_y)));
+    }
+
+    lines
+}
+
+/// Returns the byte offset where the given line of the buffer starts.
+fn line_offset(buffer: &cosmic_text::Buffer, line: usize) -> usize {
+    buffer
+        .lines
+        .iter()
+        .take(line)
+        .map(|line| line.text().len() + line.ending().as_str().len())
+        .sum()
+}
+
+/// Returns the [`cosmic_text::Cursor`] at the given byte offset of the
+/// buffer.
+fn cursor_at(
+    buffer: &cosmic_text::Buffer,
+    offset: usize,
+) -> cosmic_text::Cursor {
+    let mut start = 0;
+
+    for (i, line) in buffer.lines.iter().enumerate() {
+        let end = start + line.text().len();
+
+        if offset <= end {
+            return cosmic_text::Cursor::new(i, offset.saturating_sub(start));
+        }
+
+        start = end + line.ending().as_str().len();
+    }
+
+    let last = buffer.lines.len().saturating_sub(1);
+
+    cosmic_text::Cursor::new(
+        last,
+        buffer.lines.last().map_or(0, |line| line.text().len()),
+    )
+}
@@ -635,3 +635,30 @@ where
 {
     type Compositor = Compositor<A::Compositor, B::Compositor>;
 }
+
+/// A headless [`Renderer`] always uses its secondary (or fallback) option,
+/// since the primary one may need a window to be created.
+impl<A, B> renderer::Headless for Renderer<A, B>
+where
+    B: renderer::Headless,
+{
+    fn new(default_font: core::Font, default_text_size: core::Pixels) -> Self {
+        Self::Secondary(B::new(default_font, default_text_size))
+    }
+
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f32,
+        background_color: Color,
+    ) -> Vec<u8> {
+        match self {
+            Self::Primary(_) => {
+                panic!("the primary renderer cannot be used headlessly")
+            }
+            Self::Secondary(renderer) => {
+                renderer.screenshot(size, scale_factor, background_color)
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Implement your own event loop to drive a user interface.
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
@@ -115,6 +116,10 @@ where
     /// It returns __messages__ that may have been produced as a result of user
     /// interactions. You should feed these to your __update logic__.
     ///
+    /// Any <kbd>Tab</kbd> key press that is ignored by the widgets will move
+    /// the focus to the next focusable widget—or the previous one, if
+    /// <kbd>Shift</kbd> is held.
+    ///
     /// # Example
     /// Let's allow our [counter](index.html#usage) to change state by
     /// completing [the previous example](#example):
@@ -297,7 +302,7 @@ where
 
         let _ = ManuallyDrop::into_inner(manual_overlay);
 
-        let event_statuses = events
+        let mut event_statuses: Vec<_> = events
             .iter()
             .cloned()
             .zip(overlay_statuses)
@@ -351,6 +356,34 @@ where
             })
             .collect();
 
+        for (event, status) in events.iter().zip(event_statuses.iter_mut()) {
+            if let (
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(keyboard::key::Named::Tab),
+                    modifiers,
+                    ..
+                }),
+                event::Status::Ignored,
+            ) = (event, *status)
+            {
+                if modifiers.control() || modifiers.alt() || modifiers.logo() {
+                    continue;
+                }
+
+                let operation: Box<dyn widget::Operation<()>> =
+                    if modifiers.shift() {
+                        Box::new(widget::operation::focusable::focus_previous())
+                    } else {
+                        Box::new(widget::operation::focusable::focus_next())
+                    };
+
+                self.operate_chain(renderer, operation);
+
+                *status = event::Status::Captured;
+                redraw_request = Some(window::RedrawRequest::NextFrame);
+            }
+        }
+
         (
             if outdated {
                 State::Outdated
@@ -598,6 +631,24 @@ where
         }
     }
 
+    fn operate_chain(
+        &mut self,
+        renderer: &Renderer,
+        mut operation: Box<dyn widget::Operation<()>>,
+    ) {
+        loop {
+            self.operate(renderer, operation.as_mut());
+
+            match operation.finish() {
+                widget::operation::Outcome::Chain(next) => {
+                    operation = next;
+                }
+                widget::operation::Outcome::None
+                | widget::operation::Outcome::Some(()) => break,
+            }
+        }
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
@@ -3,6 +3,7 @@ pub mod screenshot;
 
 pub use screenshot::Screenshot;
 
+use crate::core::drag_and_drop;
 use crate::core::time::Instant;
 use crate::core::window::{
     Event, Icon, Id, Level, Mode, Settings, UserAttention,
@@ -39,6 +40,13 @@ pub enum Action {
     /// button was pressed immediately before this function is called.
     Drag(Id),
 
+    /// Start dragging some data out of the window, to be dropped in
+    /// another application.
+    ///
+    /// Like [`Action::Drag`], there's no guarantee that this will work
+    /// unless the left mouse button is held.
+    DragAndDrop(Id, drag_and_drop::Data, oneshot::Sender<bool>),
+
     /// Resize the window to the given logical dimensions.
     Resize(Id, Size),
 
@@ -246,6 +254,18 @@ pub fn drag<T>(id: Id) -> Task<T> {
     task::effect(crate::Action::Window(Action::Drag(id)))
 }
 
+/// Begins dragging the given data out of the window while the left mouse
+/// button is held.
+///
+/// The [`Task`] produces `true` if the data was dropped in some target, or
+/// `false` if the drag was cancelled or is not supported by the windowing
+/// backend.
+pub fn drag_and_drop(id: Id, data: drag_and_drop::Data) -> Task<bool> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::DragAndDrop(id, data, channel))
+    })
+}
+
 /// Resizes the window to the given logical dimensions.
 pub fn resize<T>(id: Id, new_size: Size) -> Task<T> {
     task::effect(crate::Action::Window(Action::Resize(id, new_size)))
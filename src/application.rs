@@ -30,7 +30,9 @@
 //!     ]
 //! }
 //! ```
+use crate::core::renderer::Headless;
 use crate::program::{self, Program};
+use crate::test;
 use crate::window;
 use crate::{Element, Font, Result, Settings, Size, Subscription, Task};
 
@@ -70,7 +72,14 @@ pub fn application<State, Message, Theme, Renderer>(
     title: impl Title<State>,
     update: impl Update<State, Message>,
     view: impl for<'a> self::View<'a, State, Message, Theme, Renderer>,
-) -> Application<impl Program<State = State, Message = Message, Theme = Theme>>
+) -> Application<
+    impl Program<
+        State = State,
+        Message = Message,
+        Theme = Theme,
+        Renderer = Renderer,
+    >,
+>
 where
     State: 'static,
     Message: Send + std::fmt::Debug + 'static,
@@ -175,6 +184,20 @@ impl<P: Program> Application<P> {
             .run_with(self.settings, Some(self.window), initialize)
     }
 
+    /// Creates a headless [`Simulator`] of the [`Application`] with the
+    /// given initial state.
+    ///
+    /// The [`Simulator`] does not need a window and can be used to test
+    /// the [`Application`] in-process.
+    ///
+    /// [`Simulator`]: crate::test::Simulator
+    pub fn simulate(self, state: P::State) -> test::Simulator<P>
+    where
+        P::Renderer: Headless,
+    {
+        test::Simulator::new(self.raw, &self.settings, self.window.size, state)
+    }
+
     /// Sets the [`Settings`] that will be used to run the [`Application`].
     pub fn settings(self, settings: Settings) -> Self {
         Self { settings, ..self }
@@ -308,7 +331,12 @@ impl<P: Program> Application<P> {
         self,
         title: impl Title<P::State>,
     ) -> Application<
-        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+        impl Program<
+            State = P::State,
+            Message = P::Message,
+            Theme = P::Theme,
+            Renderer = P::Renderer,
+        >,
     > {
         Application {
             raw: program::with_title(self.raw, move |state, _window| {
@@ -324,7 +352,12 @@ impl<P: Program> Application<P> {
         self,
         f: impl Fn(&P::State) -> Subscription<P::Message>,
     ) -> Application<
-        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+        impl Program<
+            State = P::State,
+            Message = P::Message,
+            Theme = P::Theme,
+            Renderer = P::Renderer,
+        >,
     > {
         Application {
             raw: program::with_subscription(self.raw, f),
@@ -338,7 +371,12 @@ impl<P: Program> Application<P> {
         self,
         f: impl Fn(&P::State) -> P::Theme,
     ) -> Application<
-        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+        impl Program<
+            State = P::State,
+            Message = P::Message,
+            Theme = P::Theme,
+            Renderer = P::Renderer,
+        >,
     > {
         Application {
             raw: program::with_theme(self.raw, move |state, _window| f(state)),
@@ -352,7 +390,12 @@ impl<P: Program> Application<P> {
         self,
         f: impl Fn(&P::State, &P::Theme) -> Appearance,
     ) -> Application<
-        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+        impl Program<
+            State = P::State,
+            Message = P::Message,
+            Theme = P::Theme,
+            Renderer = P::Renderer,
+        >,
     > {
         Application {
             raw: program::with_style(self.raw, f),
@@ -366,7 +409,12 @@ impl<P: Program> Application<P> {
         self,
         f: impl Fn(&P::State) -> f64,
     ) -> Application<
-        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+        impl Program<
+            State = P::State,
+            Message = P::Message,
+            Theme = P::Theme,
+            Renderer = P::Renderer,
+        >,
     > {
         Application {
             raw: program::with_scale_factor(self.raw, move |state, _window| {
@@ -162,7 +162,12 @@ impl<P: Program> Daemon<P> {
         self,
         title: impl Title<P::State>,
     ) -> Daemon<
-        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+        impl Program<
+            State = P::State,
+            Message = P::Message,
+            Theme = P::Theme,
+            Renderer = P::Renderer,
+        >,
     > {
         Daemon {
             raw: program::with_title(self.raw, move |state, window| {
@@ -177,7 +182,12 @@ impl<P: Program> Daemon<P> {
         self,
         f: impl Fn(&P::State) -> Subscription<P::Message>,
     ) -> Daemon<
-        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+        impl Program<
+            State = P::State,
+            Message = P::Message,
+            Theme = P::Theme,
+            Renderer = P::Renderer,
+        >,
     > {
         Daemon {
             raw: program::with_subscription(self.raw, f),
@@ -190,7 +200,12 @@ impl<P: Program> Daemon<P> {
         self,
         f: impl Fn(&P::State, window::Id) -> P::Theme,
     ) -> Daemon<
-        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+        impl Program<
+            State = P::State,
+            Message = P::Message,
+            Theme = P::Theme,
+            Renderer = P::Renderer,
+        >,
     > {
         Daemon {
             raw: program::with_theme(self.raw, f),
@@ -203,7 +218,12 @@ impl<P: Program> Daemon<P> {
         self,
         f: impl Fn(&P::State, &P::Theme) -> Appearance,
     ) -> Daemon<
-        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+        impl Program<
+            State = P::State,
+            Message = P::Message,
+            Theme = P::Theme,
+            Renderer = P::Renderer,
+        >,
     > {
         Daemon {
             raw: program::with_style(self.raw, f),
@@ -216,7 +236,12 @@ impl<P: Program> Daemon<P> {
         self,
         f: impl Fn(&P::State, window::Id) -> f64,
     ) -> Daemon<
-        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+        impl Program<
+            State = P::State,
+            Message = P::Message,
+            Theme = P::Theme,
+            Renderer = P::Renderer,
+        >,
     > {
         Daemon {
             raw: program::with_scale_factor(self.raw, f),
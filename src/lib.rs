@@ -467,6 +467,7 @@ mod program;
 pub mod application;
 pub mod daemon;
 pub mod settings;
+pub mod test;
 pub mod time;
 pub mod window;
 
@@ -474,15 +475,16 @@ pub mod window;
 pub mod advanced;
 
 pub use crate::core::alignment;
+pub use crate::core::animation;
 pub use crate::core::border;
 pub use crate::core::color;
 pub use crate::core::gradient;
 pub use crate::core::padding;
 pub use crate::core::theme;
 pub use crate::core::{
-    Alignment, Background, Border, Color, ContentFit, Degrees, Gradient,
-    Length, Padding, Pixels, Point, Radians, Rectangle, Rotation, Shadow, Size,
-    Theme, Transformation, Vector,
+    Alignment, Animation, Background, Border, Color, ContentFit, Degrees,
+    Gradient, Length, Padding, Pixels, Point, Radians, Rectangle, Rotation,
+    Shadow, Size, Theme, Transformation, Vector,
 };
 pub use crate::runtime::exit;
 pub use iced_futures::Subscription;
@@ -499,11 +501,17 @@ pub mod task {
 
 pub mod clipboard {
     //! Access the clipboard.
+    pub use crate::core::clipboard::Kind;
     pub use crate::runtime::clipboard::{
         read, read_primary, write, write_primary,
     };
 }
 
+pub mod drag_and_drop {
+    //! Exchange data with other applications by dragging and dropping.
+    pub use crate::core::drag_and_drop::Data;
+}
+
 pub mod executor {
     //! Choose your preferred executor to power your application.
     pub use iced_futures::Executor;
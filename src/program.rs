@@ -196,7 +196,12 @@ pub trait Program: Sized {
 pub fn with_title<P: Program>(
     program: P,
     title: impl Fn(&P::State, window::Id) -> String,
-) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+) -> impl Program<
+    State = P::State,
+    Message = P::Message,
+    Theme = P::Theme,
+    Renderer = P::Renderer,
+> {
     struct WithTitle<P, Title> {
         program: P,
         title: Title,
@@ -267,7 +272,12 @@ pub fn with_title<P: Program>(
 pub fn with_subscription<P: Program>(
     program: P,
     f: impl Fn(&P::State) -> Subscription<P::Message>,
-) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+) -> impl Program<
+    State = P::State,
+    Message = P::Message,
+    Theme = P::Theme,
+    Renderer = P::Renderer,
+> {
     struct WithSubscription<P, F> {
         program: P,
         subscription: F,
@@ -340,7 +350,12 @@ pub fn with_subscription<P: Program>(
 pub fn with_theme<P: Program>(
     program: P,
     f: impl Fn(&P::State, window::Id) -> P::Theme,
-) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+) -> impl Program<
+    State = P::State,
+    Message = P::Message,
+    Theme = P::Theme,
+    Renderer = P::Renderer,
+> {
     struct WithTheme<P, F> {
         program: P,
         theme: F,
@@ -410,7 +425,12 @@ pub fn with_theme<P: Program>(
 pub fn with_style<P: Program>(
     program: P,
     f: impl Fn(&P::State, &P::Theme) -> Appearance,
-) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+) -> impl Program<
+    State = P::State,
+    Message = P::Message,
+    Theme = P::Theme,
+    Renderer = P::Renderer,
+> {
     struct WithStyle<P, F> {
         program: P,
         style: F,
@@ -480,7 +500,12 @@ pub fn with_style<P: Program>(
 pub fn with_scale_factor<P: Program>(
     program: P,
     f: impl Fn(&P::State, window::Id) -> f64,
-) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+) -> impl Program<
+    State = P::State,
+    Message = P::Message,
+    Theme = P::Theme,
+    Renderer = P::Renderer,
+> {
     struct WithScaleFactor<P, F> {
         program: P,
         scale_factor: F,
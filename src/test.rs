@@ -0,0 +1,684 @@
+//! Test your applications headlessly, without a window.
+//!
+//! A [`Simulator`] runs the `view` and `update` logic of an [`Application`]
+//! fully in-process. You can use it to find widgets with a [`Selector`],
+//! inject events—like clicks, key presses or typed text—and assert on the
+//! resulting state.
+//!
+//! # Example
+//! ```
+//! use iced::widget::{button, text, Column};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Increment,
+//! }
+//!
+//! fn update(counter: &mut u64, message: Message) {
+//!     match message {
+//!         Message::Increment => *counter += 1,
+//!     }
+//! }
+//!
+//! fn view(counter: &u64) -> Column<Message> {
+//!     Column::new()
+//!         .push(text(counter))
+//!         .push(button("+").on_press(Message::Increment))
+//! }
+//!
+//! let mut simulator =
+//!     iced::application("A counter", update, view).simulate(0);
+//!
+//! simulator.click("+")?;
+//!
+//! assert_eq!(simulator.state(), &1);
+//! # Ok::<(), iced::test::Error>(())
+//! ```
+//!
+//! # Snapshots
+//! A [`Simulator`] can also render the current user interface on the CPU and
+//! produce a [`Snapshot`] of it. With the `image` feature enabled, you can
+//! compare a [`Snapshot`] against a golden PNG file with
+//! [`Snapshot::matches_png`].
+//!
+//! Golden files are never written implicitly; run your tests with the
+//! `ICED_TEST_UPDATE_GOLDENS` environment variable set to create or update
+//! them.
+//!
+//! [`Application`]: crate::Application
+pub use crate::core::widget::operation::selector::{Kind, Selector, Target};
+pub use crate::core::widget::Id;
+
+use crate::core::clipboard::{self, Clipboard};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::renderer::{self, Headless};
+use crate::core::widget::operation::selector;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::window;
+use crate::core::{Point, Size, SmolStr};
+use crate::program::Program;
+use crate::runtime::user_interface::{self, UserInterface};
+use crate::runtime::{self, Action};
+use crate::{Settings, Task};
+
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+/// The default amount of time a [`Simulator`] waits for a [`Task`].
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A headless runner of an [`Application`].
+///
+/// You can create a [`Simulator`] with [`Application::simulate`].
+///
+/// Any [`Task`] produced by the `update` logic will be run to completion in
+/// the current thread, or until the timeout of the [`Simulator`] elapses;
+/// see [`Simulator::set_timeout`]. Only widget operations and clipboard
+/// actions are performed; any other runtime actions are ignored.
+///
+/// [`Application`]: crate::Application
+/// [`Application::simulate`]: crate::Application::simulate
+#[allow(missing_debug_implementations)]
+pub struct Simulator<P: Program> {
+    program: P,
+    state: P::State,
+    window: window::Id,
+    size: Size,
+    renderer: P::Renderer,
+    cursor: mouse::Cursor,
+    modifiers: keyboard::Modifiers,
+    clipboard: Memory,
+    cache: Option<user_interface::Cache>,
+    timeout: Duration,
+}
+
+impl<P> Simulator<P>
+where
+    P: Program,
+    P::Renderer: Headless,
+{
+    pub(crate) fn new(
+        program: P,
+        settings: &Settings,
+        size: Size,
+        state: P::State,
+    ) -> Self {
+        for font in &settings.fonts {
+            crate::graphics::text::font_system()
+                .write()
+                .expect("Write to font system")
+                .load_font(font.clone());
+        }
+
+        Self {
+            program,
+            state,
+            window: window::Id::unique(),
+            size,
+            renderer: P::Renderer::new(
+                settings.default_font,
+                settings.default_text_size,
+            ),
+            cursor: mouse::Cursor::Unavailable,
+            modifiers: keyboard::Modifiers::default(),
+            clipboard: Memory::default(),
+            cache: Some(user_interface::Cache::default()),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Returns the current state of the [`Simulator`].
+    pub fn state(&self) -> &P::State {
+        &self.state
+    }
+
+    /// Consumes the [`Simulator`] and returns its current state.
+    pub fn into_state(self) -> P::State {
+        self.state
+    }
+
+    /// Returns the current size of the simulated window.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Resizes the simulated window.
+    pub fn resize(&mut self, size: Size) {
+        self.size = size;
+
+        let _ = self.simulate([Event::Window(window::Event::Resized(size))]);
+    }
+
+    /// Sets the maximum amount of time the [`Simulator`] will spend running
+    /// a single [`Task`].
+    ///
+    /// Any [`Task`] that does not finish in time—like a never-ending stream
+    /// of timer ticks—is dropped once the timeout elapses. By default, it is
+    /// one second.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Returns the current contents of the simulated clipboard.
+    pub fn clipboard(&self, kind: clipboard::Kind) -> Option<&str> {
+        self.clipboard.contents(kind)
+    }
+
+    /// Finds the first widget matching the given [`Selector`].
+    ///
+    /// A [`Selector`] can match widgets by [`Id`], by the text they display,
+    /// or by their [`Kind`].
+    pub fn find(&mut self, selector: impl Into<Selector>) -> Option<Target> {
+        self.query(selector::find(selector.into())).flatten()
+    }
+
+    /// Finds all the widgets matching the given [`Selector`].
+    pub fn find_all(&mut self, selector: impl Into<Selector>) -> Vec<Target> {
+        self.query(selector::find_all(selector.into()))
+            .unwrap_or_default()
+    }
+
+    /// Draws the current user interface and takes a [`Snapshot`] of it.
+    ///
+    /// The user interface is rendered on the CPU, using the theme, style and
+    /// scale factor of the [`Application`].
+    ///
+    /// [`Application`]: crate::Application
+    pub fn snapshot(&mut self) -> Snapshot {
+        let theme = self.program.theme(&self.state, self.window);
+        let appearance = self.program.style(&self.state, &theme);
+        let scale_factor =
+            self.program.scale_factor(&self.state, self.window) as f32;
+
+        let mut user_interface = UserInterface::build(
+            self.program.view(&self.state, self.window),
+            self.size,
+            self.cache.take().unwrap_or_default(),
+            &mut self.renderer,
+        );
+
+        let _ = user_interface.draw(
+            &mut self.renderer,
+            &theme,
+            &renderer::Style {
+                text_color: appearance.text_color,
+            },
+            self.cursor,
+        );
+
+        self.cache = Some(user_interface.into_cache());
+
+        let size = Size::new(
+            (self.size.width * scale_factor).round() as u32,
+            (self.size.height * scale_factor).round() as u32,
+        );
+
+        let rgba = self.renderer.screenshot(
+            size,
+            scale_factor,
+            appearance.background_color,
+        );
+
+        Snapshot { size, rgba }
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn point_at(&mut self, position: impl Into<Point>) {
+        let position = position.into();
+
+        self.cursor = mouse::Cursor::Available(position);
+
+        let _ = self
+            .simulate([Event::Mouse(mouse::Event::CursorMoved { position })]);
+    }
+
+    /// Clicks the center of the first widget matching the given
+    /// [`Selector`].
+    ///
+    /// Returns the clicked [`Target`].
+    pub fn click(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let selector = selector.into();
+        let target = self
+            .find(selector.clone())
+            .ok_or(Error::NotFound(selector))?;
+
+        self.click_at(target.center());
+
+        Ok(target)
+    }
+
+    /// Clicks the given position with the left mouse button.
+    pub fn click_at(&mut self, position: impl Into<Point>) {
+        self.point_at(position);
+
+        let _ = self.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+    }
+
+    /// Scrolls the mouse wheel at the current cursor position.
+    pub fn scroll(&mut self, delta: mouse::ScrollDelta) {
+        let _ = self
+            .simulate([Event::Mouse(mouse::Event::WheelScrolled { delta })]);
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn typewrite(&mut self, text: &str) {
+        for character in text.chars() {
+            let text = SmolStr::new(character.encode_utf8(&mut [0; 4]));
+
+            self.press(keyboard::Key::Character(text.clone()), Some(text));
+        }
+    }
+
+    /// Presses and releases the given [`keyboard::Key`].
+    pub fn tap_key(&mut self, key: impl Into<keyboard::Key>) {
+        self.press(key.into(), None);
+    }
+
+    /// Changes the state of the modifier keys.
+    pub fn set_modifiers(&mut self, modifiers: keyboard::Modifiers) {
+        self.modifiers = modifiers;
+
+        let _ = self.simulate([Event::Keyboard(
+            keyboard::Event::ModifiersChanged(modifiers),
+        )]);
+    }
+
+    fn press(&mut self, key: keyboard::Key, text: Option<SmolStr>) {
+        let _ = self.simulate([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: key.clone(),
+                location: keyboard::Location::Standard,
+                modifiers: self.modifiers,
+                text,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key,
+                location: keyboard::Location::Standard,
+                modifiers: self.modifiers,
+            }),
+        ]);
+    }
+
+    /// Processes the given events and feeds any produced messages to the
+    /// `update` logic of the [`Application`].
+    ///
+    /// Returns the [`event::Status`] of each event.
+    ///
+    /// [`Application`]: crate::Application
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        let events: Vec<Event> = events.into_iter().collect();
+        let mut messages = Vec::new();
+
+        let mut user_interface = UserInterface::build(
+            self.program.view(&self.state, self.window),
+            self.size,
+            self.cache.take().unwrap_or_default(),
+            &mut self.renderer,
+        );
+
+        let (_state, statuses) = user_interface.update(
+            &events,
+            self.cursor,
+            &mut self.renderer,
+            &mut self.clipboard,
+            &mut messages,
+        );
+
+        self.cache = Some(user_interface.into_cache());
+
+        for message in messages {
+            self.update(message);
+        }
+
+        statuses
+    }
+
+    /// Feeds the given message to the `update` logic of the [`Application`]
+    /// and runs the resulting [`Task`] to completion.
+    ///
+    /// [`Application`]: crate::Application
+    pub fn update(&mut self, message: P::Message) {
+        let task = self.program.update(&mut self.state, message);
+
+        self.run(task);
+    }
+
+    /// Runs the given [`Task`] to completion, feeding its output to the
+    /// `update` logic of the [`Application`].
+    ///
+    /// The [`Task`] is dropped if it does not finish before the timeout of
+    /// the [`Simulator`] elapses.
+    ///
+    /// [`Application`]: crate::Application
+    pub fn run(&mut self, task: Task<P::Message>) {
+        let Some(mut stream) = runtime::task::into_stream(task) else {
+            return;
+        };
+
+        let deadline = Instant::now() + self.timeout;
+        let waker = Waker::from(Arc::new(Unparker(thread::current())));
+        let mut context = Context::from_waker(&waker);
+
+        while let Some(remaining) =
+            deadline.checked_duration_since(Instant::now())
+        {
+            let action = match stream.as_mut().poll_next(&mut context) {
+                Poll::Ready(Some(action)) => action,
+                Poll::Ready(None) => break,
+                Poll::Pending => {
+                    thread::park_timeout(remaining);
+                    continue;
+                }
+            };
+
+            match action {
+                Action::Output(message) => {
+                    self.update(message);
+                }
+                Action::Widget(mut operation) => {
+                    self.operate(operation.as_mut());
+                }
+                Action::Clipboard(runtime::clipboard::Action::Read {
+                    target,
+                    channel,
+                }) => {
+                    let _ = channel.send(self.clipboard.read(target));
+                }
+                Action::Clipboard(runtime::clipboard::Action::Write {
+                    target,
+                    contents,
+                }) => {
+                    self.clipboard.write(target, contents);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Applies a [`widget::Operation`] to the current user interface,
+    /// following any chained operations.
+    pub fn operate(&mut self, operation: &mut dyn Operation<()>) {
+        let mut user_interface = UserInterface::build(
+            self.program.view(&self.state, self.window),
+            self.size,
+            self.cache.take().unwrap_or_default(),
+            &mut self.renderer,
+        );
+
+        user_interface.operate(&self.renderer, operation);
+
+        let mut next = match operation.finish() {
+            operation::Outcome::Chain(next) => Some(next),
+            operation::Outcome::None | operation::Outcome::Some(()) => None,
+        };
+
+        while let Some(mut operation) = next.take() {
+            user_interface.operate(&self.renderer, operation.as_mut());
+
+            if let operation::Outcome::Chain(chained) = operation.finish() {
+                next = Some(chained);
+            }
+        }
+
+        self.cache = Some(user_interface.into_cache());
+    }
+
+    fn query<T>(&mut self, operation: impl Operation<T> + 'static) -> Option<T>
+    where
+        T: Send + 'static,
+    {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let mut operation =
+            operation::map(Box::new(operation), move |output| {
+                let _ = sender.send(output);
+            });
+
+        self.operate(&mut operation);
+
+        receiver.try_recv().ok()
+    }
+}
+
+/// Wakes up the thread of a [`Simulator`] waiting for a [`Task`].
+struct Unparker(Thread);
+
+impl Wake for Unparker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// A rendered image of the user interface of a [`Simulator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    size: Size<u32>,
+    rgba: Vec<u8>,
+}
+
+impl Snapshot {
+    /// Creates a [`Snapshot`] from the given RGBA pixels.
+    ///
+    /// # Panics
+    /// Panics if the length of the pixels does not match the given size.
+    pub fn new(size: Size<u32>, rgba: Vec<u8>) -> Self {
+        assert_eq!(
+            rgba.len(),
+            size.width as usize * size.height as usize * 4,
+            "the pixels of a snapshot must match its size"
+        );
+
+        Self { size, rgba }
+    }
+
+    /// Returns the physical size of the [`Snapshot`].
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    /// Returns the RGBA pixels of the [`Snapshot`].
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+
+    /// Compares the [`Snapshot`] against the expected one, allowing each
+    /// color channel of a pixel to differ by the given tolerance.
+    ///
+    /// Returns `None` if both snapshots match. Otherwise, returns a diff
+    /// [`Snapshot`] where the mismatching pixels are painted red on top of a
+    /// faded copy of this [`Snapshot`].
+    pub fn diff(&self, expected: &Snapshot, tolerance: u8) -> Option<Snapshot> {
+        if self.size != expected.size {
+            return Some(Snapshot {
+                size: self.size,
+                rgba: self
+                    .rgba
+                    .chunks_exact(4)
+                    .flat_map(|_| MISMATCH)
+                    .collect(),
+            });
+        }
+
+        let mut has_mismatches = false;
+
+        let rgba = self
+            .rgba
+            .chunks_exact(4)
+            .zip(expected.rgba.chunks_exact(4))
+            .flat_map(|(actual, expected)| {
+                let is_mismatch =
+                    actual.iter().zip(expected).any(|(actual, expected)| {
+                        actual.abs_diff(*expected) > tolerance
+                    });
+
+                if is_mismatch {
+                    has_mismatches = true;
+
+                    MISMATCH
+                } else {
+                    [actual[0] / 4, actual[1] / 4, actual[2] / 4, 0xFF]
+                }
+            })
+            .collect();
+
+        has_mismatches.then_some(Snapshot {
+            size: self.size,
+            rgba,
+        })
+    }
+
+    /// Compares the [`Snapshot`] against the golden PNG file at the given
+    /// path, allowing each color channel of a pixel to differ by the given
+    /// tolerance.
+    ///
+    /// If the golden file does not exist, an [`Error::GoldenMissing`] is
+    /// returned. When the `ICED_TEST_UPDATE_GOLDENS` environment variable is
+    /// set, the [`Snapshot`] is saved as the new golden file instead and
+    /// considered a match.
+    ///
+    /// If the [`Snapshot`] does not match, a diff image is written next to
+    /// the golden file with a `.diff.png` extension.
+    #[cfg(feature = "image")]
+    pub fn matches_png(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        tolerance: u8,
+    ) -> Result<bool, Error> {
+        let path = path.as_ref();
+
+        if std::env::var_os(UPDATE_GOLDENS).is_some() {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            self.save_png(path)?;
+
+            return Ok(true);
+        }
+
+        if !path.exists() {
+            return Err(Error::GoldenMissing(path.to_path_buf()));
+        }
+
+        let golden = image::open(path)?.to_rgba8();
+        let expected = Snapshot {
+            size: Size::new(golden.width(), golden.height()),
+            rgba: golden.into_raw(),
+        };
+
+        let Some(diff) = self.diff(&expected, tolerance) else {
+            return Ok(true);
+        };
+
+        diff.save_png(path.with_extension("diff.png"))?;
+
+        Ok(false)
+    }
+
+    /// Saves the [`Snapshot`] as a PNG file at the given path.
+    #[cfg(feature = "image")]
+    pub fn save_png(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<(), Error> {
+        image::save_buffer_with_format(
+            path,
+            &self.rgba,
+            self.size.width,
+            self.size.height,
+            image::ColorType::Rgba8,
+            image::ImageFormat::Png,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// The color of the mismatching pixels in a diff [`Snapshot`].
+const MISMATCH: [u8; 4] = [0xFF, 0x00, 0x00, 0xFF];
+
+/// The environment variable that makes [`Snapshot::matches_png`] write the
+/// golden files instead of comparing against them.
+#[cfg(feature = "image")]
+const UPDATE_GOLDENS: &str = "ICED_TEST_UPDATE_GOLDENS";
+
+/// An error produced by a [`Simulator`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// No widget matching the given [`Selector`] could be found.
+    #[error("no widget found matching: {0:?}")]
+    NotFound(Selector),
+
+    /// The golden file of a snapshot does not exist.
+    #[cfg(feature = "image")]
+    #[error(
+        "golden snapshot not found: {0:?} \
+         (set ICED_TEST_UPDATE_GOLDENS to create it)"
+    )]
+    GoldenMissing(std::path::PathBuf),
+
+    /// A snapshot could not be read or written.
+    #[cfg(feature = "image")]
+    #[error("snapshot I/O failed: {0}")]
+    Io(String),
+
+    /// The `image` crate reported an error.
+    #[cfg(feature = "image")]
+    #[error("snapshot image could not be processed: {0}")]
+    Image(String),
+}
+
+#[cfg(feature = "image")]
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error.to_string())
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<image::error::ImageError> for Error {
+    fn from(error: image::error::ImageError) -> Self {
+        Self::Image(error.to_string())
+    }
+}
+
+#[derive(Debug, Default)]
+struct Memory {
+    standard: Option<String>,
+    primary: Option<String>,
+}
+
+impl Memory {
+    fn contents(&self, kind: clipboard::Kind) -> Option<&str> {
+        match kind {
+            clipboard::Kind::Standard => self.standard.as_deref(),
+            clipboard::Kind::Primary => self.primary.as_deref(),
+        }
+    }
+}
+
+impl Clipboard for Memory {
+    fn read(&self, kind: clipboard::Kind) -> Option<String> {
+        self.contents(kind).map(str::to_owned)
+    }
+
+    fn write(&mut self, kind: clipboard::Kind, contents: String) {
+        match kind {
+            clipboard::Kind::Standard => self.standard = Some(contents),
+            clipboard::Kind::Primary => self.primary = Some(contents),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Simulate the rich text widget with inline elements.
+use iced::clipboard;
+use iced::keyboard;
+use iced::widget::text::{Rich, Span};
+use iced::widget::{column, container, text};
+use iced::Element;
+
+fn inlines(_state: &()) -> Element<'_, ()> {
+    column![
+        Rich::<(), ()>::new()
+            .push_inline(container(text("A")).width(40).height(30))
+            .push_inline(container(text("B")).width(25).height(10)),
+        Rich::<(), ()>::new()
+            .push(Span::new("Hi "))
+            .push_inline(container(text("C")).width(10).height(10))
+            .push(Span::new("there"))
+            .selectable(true),
+    ]
+    .into()
+}
+
+#[test]
+fn places_inlines() {
+    let mut simulator =
+        iced::application("Inlines", |_: &mut (), _: ()| {}, inlines)
+            .simulate(());
+
+    let a = simulator.find("A").expect("A is visible").bounds;
+    let b = simulator.find("B").expect("B is visible").bounds;
+
+    assert_eq!((a.x, a.y), (0.0, 0.0));
+    assert_eq!((b.x, b.y), (40.0, 10.0));
+}
+
+#[test]
+fn copies_without_inlines() {
+    let mut simulator =
+        iced::application("Inlines", |_: &mut (), _: ()| {}, inlines)
+            .simulate(());
+
+    let _ = simulator.click("C").expect("C is visible");
+    simulator.set_modifiers(keyboard::Modifiers::COMMAND);
+    simulator.tap_key(keyboard::Key::Character("a".into()));
+    simulator.tap_key(keyboard::Key::Character("c".into()));
+
+    assert_eq!(
+        simulator.clipboard(clipboard::Kind::Standard),
+        Some("Hi there")
+    );
+}
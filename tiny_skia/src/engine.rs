@@ -402,6 +402,9 @@ impl Engine {
                 horizontal_alignment,
                 vertical_alignment,
                 shaping,
+                wrapping,
+                ellipsis,
+                max_lines,
                 clip_bounds: text_bounds, // TODO
             } => {
                 let physical_bounds = *text_bounds * transformation;
@@ -423,6 +426,9 @@ impl Engine {
                     *horizontal_alignment,
                     *vertical_alignment,
                     *shaping,
+                    *wrapping,
+                    *ellipsis,
+                    *max_lines,
                     pixels,
                     clip_mask,
                     transformation,
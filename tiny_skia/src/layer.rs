@@ -89,6 +89,9 @@ impl Layer {
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            wrapping: text.wrapping,
+            ellipsis: text.ellipsis,
+            max_lines: text.max_lines,
             clip_bounds: clip_bounds * transformation,
         };
 
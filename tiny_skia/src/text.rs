@@ -1,5 +1,5 @@
 use crate::core::alignment;
-use crate::core::text::Shaping;
+use crate::core::text::{Ellipsis, Shaping, Wrapping};
 use crate::core::{
     Color, Font, Pixels, Point, Rectangle, Size, Transformation,
 };
@@ -111,6 +111,9 @@ impl Pipeline {
         horizontal_alignment: alignment::Horizontal,
         vertical_alignment: alignment::Vertical,
         shaping: Shaping,
+        wrapping: Wrapping,
+        ellipsis: Ellipsis,
+        max_lines: Option<usize>,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
         transformation: Transformation,
@@ -127,6 +130,9 @@ impl Pipeline {
             size: size.into(),
             line_height,
             shaping,
+            wrapping,
+            ellipsis,
+            max_lines,
         };
 
         let (_, entry) = self.cache.get_mut().allocate(font_system, key);
@@ -216,6 +216,24 @@ pub fn screenshot<T: AsRef<str>>(
     viewport: &Viewport,
     background_color: Color,
     overlay: &[T],
+) -> Vec<u8> {
+    offscreen(
+        renderer,
+        &mut surface.clip_mask,
+        viewport,
+        background_color,
+        overlay,
+    )
+}
+
+/// Draws the primitives of the [`Renderer`] into an offscreen RGBA buffer,
+/// without the need of a [`Surface`].
+pub fn offscreen<T: AsRef<str>>(
+    renderer: &mut Renderer,
+    clip_mask: &mut tiny_skia::Mask,
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
 ) -> Vec<u8> {
     let size = viewport.physical_size();
 
@@ -229,7 +247,7 @@ pub fn screenshot<T: AsRef<str>>(
             size.height,
         )
         .expect("Create offscreen pixel map"),
-        &mut surface.clip_mask,
+        clip_mask,
         viewport,
         &[Rectangle::with_size(Size::new(
             size.width as f32,
@@ -1,5 +1,5 @@
 //! Build and draw geometry.
-use crate::core::text::LineHeight;
+use crate::core::text::{Ellipsis, LineHeight, Wrapping};
 use crate::core::{
     Pixels, Point, Radians, Rectangle, Size, Transformation, Vector,
 };
@@ -280,6 +280,9 @@ impl geometry::frame::Backend for Frame {
                 horizontal_alignment: text.horizontal_alignment,
                 vertical_alignment: text.vertical_alignment,
                 shaping: text.shaping,
+                wrapping: Wrapping::default(),
+                ellipsis: Ellipsis::default(),
+                max_lines: None,
                 clip_bounds: self.clip_bounds,
             });
         } else {
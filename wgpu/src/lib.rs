@@ -408,6 +408,9 @@ impl Renderer {
                         horizontal_alignment: alignment::Horizontal::Left,
                         vertical_alignment: alignment::Vertical::Top,
                         shaping: core::text::Shaping::Basic,
+                        wrapping: core::text::Wrapping::default(),
+                        ellipsis: core::text::Ellipsis::default(),
+                        max_lines: None,
                     };
 
                     renderer.fill_text(
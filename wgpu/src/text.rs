@@ -467,6 +467,9 @@ fn prepare(
                 line_height,
                 font,
                 shaping,
+                wrapping,
+                ellipsis,
+                max_lines,
                 ..
             } => {
                 let (key, _) = buffer_cache.allocate(
@@ -481,6 +484,9 @@ fn prepare(
                             height: bounds.height,
                         },
                         shaping: *shaping,
+                        wrapping: *wrapping,
+                        ellipsis: *ellipsis,
+                        max_lines: *max_lines,
                     },
                 );
 
@@ -1,18 +1,26 @@
 //! Allow your users to perform actions by pressing a button.
+use crate::core::accessibility;
+use crate::core::animation::Interpolable;
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::theme::palette;
+use crate::core::time::Duration;
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
-use crate::core::widget::Operation;
+use crate::core::widget::Id;
+use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Rectangle,
     Shadow, Shell, Size, Theme, Vector, Widget,
 };
+use crate::transition::Transition;
 
 /// A generic widget that produces a message when pressed.
 ///
@@ -52,12 +60,14 @@ where
     Renderer: crate::core::Renderer,
     Theme: Catalog,
 {
+    id: Option<Id>,
     content: Element<'a, Message, Theme, Renderer>,
     on_press: Option<OnPress<'a, Message>>,
     width: Length,
     height: Length,
     padding: Padding,
     clip: bool,
+    transition: Option<Duration>,
     class: Theme::Class<'a>,
 }
 
@@ -88,16 +98,24 @@ where
         let size = content.as_widget().size_hint();
 
         Button {
+            id: None,
             content,
             on_press: None,
             width: size.width.fluid(),
             height: size.height.fluid(),
             padding: DEFAULT_PADDING,
             clip: false,
+            transition: None,
             class: Theme::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Button`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the width of the [`Button`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -156,6 +174,15 @@ where
         self
     }
 
+    /// Sets the duration of the transition between the styles of the
+    /// [`Button`] when its [`Status`] changes.
+    ///
+    /// By default, the style changes instantly.
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
     /// Sets the style of the [`Button`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -173,11 +200,46 @@ where
         self.class = class.into();
         self
     }
+
+    fn status(
+        &self,
+        state: &State,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Status {
+        if self.on_press.is_none() {
+            Status::Disabled
+        } else if state.is_pressed && cursor.is_over(layout.bounds()) {
+            Status::Pressed
+        } else if state.is_focused {
+            Status::Focused
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Default)]
 struct State {
     is_pressed: bool,
+    is_focused: bool,
+    transition: Transition<Status>,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -238,14 +300,35 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<()>,
     ) {
-        operation.container(None, layout.bounds(), &mut |operation| {
-            self.content.as_widget().operate(
-                &mut tree.children[0],
-                layout.children().next().unwrap(),
-                renderer,
-                operation,
+        if self.on_press.is_some() {
+            let state = tree.state.downcast_mut::<State>();
+
+            operation.focusable_with_bounds(
+                state,
+                self.id.as_ref(),
+                layout.bounds(),
             );
-        });
+        }
+
+        let node = || {
+            accessibility::Node::new(accessibility::Role::Button)
+                .disabled(self.on_press.is_none())
+                .action(accessibility::Action::Click)
+        };
+
+        operation.accessible(
+            self.id.as_ref(),
+            layout.bounds(),
+            &node,
+            &mut |operation| {
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout.children().next().unwrap(),
+                    renderer,
+                    operation,
+                );
+            },
+        );
     }
 
     fn on_event(
@@ -275,12 +358,14 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.is_focused = false;
+
                 if self.on_press.is_some() {
                     let bounds = layout.bounds();
 
                     if cursor.is_over(bounds) {
-                        let state = tree.state.downcast_mut::<State>();
-
                         state.is_pressed = true;
 
                         return event::Status::Captured;
@@ -311,6 +396,33 @@ where
 
                 state.is_pressed = false;
             }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(duration) = self.transition {
+                    let state = tree.state.downcast_mut::<State>();
+                    let status = self.status(state, layout, cursor);
+
+                    if let Some(request) =
+                        state.transition.update(status, duration, now)
+                    {
+                        shell.request_redraw(request);
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Space | key::Named::Enter),
+                ..
+            }) => {
+                if let Some(on_press) = self.on_press.as_ref().map(OnPress::get)
+                {
+                    let state = tree.state.downcast_mut::<State>();
+
+                    if state.is_focused {
+                        shell.publish(on_press);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
             _ => {}
         }
 
@@ -329,24 +441,18 @@ where
     ) {
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
-        let is_mouse_over = cursor.is_over(bounds);
 
-        let status = if self.on_press.is_none() {
-            Status::Disabled
-        } else if is_mouse_over {
-            let state = tree.state.downcast_ref::<State>();
+        let state = tree.state.downcast_ref::<State>();
+        let status = self.status(state, layout, cursor);
 
-            if state.is_pressed {
-                Status::Pressed
-            } else {
-                Status::Hovered
-            }
+        let style = if self.transition.is_some() {
+            state
+                .transition
+                .style(status, |status| theme.style(&self.class, status))
         } else {
-            Status::Active
+            theme.style(&self.class, status)
         };
 
-        let style = theme.style(&self.class, status);
-
         if style.background.is_some()
             || style.border.width > 0.0
             || style.shadow.color.a > 0.0
@@ -444,6 +550,8 @@ pub enum Status {
     Hovered,
     /// The [`Button`] is being pressed.
     Pressed,
+    /// The [`Button`] can be pressed and it has keyboard focus.
+    Focused,
     /// The [`Button`] cannot be pressed.
     Disabled,
 }
@@ -471,6 +579,24 @@ impl Style {
     }
 }
 
+impl Interpolable for Style {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        let transparent = |background: Background| background.scale_alpha(0.0);
+
+        Self {
+            background: match (self.background, other.background) {
+                (Some(a), Some(b)) => Some(a.interpolate(&b, ratio)),
+                (Some(a), None) => Some(a.interpolate(&transparent(a), ratio)),
+                (None, Some(b)) => Some(transparent(b).interpolate(&b, ratio)),
+                (None, None) => None,
+            },
+            text_color: self.text_color.interpolate(&other.text_color, ratio),
+            border: self.border.interpolate(&other.border, ratio),
+            shadow: self.shadow.interpolate(&other.shadow, ratio),
+        }
+    }
+}
+
 impl Default for Style {
     fn default() -> Self {
         Self {
@@ -520,6 +646,7 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.primary.base.color)),
             ..base
         },
+        Status::Focused => focused(base),
         Status::Disabled => disabled(base),
     }
 }
@@ -535,6 +662,7 @@ pub fn secondary(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.secondary.strong.color)),
             ..base
         },
+        Status::Focused => focused(base),
         Status::Disabled => disabled(base),
     }
 }
@@ -550,6 +678,7 @@ pub fn success(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.success.strong.color)),
             ..base
         },
+        Status::Focused => focused(base),
         Status::Disabled => disabled(base),
     }
 }
@@ -565,6 +694,7 @@ pub fn danger(theme: &Theme, status: Status) -> Style {
             background: Some(Background::Color(palette.danger.strong.color)),
             ..base
         },
+        Status::Focused => focused(base),
         Status::Disabled => disabled(base),
     }
 }
@@ -584,6 +714,7 @@ pub fn text(theme: &Theme, status: Status) -> Style {
             text_color: palette.background.base.text.scale_alpha(0.8),
             ..base
         },
+        Status::Focused => focused(base),
         Status::Disabled => disabled(base),
     }
 }
@@ -597,6 +728,17 @@ fn styled(pair: palette::Pair) -> Style {
     }
 }
 
+fn focused(style: Style) -> Style {
+    Style {
+        border: Border {
+            color: style.text_color,
+            width: 2.0,
+            ..style.border
+        },
+        ..style
+    }
+}
+
 fn disabled(style: Style) -> Style {
     Style {
         background: style
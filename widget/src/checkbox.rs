@@ -1,18 +1,26 @@
 //! Show toggle controls using checkboxes.
+use crate::core::accessibility;
 use crate::core::alignment;
+use crate::core::animation::Interpolable;
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::theme::palette;
+use crate::core::time::Duration;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Pixels,
     Rectangle, Shell, Size, Theme, Widget,
 };
+use crate::transition::Transition;
 
 /// A box that can be checked.
 ///
@@ -41,6 +49,7 @@ pub struct Checkbox<
     Renderer: text::Renderer,
     Theme: Catalog,
 {
+    id: Option<widget::Id>,
     is_checked: bool,
     on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
     label: String,
@@ -50,8 +59,10 @@ pub struct Checkbox<
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
+    text_wrapping: text::Wrapping,
     font: Option<Renderer::Font>,
     icon: Icon<Renderer::Font>,
+    transition: Option<Duration>,
     class: Theme::Class<'a>,
 }
 
@@ -73,6 +84,7 @@ where
     ///   * a boolean describing whether the [`Checkbox`] is checked or not
     pub fn new(label: impl Into<String>, is_checked: bool) -> Self {
         Checkbox {
+            id: None,
             is_checked,
             on_toggle: None,
             label: label.into(),
@@ -82,6 +94,7 @@ where
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::Basic,
+            text_wrapping: text::Wrapping::default(),
             font: None,
             icon: Icon {
                 font: Renderer::ICON_FONT,
@@ -90,10 +103,17 @@ where
                 line_height: text::LineHeight::default(),
                 shaping: text::Shaping::Basic,
             },
+            transition: None,
             class: Theme::default(),
         }
     }
 
+    /// Sets the [`widget::Id`] of the [`Checkbox`].
+    pub fn id(mut self, id: widget::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the function that will be called when the [`Checkbox`] is toggled.
     /// It will receive the new state of the [`Checkbox`] and must produce a
     /// `Message`.
@@ -158,6 +178,12 @@ where
         self
     }
 
+    /// Sets the [`text::Wrapping`] strategy of the [`Checkbox`].
+    pub fn text_wrapping(mut self, wrapping: text::Wrapping) -> Self {
+        self.text_wrapping = wrapping;
+        self
+    }
+
     /// Sets the [`Renderer::Font`] of the text of the [`Checkbox`].
     ///
     /// [`Renderer::Font`]: crate::core::text::Renderer
@@ -172,6 +198,15 @@ where
         self
     }
 
+    /// Sets the duration of the transition between the styles of the
+    /// [`Checkbox`] when its [`Status`] changes.
+    ///
+    /// By default, the style changes instantly.
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
     /// Sets the style of the [`Checkbox`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -189,6 +224,25 @@ where
         self.class = class.into();
         self
     }
+
+    fn status(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Status {
+        let is_checked = self.is_checked;
+
+        if self.on_toggle.is_none() {
+            Status::Disabled { is_checked }
+        } else if state.is_focused {
+            Status::Focused { is_checked }
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered { is_checked }
+        } else {
+            Status::Active { is_checked }
+        }
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -198,11 +252,11 @@ where
     Theme: Catalog,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -223,12 +277,11 @@ where
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
-                widget::text::layout(
-                    state,
+                widget::text::layout_with(
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -240,14 +293,51 @@ where
                     alignment::Horizontal::Left,
                     alignment::Vertical::Top,
                     self.text_shaping,
+                    widget::text::Settings {
+                        wrapping: self.text_wrapping,
+                        ..widget::text::Settings::default()
+                    },
                 )
             },
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if self.on_toggle.is_some() {
+            operation.focusable_with_bounds(
+                state,
+                self.id.as_ref(),
+                layout.bounds(),
+            );
+        }
+
+        let node = || {
+            accessibility::Node::new(accessibility::Role::CheckBox)
+                .label(self.label.clone())
+                .toggled(self.is_checked)
+                .disabled(self.on_toggle.is_none())
+                .action(accessibility::Action::Click)
+        };
+
+        operation.accessible(
+            self.id.as_ref(),
+            layout.bounds(),
+            &node,
+            &mut |_| {},
+        );
+    }
+
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -256,11 +346,15 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let mouse_over = cursor.is_over(layout.bounds());
 
+                state.is_focused = false;
+
                 if mouse_over {
                     if let Some(on_toggle) = &self.on_toggle {
                         shell.publish((on_toggle)(!self.is_checked));
@@ -268,6 +362,26 @@ where
                     }
                 }
             }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(duration) = self.transition {
+                    let status = self.status(state, layout, cursor);
+
+                    if let Some(request) =
+                        state.transition.update(status, duration, now)
+                    {
+                        shell.request_redraw(request);
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Space | key::Named::Enter),
+                ..
+            }) if state.is_focused => {
+                if let Some(on_toggle) = &self.on_toggle {
+                    shell.publish((on_toggle)(!self.is_checked));
+                    return event::Status::Captured;
+                }
+            }
             _ => {}
         }
 
@@ -299,22 +413,19 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let is_mouse_over = cursor.is_over(layout.bounds());
-        let is_disabled = self.on_toggle.is_none();
-        let is_checked = self.is_checked;
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
 
         let mut children = layout.children();
 
-        let status = if is_disabled {
-            Status::Disabled { is_checked }
-        } else if is_mouse_over {
-            Status::Hovered { is_checked }
+        let status = self.status(state, layout, cursor);
+        let style = if self.transition.is_some() {
+            state
+                .transition
+                .style(status, |status| theme.style(&self.class, status))
         } else {
-            Status::Active { is_checked }
+            theme.style(&self.class, status)
         };
 
-        let style = theme.style(&self.class, status);
-
         {
             let layout = children.next().unwrap();
             let bounds = layout.bounds();
@@ -348,6 +459,9 @@ where
                         horizontal_alignment: alignment::Horizontal::Center,
                         vertical_alignment: alignment::Vertical::Center,
                         shaping: *shaping,
+                        wrapping: text::Wrapping::default(),
+                        ellipsis: text::Ellipsis::default(),
+                        max_lines: None,
                     },
                     bounds.center(),
                     style.icon_color,
@@ -358,16 +472,15 @@ where
 
         {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> =
-                tree.state.downcast_ref();
 
             crate::text::draw(
                 renderer,
                 defaults,
                 label_layout,
-                state.0.raw(),
+                state.label.0.raw(),
                 crate::text::Style {
                     color: style.text_color,
+                    ..crate::text::Style::default()
                 },
                 viewport,
             );
@@ -389,6 +502,27 @@ where
     }
 }
 
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    is_focused: bool,
+    transition: Transition<Status>,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 /// The icon in a [`Checkbox`].
 #[derive(Debug, Clone, PartialEq)]
 pub struct Icon<Font> {
@@ -417,6 +551,11 @@ pub enum Status {
         /// Indicates if the [`Checkbox`] is currently checked.
         is_checked: bool,
     },
+    /// The [`Checkbox`] can be interacted with and it has keyboard focus.
+    Focused {
+        /// Indicates if the [`Checkbox`] is currently checked.
+        is_checked: bool,
+    },
     /// The [`Checkbox`] cannot be interacted with.
     Disabled {
         /// Indicates if the [`Checkbox`] is currently checked.
@@ -437,6 +576,17 @@ pub struct Style {
     pub text_color: Option<Color>,
 }
 
+impl Interpolable for Style {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, ratio),
+            icon_color: self.icon_color.interpolate(&other.icon_color, ratio),
+            border: self.border.interpolate(&other.border, ratio),
+            text_color: self.text_color.interpolate(&other.text_color, ratio),
+        }
+    }
+}
+
 /// The theme catalog of a [`Checkbox`].
 pub trait Catalog: Sized {
     /// The item class of the [`Catalog`].
@@ -489,6 +639,9 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
             palette.background.strong,
             is_checked,
         ),
+        Status::Focused { is_checked } => {
+            focused(primary(theme, Status::Active { is_checked }))
+        }
     }
 }
 
@@ -515,6 +668,9 @@ pub fn secondary(theme: &Theme, status: Status) -> Style {
             palette.background.weak,
             is_checked,
         ),
+        Status::Focused { is_checked } => {
+            focused(secondary(theme, Status::Active { is_checked }))
+        }
     }
 }
 
@@ -541,6 +697,9 @@ pub fn success(theme: &Theme, status: Status) -> Style {
             palette.success.weak,
             is_checked,
         ),
+        Status::Focused { is_checked } => {
+            focused(success(theme, Status::Active { is_checked }))
+        }
     }
 }
 
@@ -567,6 +726,19 @@ pub fn danger(theme: &Theme, status: Status) -> Style {
             palette.danger.weak,
             is_checked,
         ),
+        Status::Focused { is_checked } => {
+            focused(danger(theme, Status::Active { is_checked }))
+        }
+    }
+}
+
+fn focused(style: Style) -> Style {
+    Style {
+        border: Border {
+            width: 2.0,
+            ..style.border
+        },
+        ..style
     }
 }
 
@@ -0,0 +1,1119 @@
+//! Pick a color from a panel anchored to a trigger element.
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::clipboard;
+use crate::core::event::{self, Event};
+use crate::core::gradient;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Clipboard, Color, Degrees, Element, Layout, Length, Pixels,
+    Point, Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
+};
+use crate::picker::{self, fill_text, Action, Popup};
+
+/// The padding of the panel.
+const PADDING: f32 = 8.0;
+
+/// The width of the contents of the panel.
+const WIDTH: f32 = 220.0;
+
+/// The height of the saturation and value square.
+const SQUARE_HEIGHT: f32 = 150.0;
+
+/// The height of the hue and channel bars.
+const BAR_HEIGHT: f32 = 12.0;
+
+/// The vertical space between the rows of the panel.
+const SPACING: f32 = 8.0;
+
+/// The width of the labels of the channel bars.
+const CHANNEL_LABEL_WIDTH: f32 = 16.0;
+
+/// The width of the values of the channel bars.
+const CHANNEL_VALUE_WIDTH: f32 = 36.0;
+
+/// A panel to pick a color that is shown below a trigger element when
+/// clicked.
+///
+/// The color can be picked by its hue, saturation and value, by its red,
+/// green and blue channels, or typed as a hexadecimal code.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// # use iced_widget::{button, color_picker, text};
+/// use iced_widget::core::Color;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ColorChanged(Color),
+///     Noop,
+/// }
+///
+/// fn view<'a>(color: Color) -> Element<'a, Message> {
+///     color_picker(
+///         button(text("Pick a color")).on_press(Message::Noop),
+///         color,
+///         Message::ColorChanged,
+///     )
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ColorPicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    trigger: Element<'a, Message, Theme, Renderer>,
+    color: Color,
+    on_change: Box<dyn Fn(Color) -> Message + 'a>,
+    text_size: Option<Pixels>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`ColorPicker`] with the given trigger, the current
+    /// [`Color`] and a function producing a message when it changes.
+    pub fn new(
+        trigger: impl Into<Element<'a, Message, Theme, Renderer>>,
+        color: Color,
+        on_change: impl Fn(Color) -> Message + 'a,
+    ) -> Self {
+        Self {
+            trigger: trigger.into(),
+            color,
+            on_change: Box::new(on_change),
+            text_size: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the text size of the panel.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the style of the panel.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the panel.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    is_open: bool,
+    panel: Editor,
+}
+
+/// The state of the [`Palette`] of a [`ColorPicker`].
+#[derive(Debug, Clone, Default)]
+struct Editor {
+    hsv: Hsv,
+    color: Option<Color>,
+    drag: Option<Target>,
+    hex: Option<Hex>,
+}
+
+/// The hexadecimal code being typed in the panel.
+#[derive(Debug, Clone, PartialEq)]
+struct Hex {
+    code: String,
+    is_selected: bool,
+}
+
+impl Hex {
+    /// Starts editing the given code, fully selected; typing replaces it.
+    fn new(code: String) -> Self {
+        Self {
+            code,
+            is_selected: true,
+        }
+    }
+
+    /// Inserts the hexadecimal digits of the given text, replacing the
+    /// selection, if any.
+    fn insert(&mut self, text: &str) {
+        if std::mem::take(&mut self.is_selected) {
+            self.code.clear();
+        }
+
+        for c in text.chars().filter(char::is_ascii_hexdigit) {
+            if self.code.len() < 6 {
+                self.code.push(c.to_ascii_uppercase());
+            }
+        }
+    }
+
+    /// Deletes the last digit, or the whole code if it is selected.
+    fn backspace(&mut self) {
+        if std::mem::take(&mut self.is_selected) {
+            self.code.clear();
+        } else {
+            let _ = self.code.pop();
+        }
+    }
+}
+
+/// A color in the HSV color space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Hsv {
+    hue: f32,
+    saturation: f32,
+    value: f32,
+}
+
+impl Hsv {
+    /// Converts the given [`Color`], keeping the given hue if the color is
+    /// a shade of gray.
+    fn from_color(color: Color, hue: f32) -> Self {
+        let max = color.r.max(color.g).max(color.b);
+        let min = color.r.min(color.g).min(color.b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            hue
+        } else if max == color.r {
+            60.0 * ((color.g - color.b) / delta).rem_euclid(6.0)
+        } else if max == color.g {
+            60.0 * ((color.b - color.r) / delta + 2.0)
+        } else {
+            60.0 * ((color.r - color.g) / delta + 4.0)
+        };
+
+        Self {
+            hue,
+            saturation: if max == 0.0 { 0.0 } else { delta / max },
+            value: max,
+        }
+    }
+
+    fn to_color(self, alpha: f32) -> Color {
+        let chroma = self.value * self.saturation;
+        let sector = (self.hue / 60.0).rem_euclid(6.0);
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let min = self.value - chroma;
+
+        Color::from_rgba(r + min, g + min, b + min, alpha)
+    }
+}
+
+/// A draggable region of the [`Palette`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Square,
+    Hue,
+    Channel(usize),
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.trigger)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.trigger));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.trigger.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.trigger
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.trigger.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.trigger.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if cursor.is_over(layout.bounds()) {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.is_open = !state.is_open;
+                state.panel.drag = None;
+                state.panel.hex = None;
+
+                shell.invalidate_layout();
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.trigger.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.trigger.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (state, trigger) = (&mut tree.state, &mut tree.children[0]);
+        let State { is_open, panel } = state.downcast_mut::<State>();
+
+        if !*is_open {
+            return self.trigger.as_widget_mut().overlay(
+                trigger,
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        // Only convert the color when it changes from the outside, so the hue
+        // of grays is not lost while picking
+        if panel.color != Some(self.color) {
+            panel.hsv = Hsv::from_color(self.color, panel.hsv.hue);
+            panel.color = Some(self.color);
+        }
+
+        Some(overlay::Element::new(Box::new(Popup::new(
+            is_open,
+            Palette {
+                editor: panel,
+                color: self.color,
+                on_change: &self.on_change,
+                text_size: self.text_size,
+                class: &self.class,
+            },
+            layout.bounds() + translation,
+        ))))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<ColorPicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(color_picker: ColorPicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(color_picker)
+    }
+}
+
+struct Palette<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    editor: &'b mut Editor,
+    color: Color,
+    on_change: &'b dyn Fn(Color) -> Message,
+    text_size: Option<Pixels>,
+    class: &'b Theme::Class<'a>,
+}
+
+/// The bounds of the different parts of a [`Palette`].
+struct Regions {
+    square: Rectangle,
+    hue: Rectangle,
+    channels: [Rectangle; 3],
+    swatch: Rectangle,
+    hex: Rectangle,
+}
+
+impl Regions {
+    fn new(bounds: Rectangle, line_height: f32) -> Self {
+        let x = bounds.x + PADDING;
+        let mut y = bounds.y + PADDING;
+
+        let square =
+            Rectangle::new(Point::new(x, y), Size::new(WIDTH, SQUARE_HEIGHT));
+        y += SQUARE_HEIGHT + SPACING;
+
+        let hue =
+            Rectangle::new(Point::new(x, y), Size::new(WIDTH, BAR_HEIGHT));
+        y += BAR_HEIGHT + SPACING;
+
+        let channels = [0.0, 1.0, 2.0].map(|row| Rectangle {
+            x: x + CHANNEL_LABEL_WIDTH,
+            y: y + row * line_height + (line_height - BAR_HEIGHT) / 2.0,
+            width: WIDTH - CHANNEL_LABEL_WIDTH - CHANNEL_VALUE_WIDTH,
+            height: BAR_HEIGHT,
+        });
+        y += line_height * 3.0 + SPACING;
+
+        let input_height = line_height + PADDING;
+
+        let swatch = Rectangle::new(
+            Point::new(x, y),
+            Size::new(input_height, input_height),
+        );
+
+        let hex = Rectangle::new(
+            Point::new(x + input_height + SPACING, y),
+            Size::new(WIDTH - input_height - SPACING, input_height),
+        );
+
+        Self {
+            square,
+            hue,
+            channels,
+            swatch,
+            hex,
+        }
+    }
+
+    fn target(&self, position: Point) -> Option<Target> {
+        if self.square.contains(position) {
+            Some(Target::Square)
+        } else if self.hue.contains(position) {
+            Some(Target::Hue)
+        } else {
+            self.channels
+                .iter()
+                .position(|channel| channel.contains(position))
+                .map(Target::Channel)
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme> Palette<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fn line_height<Renderer>(&self, renderer: &Renderer) -> f32
+    where
+        Renderer: text::Renderer,
+    {
+        text::LineHeight::default()
+            .to_absolute(
+                self.text_size.unwrap_or_else(|| renderer.default_size()),
+            )
+            .0
+    }
+
+    /// Updates the color by dragging the given target to the given position.
+    fn drag(
+        &mut self,
+        regions: &Regions,
+        target: Target,
+        position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let ratio = |bounds: Rectangle| {
+            ((position.x - bounds.x) / bounds.width).clamp(0.0, 1.0)
+        };
+
+        let hsv = &mut self.editor.hsv;
+
+        match target {
+            Target::Square => {
+                let square = regions.square;
+
+                hsv.saturation = ratio(square);
+                hsv.value = 1.0
+                    - ((position.y - square.y) / square.height).clamp(0.0, 1.0);
+            }
+            Target::Hue => {
+                hsv.hue = ratio(regions.hue) * 360.0;
+            }
+            Target::Channel(index) => {
+                let mut channels = [self.color.r, self.color.g, self.color.b];
+                channels[index] = ratio(regions.channels[index]);
+
+                let [r, g, b] = channels;
+
+                *hsv = Hsv::from_color(Color::from_rgb(r, g, b), hsv.hue);
+            }
+        }
+
+        let color = hsv.to_color(self.color.a);
+
+        self.change(color, shell);
+    }
+
+    fn change(&mut self, color: Color, shell: &mut Shell<'_, Message>) {
+        if color != self.color {
+            self.editor.color = Some(color);
+            self.color = color;
+
+            shell.publish((self.on_change)(color));
+        }
+    }
+
+    /// Applies the hexadecimal code being typed, if valid.
+    fn apply_hex(&mut self, shell: &mut Shell<'_, Message>) {
+        let Some(color) = self
+            .editor
+            .hex
+            .as_ref()
+            .and_then(|hex| parse_hex(&hex.code))
+        else {
+            return;
+        };
+
+        let color = Color {
+            a: self.color.a,
+            ..color
+        };
+
+        self.editor.hsv = Hsv::from_color(color, self.editor.hsv.hue);
+        self.change(color, shell);
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> picker::Panel<Message, Theme, Renderer>
+    for Palette<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn size(&self, renderer: &Renderer) -> Size {
+        let line_height = self.line_height(renderer);
+
+        Size::new(
+            WIDTH + PADDING * 2.0,
+            SQUARE_HEIGHT
+                + BAR_HEIGHT
+                + line_height * 3.0
+                + line_height
+                + PADDING
+                + SPACING * 3.0
+                + PADDING * 2.0,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> Action {
+        let regions = Regions::new(bounds, self.line_height(renderer));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return Action::Ignored;
+                };
+
+                if regions.hex.contains(position) {
+                    if self.editor.hex.is_none() {
+                        self.editor.hex = Some(Hex::new(hex(self.color)));
+                    }
+                } else {
+                    self.editor.hex = None;
+                }
+
+                if let Some(target) = regions.target(position) {
+                    self.editor.drag = Some(target);
+                    self.drag(&regions, target, position, shell);
+                }
+
+                Action::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some(target) = self.editor.drag else {
+                    return Action::Ignored;
+                };
+
+                self.drag(&regions, target, *position, shell);
+
+                Action::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if self.editor.drag.take().is_some() {
+                    Action::Captured
+                } else {
+                    Action::Ignored
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) => {
+                let Some(hex) = &mut self.editor.hex else {
+                    return Action::Ignored;
+                };
+
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::Enter) => {
+                        self.apply_hex(shell);
+                        self.editor.hex = None;
+                    }
+                    keyboard::Key::Named(key::Named::Escape) => {
+                        self.editor.hex = None;
+                    }
+                    keyboard::Key::Named(key::Named::Backspace) => {
+                        hex.backspace();
+                    }
+                    keyboard::Key::Character("a") if modifiers.command() => {
+                        hex.is_selected = true;
+                    }
+                    keyboard::Key::Character("c") if modifiers.command() => {
+                        clipboard.write(
+                            clipboard::Kind::Standard,
+                            format!("#{}", hex.code),
+                        );
+                    }
+                    keyboard::Key::Character("v") if modifiers.command() => {
+                        if let Some(content) =
+                            clipboard.read(clipboard::Kind::Standard)
+                        {
+                            hex.insert(content.trim().trim_start_matches('#'));
+
+                            if hex.code.len() == 6 {
+                                self.apply_hex(shell);
+                            }
+                        }
+                    }
+                    _ => {
+                        let Some(text) = text else {
+                            return Action::Captured;
+                        };
+
+                        hex.insert(text);
+
+                        if hex.code.len() == 6 {
+                            self.apply_hex(shell);
+                        }
+                    }
+                }
+
+                Action::Captured
+            }
+            _ => Action::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let regions = Regions::new(bounds, self.line_height(renderer));
+
+        if self.editor.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        match cursor.position() {
+            Some(position) if regions.target(position).is_some() => {
+                mouse::Interaction::Crosshair
+            }
+            Some(position) if regions.hex.contains(position) => {
+                mouse::Interaction::Text
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let regions = Regions::new(bounds, self.line_height(renderer));
+        let hsv = self.editor.hsv;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+            },
+            style.background,
+        );
+
+        // Saturation grows to the right and value grows upwards
+        let square = regions.square;
+        let radius = border::Radius::from(4.0);
+
+        let hue = Hsv {
+            hue: hsv.hue,
+            saturation: 1.0,
+            value: 1.0,
+        }
+        .to_color(1.0);
+
+        for background in [
+            Background::Color(hue),
+            gradient::Linear::new(Degrees(90.0))
+                .add_stop(0.0, Color::WHITE)
+                .add_stop(1.0, Color::WHITE.scale_alpha(0.0))
+                .into(),
+            gradient::Linear::new(Degrees(180.0))
+                .add_stop(0.0, Color::BLACK.scale_alpha(0.0))
+                .add_stop(1.0, Color::BLACK)
+                .into(),
+        ] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: square,
+                    border: border::rounded(radius),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        draw_handle(
+            renderer,
+            Point::new(
+                square.x + hsv.saturation * square.width,
+                square.y + (1.0 - hsv.value) * square.height,
+            ),
+            self.color,
+            style.handle_color,
+        );
+
+        let hues = (0..=6).fold(
+            gradient::Linear::new(Degrees(90.0)),
+            |gradient, sector| {
+                gradient.add_stop(
+                    sector as f32 / 6.0,
+                    Hsv {
+                        hue: sector as f32 * 60.0,
+                        saturation: 1.0,
+                        value: 1.0,
+                    }
+                    .to_color(1.0),
+                )
+            },
+        );
+
+        draw_bar(
+            renderer,
+            regions.hue,
+            hues.into(),
+            hsv.hue / 360.0,
+            hue,
+            style.handle_color,
+        );
+
+        let channels = [self.color.r, self.color.g, self.color.b];
+
+        for (index, (label, bar)) in ["R", "G", "B"]
+            .into_iter()
+            .zip(regions.channels)
+            .enumerate()
+        {
+            let with = |value: f32| {
+                let mut channels = channels;
+                channels[index] = value;
+
+                let [r, g, b] = channels;
+
+                Color::from_rgb(r, g, b)
+            };
+
+            fill_text(
+                renderer,
+                label,
+                size,
+                Point::new(bounds.x + PADDING, bar.center_y()),
+                alignment::Horizontal::Left,
+                style.text_color,
+                bounds,
+            );
+
+            draw_bar(
+                renderer,
+                bar,
+                gradient::Linear::new(Degrees(90.0))
+                    .add_stop(0.0, with(0.0))
+                    .add_stop(1.0, with(1.0))
+                    .into(),
+                channels[index],
+                with(channels[index]),
+                style.handle_color,
+            );
+
+            fill_text(
+                renderer,
+                format!("{}", (channels[index] * 255.0).round()),
+                size,
+                Point::new(bounds.x + PADDING + WIDTH, bar.center_y()),
+                alignment::Horizontal::Right,
+                style.text_color,
+                bounds,
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: regions.swatch,
+                border: Border {
+                    color: style.input_border,
+                    width: 1.0,
+                    radius,
+                },
+                ..renderer::Quad::default()
+            },
+            self.color,
+        );
+
+        let is_editing = self.editor.hex.is_some();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: regions.hex,
+                border: Border {
+                    color: if is_editing {
+                        style.input_focused_border
+                    } else {
+                        style.input_border
+                    },
+                    width: 1.0,
+                    radius,
+                },
+                ..renderer::Quad::default()
+            },
+            style.input_background,
+        );
+
+        let code = match &self.editor.hex {
+            Some(hex) if hex.is_selected => {
+                let code = format!("#{}", hex.code);
+
+                let width = Renderer::Paragraph::with_text(Text {
+                    content: code.as_str(),
+                    bounds: Size::INFINITY,
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::default(),
+                    ellipsis: text::Ellipsis::default(),
+                    max_lines: None,
+                })
+                .min_width();
+
+                let height = text::LineHeight::default().to_absolute(size).0;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: regions.hex.x + PADDING,
+                            y: regions.hex.center_y() - height / 2.0,
+                            width,
+                            height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.input_selection,
+                );
+
+                code
+            }
+            Some(hex) => format!("#{}|", hex.code),
+            None => format!("#{}", hex(self.color)),
+        };
+
+        fill_text(
+            renderer,
+            code,
+            size,
+            Point::new(regions.hex.x + PADDING, regions.hex.center_y()),
+            alignment::Horizontal::Left,
+            style.text_color,
+            bounds,
+        );
+    }
+}
+
+/// Draws a bar filled with the given background and a handle at the given
+/// ratio.
+fn draw_bar<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    background: Background,
+    ratio: f32,
+    color: Color,
+    handle_color: Color,
+) where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: border::rounded(bounds.height / 2.0),
+            ..renderer::Quad::default()
+        },
+        background,
+    );
+
+    draw_handle(
+        renderer,
+        Point::new(bounds.x + ratio * bounds.width, bounds.center_y()),
+        color,
+        handle_color,
+    );
+}
+
+fn draw_handle<Renderer>(
+    renderer: &mut Renderer,
+    center: Point,
+    color: Color,
+    handle_color: Color,
+) where
+    Renderer: renderer::Renderer,
+{
+    const RADIUS: f32 = 7.0;
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: center.x - RADIUS,
+                y: center.y - RADIUS,
+                width: RADIUS * 2.0,
+                height: RADIUS * 2.0,
+            },
+            border: Border {
+                color: handle_color,
+                width: 2.0,
+                radius: RADIUS.into(),
+            },
+            ..renderer::Quad::default()
+        },
+        color,
+    );
+}
+
+/// Returns the hexadecimal code of the given [`Color`], without alpha.
+fn hex(color: Color) -> String {
+    let [r, g, b, _] = color.into_rgba8();
+
+    format!("{r:02X}{g:02X}{b:02X}")
+}
+
+/// Parses a hexadecimal code of 3 or 6 digits.
+fn parse_hex(code: &str) -> Option<Color> {
+    let digit = |index: usize, length: usize| {
+        u8::from_str_radix(code.get(index..index + length)?, 16).ok()
+    };
+
+    match code.len() {
+        3 => Some(Color::from_rgb8(
+            digit(0, 1)? * 17,
+            digit(1, 1)? * 17,
+            digit(2, 1)? * 17,
+        )),
+        6 => Some(Color::from_rgb8(digit(0, 2)?, digit(2, 2)?, digit(4, 2)?)),
+        _ => None,
+    }
+}
+
+/// The appearance of the panel of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the panel.
+    pub background: Background,
+    /// The [`Border`] of the panel.
+    pub border: Border,
+    /// The [`Shadow`] of the panel.
+    pub shadow: Shadow,
+    /// The text [`Color`] of the panel.
+    pub text_color: Color,
+    /// The [`Color`] of the border of the handles.
+    pub handle_color: Color,
+    /// The [`Background`] of the hexadecimal input.
+    pub input_background: Background,
+    /// The [`Color`] of the border of the hexadecimal input and the swatch.
+    pub input_border: Color,
+    /// The [`Color`] of the border of the hexadecimal input while typing.
+    pub input_focused_border: Color,
+    /// The [`Color`] of the selected code of the hexadecimal input.
+    pub input_selection: Color,
+}
+
+/// The theme catalog of a [`ColorPicker`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`ColorPicker`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`ColorPicker`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: palette.background.strong.color,
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        text_color: palette.background.base.text,
+        handle_color: Color::WHITE,
+        input_background: palette.background.base.color.into(),
+        input_border: palette.background.strong.color,
+        input_focused_border: palette.primary.strong.color,
+        input_selection: palette.primary.weak.color,
+    }
+}
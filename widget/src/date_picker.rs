@@ -0,0 +1,885 @@
+//! Pick a date from a calendar anchored to a trigger element.
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::time::SystemTime;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
+};
+use crate::picker::{self, fill_text, Action, Popup};
+
+use std::fmt;
+
+/// The padding of the calendar.
+const PADDING: f32 = 8.0;
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// A day of the Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a new [`Date`] from the given year, month (`1..=12`) and day.
+    ///
+    /// Returns `None` if the date does not exist.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        ((1..=12).contains(&month)
+            && (1..=days_in_month(year, month)).contains(&day))
+        .then_some(Self { year, month, day })
+    }
+
+    /// Returns the current [`Date`] in UTC.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        Self::from_days(i64::try_from(seconds / 86_400).unwrap_or_default())
+    }
+
+    /// Returns the year of the [`Date`].
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], starting at `1`.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month of the [`Date`], starting at `1`.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the [`Date`] the given number of days apart.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Returns the [`Date`] the given number of months apart.
+    ///
+    /// The day is clamped to the length of the resulting month.
+    pub fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u32 + 1;
+
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// Returns the day of the week of the [`Date`], starting at `0` for
+    /// Monday.
+    fn weekday(self) -> u32 {
+        // 1970-01-01 was a Thursday
+        (self.to_days() + 3).rem_euclid(7) as u32
+    }
+
+    /// Returns the number of days since 1970-01-01.
+    fn to_days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from((self.month + 9) % 12);
+        let day_of_year = (153 * month + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4
+            - year_of_era / 100
+            + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Returns the [`Date`] the given number of days after 1970-01-01.
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460
+            + day_of_era / 36_524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let month = if month < 10 { month + 3 } else { month - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A calendar that is shown below a trigger element when clicked.
+///
+/// Once open, the arrows move the focused day, `Page Up` and `Page Down`
+/// change the month—or the year, while holding `Shift`—and `Enter` picks
+/// the focused day. `Escape` closes the calendar.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// # use iced_widget::{button, date_picker, text};
+/// use iced_widget::date_picker::Date;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DatePicked(Date),
+///     Noop,
+/// }
+///
+/// fn view<'a>(date: Date) -> Element<'a, Message> {
+///     date_picker(
+///         button(text(date.to_string())).on_press(Message::Noop),
+///         date,
+///         Message::DatePicked,
+///     )
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    trigger: Element<'a, Message, Theme, Renderer>,
+    date: Option<Date>,
+    on_pick: Box<dyn Fn(Date) -> Message + 'a>,
+    text_size: Option<Pixels>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`DatePicker`] with the given trigger, the picked
+    /// [`Date`], if any, and a function producing a message when a
+    /// [`Date`] is picked.
+    pub fn new(
+        trigger: impl Into<Element<'a, Message, Theme, Renderer>>,
+        date: impl Into<Option<Date>>,
+        on_pick: impl Fn(Date) -> Message + 'a,
+    ) -> Self {
+        Self {
+            trigger: trigger.into(),
+            date: date.into(),
+            on_pick: Box::new(on_pick),
+            text_size: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the text size of the calendar.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the style of the calendar.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the calendar.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    is_open: bool,
+    focused: Date,
+    today: Date,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let today = Date::today();
+
+        Self {
+            is_open: false,
+            focused: today,
+            today,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.trigger)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.trigger));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.trigger.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.trigger
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.trigger.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.trigger.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if cursor.is_over(layout.bounds()) {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.is_open = !state.is_open;
+                state.today = Date::today();
+                state.focused = self.date.unwrap_or(state.today);
+
+                shell.invalidate_layout();
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.trigger.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.trigger.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (state, trigger) = (&mut tree.state, &mut tree.children[0]);
+        let State {
+            is_open,
+            focused,
+            today,
+        } = state.downcast_mut::<State>();
+
+        if !*is_open {
+            return self.trigger.as_widget_mut().overlay(
+                trigger,
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        Some(overlay::Element::new(Box::new(Popup::new(
+            is_open,
+            Calendar {
+                focused,
+                date: self.date,
+                today: *today,
+                on_pick: &self.on_pick,
+                text_size: self.text_size,
+                class: &self.class,
+            },
+            layout.bounds() + translation,
+        ))))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<DatePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(date_picker: DatePicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(date_picker)
+    }
+}
+
+struct Calendar<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    focused: &'b mut Date,
+    date: Option<Date>,
+    today: Date,
+    on_pick: &'b dyn Fn(Date) -> Message,
+    text_size: Option<Pixels>,
+    class: &'b Theme::Class<'a>,
+}
+
+/// A region of the [`Calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Region {
+    Previous,
+    Next,
+    Day(Date),
+}
+
+impl<'a, 'b, Message, Theme> Calendar<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fn cell<Renderer>(&self, renderer: &Renderer) -> f32
+    where
+        Renderer: text::Renderer,
+    {
+        (self.text_size.unwrap_or_else(|| renderer.default_size()).0 * 2.0)
+            .round()
+    }
+
+    /// Returns the first day of the month of the focused day.
+    fn month(&self) -> Date {
+        Date {
+            day: 1,
+            ..*self.focused
+        }
+    }
+
+    /// Returns the bounds of the given day of the focused month.
+    fn day_bounds(&self, bounds: Rectangle, cell: f32, day: u32) -> Rectangle {
+        let index = self.month().weekday() + day - 1;
+
+        Rectangle {
+            x: bounds.x + PADDING + (index % 7) as f32 * cell,
+            y: bounds.y + PADDING + (2 + index / 7) as f32 * cell,
+            width: cell,
+            height: cell,
+        }
+    }
+
+    fn region(
+        &self,
+        bounds: Rectangle,
+        cell: f32,
+        position: Point,
+    ) -> Option<Region> {
+        let column = ((position.x - bounds.x - PADDING) / cell).floor();
+        let row = ((position.y - bounds.y - PADDING) / cell).floor();
+
+        if !(0.0..7.0).contains(&column) || !(0.0..8.0).contains(&row) {
+            return None;
+        }
+
+        let (column, row) = (column as u32, row as u32);
+
+        match row {
+            0 if column == 0 => Some(Region::Previous),
+            0 if column == 6 => Some(Region::Next),
+            0 | 1 => None,
+            _ => {
+                let month = self.month();
+                let index = (row - 2) * 7 + column;
+
+                (index >= month.weekday())
+                    .then(|| index - month.weekday() + 1)
+                    .and_then(|day| Date::new(month.year, month.month, day))
+                    .map(Region::Day)
+            }
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> picker::Panel<Message, Theme, Renderer>
+    for Calendar<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn size(&self, renderer: &Renderer) -> Size {
+        let cell = self.cell(renderer);
+
+        Size::new(cell * 7.0 + PADDING * 2.0, cell * 8.0 + PADDING * 2.0)
+    }
+
+    fn on_event(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> Action {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return Action::Ignored;
+                };
+
+                match self.region(bounds, self.cell(renderer), position) {
+                    Some(Region::Previous) => {
+                        *self.focused = self.focused.add_months(-1);
+                    }
+                    Some(Region::Next) => {
+                        *self.focused = self.focused.add_months(1);
+                    }
+                    Some(Region::Day(date)) => {
+                        shell.publish((self.on_pick)(date));
+
+                        return Action::Close;
+                    }
+                    None => {}
+                }
+
+                Action::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                modifiers,
+                ..
+            }) => {
+                let focused = *self.focused;
+
+                *self.focused = match named {
+                    key::Named::ArrowLeft => focused.add_days(-1),
+                    key::Named::ArrowRight => focused.add_days(1),
+                    key::Named::ArrowUp => focused.add_days(-7),
+                    key::Named::ArrowDown => focused.add_days(7),
+                    key::Named::PageUp if modifiers.shift() => {
+                        focused.add_months(-12)
+                    }
+                    key::Named::PageUp => focused.add_months(-1),
+                    key::Named::PageDown if modifiers.shift() => {
+                        focused.add_months(12)
+                    }
+                    key::Named::PageDown => focused.add_months(1),
+                    key::Named::Enter | key::Named::Space => {
+                        shell.publish((self.on_pick)(focused));
+
+                        return Action::Close;
+                    }
+                    _ => return Action::Ignored,
+                };
+
+                Action::Captured
+            }
+            _ => Action::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let cell = self.cell(renderer);
+
+        let hovered = cursor
+            .position_over(bounds)
+            .and_then(|position| self.region(bounds, cell, position));
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+            },
+            style.background,
+        );
+
+        let header_y = bounds.y + PADDING + cell / 2.0;
+
+        for (region, label, column) in
+            [(Region::Previous, "‹", 0.0), (Region::Next, "›", 6.0)]
+        {
+            let arrow = Rectangle {
+                x: bounds.x + PADDING + column * cell,
+                y: bounds.y + PADDING,
+                width: cell,
+                height: cell,
+            };
+
+            if hovered == Some(region) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: arrow,
+                        border: border::rounded(cell / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    style.hovered_background,
+                );
+            }
+
+            fill_text(
+                renderer,
+                label,
+                size,
+                arrow.center(),
+                alignment::Horizontal::Center,
+                style.text_color,
+                bounds,
+            );
+        }
+
+        let month = self.month();
+
+        fill_text(
+            renderer,
+            format!("{} {}", MONTHS[month.month as usize - 1], month.year),
+            size,
+            Point::new(bounds.center_x(), header_y),
+            alignment::Horizontal::Center,
+            style.text_color,
+            bounds,
+        );
+
+        for (column, weekday) in WEEKDAYS.iter().enumerate() {
+            fill_text(
+                renderer,
+                *weekday,
+                Pixels(size.0 * 0.8),
+                Point::new(
+                    bounds.x + PADDING + (column as f32 + 0.5) * cell,
+                    header_y + cell,
+                ),
+                alignment::Horizontal::Center,
+                style.weekday_color,
+                bounds,
+            );
+        }
+
+        for day in 1..=days_in_month(month.year, month.month) {
+            let date = Date { day, ..month };
+            let day_bounds = self.day_bounds(bounds, cell, day);
+
+            let is_selected = self.date == Some(date);
+            let is_highlighted =
+                hovered == Some(Region::Day(date)) || *self.focused == date;
+
+            let background = if is_selected {
+                Some(style.selected_background)
+            } else if is_highlighted {
+                Some(style.hovered_background)
+            } else {
+                None
+            };
+
+            let today_border = if date == self.today {
+                Border {
+                    color: style.today_border,
+                    width: 1.0,
+                    radius: (cell / 2.0).into(),
+                }
+            } else {
+                border::rounded(cell / 2.0)
+            };
+
+            if background.is_some() || today_border.width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: day_bounds,
+                        border: today_border,
+                        ..renderer::Quad::default()
+                    },
+                    background.unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            }
+
+            fill_text(
+                renderer,
+                day.to_string(),
+                size,
+                day_bounds.center(),
+                alignment::Horizontal::Center,
+                if is_selected {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                bounds,
+            );
+        }
+    }
+}
+
+/// The appearance of the calendar of a [`DatePicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the calendar.
+    pub background: Background,
+    /// The [`Border`] of the calendar.
+    pub border: Border,
+    /// The [`Shadow`] of the calendar.
+    pub shadow: Shadow,
+    /// The text [`Color`] of the calendar.
+    pub text_color: Color,
+    /// The text [`Color`] of the names of the days of the week.
+    pub weekday_color: Color,
+    /// The [`Background`] of a hovered or focused day.
+    pub hovered_background: Background,
+    /// The [`Background`] of the picked day.
+    pub selected_background: Background,
+    /// The text [`Color`] of the picked day.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the border around the current day.
+    pub today_border: Color,
+}
+
+/// The theme catalog of a [`DatePicker`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`DatePicker`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`DatePicker`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: palette.background.strong.color,
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        text_color: palette.background.base.text,
+        weekday_color: palette.background.base.text.scale_alpha(0.6),
+        hovered_background: palette.background.weak.color.into(),
+        selected_background: palette.primary.strong.color.into(),
+        selected_text_color: palette.primary.strong.text,
+        today_border: palette.primary.base.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_round_trip() {
+        assert_eq!(Date::new(1970, 1, 1).unwrap().to_days(), 0);
+        assert_eq!(Date::new(2000, 3, 1).unwrap().to_days(), 11_017);
+        assert_eq!(Date::new(1969, 12, 31).unwrap().to_days(), -1);
+
+        for days in (-1_000_000..1_000_000).step_by(97) {
+            assert_eq!(Date::from_days(days).to_days(), days);
+        }
+    }
+
+    #[test]
+    fn leap_years() {
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2023, 2), 28);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(1900, 2), 28);
+
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(1900, 2, 29).is_none());
+        assert!(Date::new(2023, 4, 31).is_none());
+        assert!(Date::new(2023, 13, 1).is_none());
+    }
+
+    #[test]
+    fn month_boundaries() {
+        let date = |year, month, day| Date::new(year, month, day).unwrap();
+
+        assert_eq!(date(2024, 1, 31).add_days(1), date(2024, 2, 1));
+        assert_eq!(date(2024, 2, 28).add_days(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 2, 28).add_days(1), date(2023, 3, 1));
+        assert_eq!(date(2023, 12, 31).add_days(1), date(2024, 1, 1));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 1, 31).add_months(1), date(2023, 2, 28));
+        assert_eq!(date(2024, 1, 15).add_months(-1), date(2023, 12, 15));
+        assert_eq!(date(2024, 2, 29).add_months(12), date(2025, 2, 28));
+    }
+
+    #[test]
+    fn weekdays() {
+        let date = |year, month, day| Date::new(year, month, day).unwrap();
+
+        assert_eq!(date(1970, 1, 1).weekday(), 3);
+        assert_eq!(date(2000, 1, 1).weekday(), 5);
+        assert_eq!(date(2024, 2, 29).weekday(), 3);
+        assert_eq!(date(1969, 12, 29).weekday(), 0);
+    }
+}
@@ -0,0 +1,472 @@
+//! Drag widgets around and drop them on a [`DropZone`].
+//!
+//! [`DropZone`]: crate::DropZone
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+/// The distance the cursor needs to travel before a drag starts.
+const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
+
+/// A widget that can be dragged around and dropped on a [`DropZone`],
+/// carrying a payload with it.
+///
+/// While dragging, a preview of the contents follows the cursor.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// # use iced_widget::{column, draggable, drop_zone, text};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(usize),
+/// }
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     column![
+///         draggable(text("Drag me!"), 42),
+///         drop_zone(text("Drop here!")).on_drop(Message::Dropped),
+///     ]
+///     .into()
+/// }
+/// ```
+///
+/// [`DropZone`]: crate::DropZone
+#[allow(missing_debug_implementations)]
+pub struct Draggable<
+    'a,
+    Payload,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    payload: Payload,
+}
+
+impl<'a, Payload, Message, Theme, Renderer>
+    Draggable<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+{
+    /// Creates a new [`Draggable`] with the given content and the payload
+    /// delivered to a [`DropZone`] when dropped on it.
+    ///
+    /// [`DropZone`]: crate::DropZone
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        payload: Payload,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            payload,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    drag: Option<Drag>,
+    owner: Rc<()>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: Point,
+    grab: Vector,
+    cursor: Point,
+    size: Size,
+    is_dragging: bool,
+}
+
+impl<'a, Payload, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Draggable<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !matches!(event, Event::Mouse(mouse::Event::ButtonReleased(_))) {
+            clear_dropped();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    state.drag = Some(Drag {
+                        origin: position,
+                        grab: position - layout.position(),
+                        cursor: position,
+                        size: layout.bounds().size(),
+                        is_dragging: false,
+                    });
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(drag) = &mut state.drag {
+                    drag.cursor = position;
+
+                    if !drag.is_dragging
+                        && !cursor.position().is_some_and(|cursor| {
+                            cursor.distance(drag.origin)
+                                <= DRAG_DEADBAND_DISTANCE
+                        })
+                    {
+                        drag.is_dragging = true;
+
+                        ACTIVE.with_borrow_mut(|active| {
+                            *active = Some(Active {
+                                payload: Box::new(self.payload.clone()),
+                                owner: Rc::downgrade(&state.owner),
+                                is_dropped: false,
+                            });
+                        });
+                    }
+
+                    if drag.is_dragging {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Mouse(mouse::Event::CursorLeft) => {
+                if let Some(drag) = state.drag.take() {
+                    if drag.is_dragging {
+                        drop_payload();
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return event::Status::Ignored;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::default()
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+
+        match state.drag {
+            Some(drag) if drag.is_dragging => {
+                Some(overlay::Element::new(Box::new(Preview {
+                    content: &self.content,
+                    tree: &mut tree.children[0],
+                    position: drag.cursor - drag.grab,
+                    size: drag.size,
+                })))
+            }
+            _ => self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                translation,
+            ),
+        }
+    }
+}
+
+impl<'a, Payload, Message, Theme, Renderer>
+    From<Draggable<'a, Payload, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        draggable: Draggable<'a, Payload, Message, Theme, Renderer>,
+    ) -> Self {
+        Element::new(draggable)
+    }
+}
+
+/// The preview of a [`Draggable`] following the cursor.
+///
+/// It is never hovered, so the widgets below it keep receiving the cursor.
+struct Preview<'a, 'b, Message, Theme, Renderer> {
+    content: &'b Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    position: Point,
+    size: Size,
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Preview<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, _bounds: Size) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(
+                self.tree,
+                renderer,
+                &layout::Limits::new(Size::ZERO, self.size),
+            )
+            .move_to(self.position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            mouse::Cursor::Unavailable,
+            &layout.bounds(),
+        );
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        false
+    }
+}
+
+/// The payload of the [`Draggable`] currently being dragged.
+struct Active {
+    payload: Box<dyn Any>,
+    owner: Weak<()>,
+    is_dropped: bool,
+}
+
+thread_local! {
+    static ACTIVE: RefCell<Option<Active>> = const { RefCell::new(None) };
+}
+
+/// Runs the given closure with the payload being dragged, discarding it
+/// first if its [`Draggable`] has been removed mid-drag.
+fn with_active<T>(f: impl FnOnce(&mut Option<Active>) -> T) -> T {
+    ACTIVE.with_borrow_mut(|active| {
+        if active
+            .as_ref()
+            .is_some_and(|active| active.owner.strong_count() == 0)
+        {
+            *active = None;
+        }
+
+        f(active)
+    })
+}
+
+/// Returns whether a payload of the given type is being dragged and, if so,
+/// whether it satisfies the given predicate.
+pub(crate) fn is_dragging<Payload: 'static>(
+    f: impl FnOnce(&Payload) -> bool,
+) -> Option<bool> {
+    with_active(|active| {
+        let active = active.as_ref().filter(|active| !active.is_dropped)?;
+
+        active.payload.downcast_ref().map(f)
+    })
+}
+
+/// Marks the payload being dragged as dropped.
+///
+/// Either the [`Draggable`] or a [`DropZone`] may see the release of the
+/// drag first, so both call this before anything else.
+///
+/// [`DropZone`]: crate::DropZone
+pub(crate) fn drop_payload() {
+    with_active(|active| {
+        if let Some(active) = active {
+            active.is_dropped = true;
+        }
+    });
+}
+
+/// Takes the payload that was just dropped, if it has the given type and
+/// satisfies the given predicate.
+pub(crate) fn take_payload<Payload: 'static>(
+    f: impl FnOnce(&Payload) -> bool,
+) -> Option<Payload> {
+    with_active(|active| {
+        let is_accepted = active
+            .as_ref()
+            .filter(|active| active.is_dropped)
+            .and_then(|active| active.payload.downcast_ref())
+            .is_some_and(f);
+
+        if !is_accepted {
+            return None;
+        }
+
+        active
+            .take()
+            .and_then(|active| active.payload.downcast().ok())
+            .map(|payload| *payload)
+    })
+}
+
+/// Discards the payload of a finished drag that was not dropped on any
+/// [`DropZone`].
+///
+/// [`DropZone`]: crate::DropZone
+pub(crate) fn clear_dropped() {
+    with_active(|active| {
+        if active.as_ref().is_some_and(|active| active.is_dropped) {
+            *active = None;
+        }
+    });
+}
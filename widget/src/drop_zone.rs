@@ -0,0 +1,447 @@
+//! Receive the payload of a [`Draggable`] dropped on some content.
+//!
+//! [`Draggable`]: crate::Draggable
+use crate::core::drag_and_drop;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Rectangle,
+    Shell, Size, Theme, Vector, Widget,
+};
+use crate::draggable;
+
+/// A widget that receives the payload of a [`Draggable`] dropped on it.
+///
+/// While a [`Draggable`] with an acceptable payload is being dragged, the
+/// [`DropZone`] is highlighted; even more so when the cursor is over it.
+///
+/// A [`DropZone`] can also receive data dragged from other applications;
+/// see [`DropZone::on_data_drop`].
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// # use iced_widget::{column, draggable, drop_zone, text};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(usize),
+/// }
+///
+/// fn view<'a>() -> Element<'a, Message> {
+///     column![
+///         draggable(text("Drag me!"), 42),
+///         drop_zone(text("Only odd numbers!"))
+///             .accept(|number: &usize| number % 2 == 1)
+///             .on_drop(Message::Dropped),
+///     ]
+///     .into()
+/// }
+/// ```
+///
+/// [`Draggable`]: crate::Draggable
+#[allow(missing_debug_implementations)]
+pub struct DropZone<
+    'a,
+    Payload,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drop: Option<Box<dyn Fn(Payload) -> Message + 'a>>,
+    on_data_drop: Option<Box<dyn Fn(drag_and_drop::Data) -> Message + 'a>>,
+    accept: Option<Box<dyn Fn(&Payload) -> bool + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Payload, Message, Theme, Renderer>
+    DropZone<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: 'static,
+    Theme: Catalog,
+{
+    /// Creates a new [`DropZone`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_drop: None,
+            on_data_drop: None,
+            accept: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when a payload is dropped on
+    /// the [`DropZone`].
+    ///
+    /// Unless this method is called, nothing can be dropped on the
+    /// [`DropZone`].
+    pub fn on_drop(
+        mut self,
+        on_drop: impl Fn(Payload) -> Message + 'a,
+    ) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the message that will be produced when data dragged from another
+    /// application is dropped on the [`DropZone`].
+    ///
+    /// Drops whose position is unknown are not delivered to any
+    /// [`DropZone`]; see [`window::Event::DragDropped`].
+    pub fn on_data_drop(
+        mut self,
+        on_data_drop: impl Fn(drag_and_drop::Data) -> Message + 'a,
+    ) -> Self {
+        self.on_data_drop = Some(Box::new(on_data_drop));
+        self
+    }
+
+    /// Sets the predicate deciding which payloads the [`DropZone`] accepts.
+    ///
+    /// By default, any payload of the right type is accepted.
+    pub fn accept(mut self, accept: impl Fn(&Payload) -> bool + 'a) -> Self {
+        self.accept = Some(Box::new(accept));
+        self
+    }
+
+    /// Sets the style of the [`DropZone`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DropZone`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn is_accepted(&self, payload: &Payload) -> bool {
+        match &self.accept {
+            _ if self.on_drop.is_none() => false,
+            Some(accept) => accept(payload),
+            None => true,
+        }
+    }
+}
+
+impl<'a, Payload, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropZone<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: 'static,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match &event {
+            Event::Window(window::Event::DragEntered { .. }) => {
+                state.is_receiving_data = self.on_data_drop.is_some();
+            }
+            Event::Window(window::Event::DragLeft) => {
+                state.is_receiving_data = false;
+            }
+            Event::Window(window::Event::DragDropped { position, data }) => {
+                state.is_receiving_data = false;
+
+                if let (Some(on_data_drop), Some(position)) =
+                    (&self.on_data_drop, position)
+                {
+                    if layout.bounds().contains(*position) {
+                        shell.publish(on_data_drop(data.clone()));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if cursor.is_over(layout.bounds()) =>
+            {
+                draggable::drop_payload();
+
+                if let Some(payload) =
+                    draggable::take_payload(|payload| self.is_accepted(payload))
+                {
+                    if let Some(on_drop) = &self.on_drop {
+                        shell.publish(on_drop(payload));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(_)) => {}
+            _ => {
+                draggable::clear_dropped();
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let is_available = state.is_receiving_data
+            || draggable::is_dragging(|payload| self.is_accepted(payload))
+                .unwrap_or(false);
+
+        let status = if !is_available {
+            Status::Active
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Available
+        };
+
+        let style = theme.style(&self.class, status);
+
+        if style.background.is_some() || style.border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Payload, Message, Theme, Renderer>
+    From<DropZone<'a, Payload, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Payload: 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        drop_zone: DropZone<'a, Payload, Message, Theme, Renderer>,
+    ) -> Self {
+        Element::new(drop_zone)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_receiving_data: bool,
+}
+
+/// The possible status of a [`DropZone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Nothing that the [`DropZone`] accepts is being dragged.
+    Active,
+    /// A payload that the [`DropZone`] accepts is being dragged.
+    Available,
+    /// A payload that the [`DropZone`] accepts is being dragged over it.
+    Hovered,
+}
+
+/// The appearance of a [`DropZone`], drawn over its contents.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The [`Background`] of the [`DropZone`].
+    pub background: Option<Background>,
+    /// The [`Border`] of the [`DropZone`].
+    pub border: Border,
+}
+
+/// The theme catalog of a [`DropZone`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`DropZone`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`DropZone`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Active => Style::default(),
+        Status::Available => Style {
+            background: None,
+            border: Border {
+                color: palette.primary.weak.color,
+                width: 1.0,
+                radius: 2.0.into(),
+            },
+        },
+        Status::Hovered => Style {
+            background: Some(
+                palette.primary.weak.color.scale_alpha(0.3).into(),
+            ),
+            border: Border {
+                color: palette.primary.strong.color,
+                width: 2.0,
+                radius: 2.0.into(),
+            },
+        },
+    }
+}
@@ -0,0 +1,496 @@
+//! Distribute content in a grid of rows and columns.
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Length, Padding, Pixels, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+use std::collections::HashSet;
+
+/// A container that distributes its contents in a grid of rows and columns.
+///
+/// The size of every column and row—its track—is defined by a [`Length`].
+/// Children are placed in the next free cell of the grid, from left to right
+/// and top to bottom, unless a [`Cell`] with an explicit position is pushed.
+/// New rows are added as needed.
+///
+/// # Example
+/// ```no_run
+/// # type Grid<'a, Message> = iced_widget::Grid<'a, Message>;
+/// # type Cell<'a, Message> = iced_widget::grid::Cell<'a, Message>;
+/// # use iced_widget::core::Length;
+/// # use iced_widget::{text, text_input};
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     NameChanged(String),
+///     EmailChanged(String),
+/// }
+///
+/// fn form<'a>(name: &str, email: &str) -> Grid<'a, Message> {
+///     Grid::new()
+///         .columns([Length::Shrink, Length::Fill])
+///         .spacing(10)
+///         .push(text("Name"))
+///         .push(text_input("Name", name).on_input(Message::NameChanged))
+///         .push(text("Email"))
+///         .push(text_input("Email", email).on_input(Message::EmailChanged))
+///         .push_cell(Cell::new(text("Every field is required")).span(1, 2))
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    column_spacing: f32,
+    row_spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    placements: Vec<Placement>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates an empty [`Grid`] with a single [`Length::Shrink`] column.
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            placements: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Grid`] with the given elements.
+    pub fn with_children(
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self::new().extend(children)
+    }
+
+    /// Sets the column tracks of the [`Grid`].
+    pub fn columns(
+        mut self,
+        columns: impl IntoIterator<Item = impl Into<Length>>,
+    ) -> Self {
+        self.columns = columns.into_iter().map(Into::into).collect();
+        self.width = self
+            .columns
+            .iter()
+            .fold(self.width, |width, column| width.enclose(*column));
+        self
+    }
+
+    /// Sets the row tracks of the [`Grid`].
+    ///
+    /// Any additional rows needed to fit the contents of the [`Grid`] will
+    /// be [`Length::Shrink`] tracks.
+    pub fn rows(
+        mut self,
+        rows: impl IntoIterator<Item = impl Into<Length>>,
+    ) -> Self {
+        self.rows = rows.into_iter().map(Into::into).collect();
+        self.height = self
+            .rows
+            .iter()
+            .fold(self.height, |height, row| height.enclose(*row));
+        self
+    }
+
+    /// Sets the spacing _between_ both rows and columns.
+    pub fn spacing(self, amount: impl Into<Pixels>) -> Self {
+        let amount = amount.into();
+
+        self.column_spacing(amount).row_spacing(amount)
+    }
+
+    /// Sets the spacing _between_ columns.
+    pub fn column_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.column_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the spacing _between_ rows.
+    pub fn row_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.row_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Grid`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Adds an [`Element`] to the next free cell of the [`Grid`].
+    pub fn push(
+        self,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.push_cell(Cell::new(child))
+    }
+
+    /// Adds a [`Cell`] to the [`Grid`].
+    pub fn push_cell(
+        mut self,
+        cell: Cell<'a, Message, Theme, Renderer>,
+    ) -> Self {
+        let child_size = cell.content.as_widget().size_hint();
+
+        self.width = self.width.enclose(child_size.width);
+        self.height = self.height.enclose(child_size.height);
+
+        self.placements.push(cell.placement);
+        self.children.push(cell.content);
+        self
+    }
+
+    /// Extends the [`Grid`] with the given children.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
+
+    /// Computes the [`layout::grid::Area`] of every child.
+    ///
+    /// Cells with an explicit position are placed first. The rest fill
+    /// the free cells of the grid in order, skipping any cells where they
+    /// do not fit.
+    fn areas(&self) -> Vec<layout::grid::Area> {
+        let columns = self.columns.len().max(1);
+        let mut occupied = HashSet::new();
+
+        fn occupy(
+            occupied: &mut HashSet<(usize, usize)>,
+            area: layout::grid::Area,
+        ) {
+            for row in area.row..area.row + area.rows {
+                for column in area.column..area.column + area.columns {
+                    let _ = occupied.insert((row, column));
+                }
+            }
+        }
+
+        let mut areas: Vec<_> = self
+            .placements
+            .iter()
+            .map(|placement| {
+                let (row, column) = placement.position?;
+
+                let area = layout::grid::Area {
+                    row,
+                    column,
+                    rows: placement.rows,
+                    columns: placement.columns,
+                };
+
+                occupy(&mut occupied, area);
+
+                Some(area)
+            })
+            .collect();
+
+        let (mut row, mut column) = (0, 0);
+
+        for (area, placement) in areas.iter_mut().zip(&self.placements) {
+            if area.is_some() {
+                continue;
+            }
+
+            let span = placement.columns.min(columns);
+
+            loop {
+                if column + span > columns {
+                    row += 1;
+                    column = 0;
+                    continue;
+                }
+
+                let candidate = layout::grid::Area {
+                    row,
+                    column,
+                    rows: placement.rows,
+                    columns: span,
+                };
+
+                let is_free = (row..row + placement.rows).all(|row| {
+                    (column..column + span)
+                        .all(|column| !occupied.contains(&(row, column)))
+                });
+
+                if is_free {
+                    occupy(&mut occupied, candidate);
+                    column += span;
+
+                    *area = Some(candidate);
+                    break;
+                }
+
+                column += 1;
+            }
+        }
+
+        areas.into_iter().flatten().collect()
+    }
+}
+
+impl<'a, Message, Renderer> Default for Grid<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Theme, Renderer: crate::core::Renderer>
+    FromIterator<Element<'a, Message, Theme, Renderer>>
+    for Grid<'a, Message, Theme, Renderer>
+{
+    fn from_iter<
+        T: IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    >(
+        iter: T,
+    ) -> Self {
+        Self::with_children(iter)
+    }
+}
+
+/// Some content of a [`Grid`] with an optional position and span.
+#[allow(missing_debug_implementations)]
+pub struct Cell<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    placement: Placement,
+}
+
+impl<'a, Message, Theme, Renderer> Cell<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Cell`] with the given content, spanning a single row
+    /// and column.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            placement: Placement {
+                position: None,
+                rows: 1,
+                columns: 1,
+            },
+        }
+    }
+
+    /// Places the [`Cell`] at the given row and column, starting at zero.
+    ///
+    /// By default, a [`Cell`] is placed in the next free cell of the
+    /// [`Grid`].
+    pub fn at(mut self, row: usize, column: usize) -> Self {
+        self.placement.position = Some((row, column));
+        self
+    }
+
+    /// Sets the amount of rows and columns spanned by the [`Cell`].
+    pub fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.placement.rows = rows.max(1);
+        self.placement.columns = columns.max(1);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Placement {
+    position: Option<(usize, usize)>,
+    rows: usize,
+    columns: usize,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::grid::resolve(
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            self.padding,
+            &self.columns,
+            &self.rows,
+            self.column_spacing,
+            self.row_spacing,
+            &self.areas(),
+            &self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Grid<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(grid: Grid<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(grid)
+    }
+}
@@ -1,12 +1,18 @@
 //! Helper functions to create pure widgets.
 use crate::button::{self, Button};
 use crate::checkbox::{self, Checkbox};
+use crate::color_picker::{self, ColorPicker};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
 use crate::core;
 use crate::core::widget::operation;
 use crate::core::{Element, Length, Pixels, Widget};
+use crate::date_picker::{self, DatePicker};
+use crate::draggable::Draggable;
+use crate::drop_zone::{self, DropZone};
 use crate::keyed;
+use crate::menu::{self, ContextMenu, MenuBar};
+use crate::number_input::{self, NumberInput};
 use crate::overlay;
 use crate::pick_list::{self, PickList};
 use crate::progress_bar::{self, ProgressBar};
@@ -16,13 +22,16 @@ use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::table::{self, Table};
+use crate::tabs::{self, Tabs};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
+use crate::time_picker::{self, TimePicker};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
 use crate::vertical_slider::{self, VerticalSlider};
-use crate::{Column, MouseArea, Row, Space, Stack, Themer};
+use crate::{Column, Grid, MouseArea, Row, Space, Stack, Themer, VirtualList};
 
 use std::borrow::{Borrow, Cow};
 use std::ops::RangeInclusive;
@@ -170,6 +179,18 @@ where
     Column::with_children(children)
 }
 
+/// Creates a new [`Grid`] with the given children.
+///
+/// [`Grid`]: crate::Grid
+pub fn grid<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Grid::with_children(children)
+}
+
 /// Creates a new [`keyed::Column`] with the given children.
 pub fn keyed_column<'a, Key, Message, Theme, Renderer>(
     children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
@@ -628,6 +649,126 @@ where
     Scrollable::new(content)
 }
 
+/// Creates a new [`VirtualList`] of the given items, using the `view`
+/// closure to build the rows in view.
+///
+/// [`VirtualList`]: crate::VirtualList
+pub fn virtual_list<'a, T, Message, Theme, Renderer>(
+    items: &'a [T],
+    view: impl Fn(usize, &'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> VirtualList<'a, T, Message, Theme, Renderer>
+where
+    Theme: scrollable::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    VirtualList::new(items, view)
+}
+
+/// Creates a new [`Table`] with the given columns and rows.
+///
+/// [`Table`]: crate::Table
+pub fn table<'a, T, Message, Theme, Renderer>(
+    columns: impl IntoIterator<
+        Item = table::Column<'a, T, Message, Theme, Renderer>,
+    >,
+    rows: impl IntoIterator<Item = T>,
+) -> Table<'a, Message, Theme, Renderer>
+where
+    Theme: table::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    Table::new(columns, rows)
+}
+
+/// Creates new [`Tabs`] with the given tabs and the index of the active one.
+///
+/// [`Tabs`]: crate::Tabs
+pub fn tabs<'a, Message, Theme, Renderer>(
+    tabs: impl IntoIterator<Item = tabs::Tab>,
+    active: usize,
+) -> Tabs<'a, Message, Theme, Renderer>
+where
+    Theme: tabs::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    Tabs::new(tabs, active)
+}
+
+/// Creates a new [`MenuBar`] with the given menus.
+///
+/// [`MenuBar`]: crate::MenuBar
+pub fn menu_bar<'a, Message, Theme>(
+    menus: impl IntoIterator<Item = menu::Item<Message>>,
+) -> MenuBar<'a, Message, Theme>
+where
+    Theme: menu::Catalog + 'a,
+{
+    MenuBar::new(menus)
+}
+
+/// Creates a new [`ContextMenu`] that shows the given items when the
+/// content is right clicked.
+///
+/// [`ContextMenu`]: crate::ContextMenu
+pub fn context_menu<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = menu::Item<Message>>,
+) -> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: menu::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    ContextMenu::new(content, items)
+}
+
+/// Creates a new [`DatePicker`] that shows a calendar when the given trigger
+/// is clicked.
+///
+/// [`DatePicker`]: crate::DatePicker
+pub fn date_picker<'a, Message, Theme, Renderer>(
+    trigger: impl Into<Element<'a, Message, Theme, Renderer>>,
+    date: impl Into<Option<date_picker::Date>>,
+    on_pick: impl Fn(date_picker::Date) -> Message + 'a,
+) -> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: date_picker::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    DatePicker::new(trigger, date, on_pick)
+}
+
+/// Creates a new [`TimePicker`] that shows a panel to pick a time when the
+/// given trigger is clicked.
+///
+/// [`TimePicker`]: crate::TimePicker
+pub fn time_picker<'a, Message, Theme, Renderer>(
+    trigger: impl Into<Element<'a, Message, Theme, Renderer>>,
+    time: time_picker::Time,
+    on_change: impl Fn(time_picker::Time) -> Message + 'a,
+) -> TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: time_picker::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    TimePicker::new(trigger, time, on_change)
+}
+
+/// Creates a new [`ColorPicker`] that shows a panel to pick a color when the
+/// given trigger is clicked.
+///
+/// [`ColorPicker`]: crate::ColorPicker
+pub fn color_picker<'a, Message, Theme, Renderer>(
+    trigger: impl Into<Element<'a, Message, Theme, Renderer>>,
+    color: core::Color,
+    on_change: impl Fn(core::Color) -> Message + 'a,
+) -> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: color_picker::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    ColorPicker::new(trigger, color, on_change)
+}
+
 /// Creates a new [`Button`] with the provided content.
 ///
 /// [`Button`]: crate::Button
@@ -769,6 +910,26 @@ where
     TextInput::new(placeholder, value)
 }
 
+/// Creates a new [`NumberInput`].
+///
+/// [`NumberInput`]: crate::NumberInput
+pub fn number_input<'a, T, Message, Theme, Renderer>(
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: num_traits::Num
+        + num_traits::Bounded
+        + Copy
+        + PartialOrd
+        + std::fmt::Display
+        + std::str::FromStr,
+    Theme: number_input::Catalog + text_input::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    NumberInput::new(value, on_change)
+}
+
 /// Creates a new [`TextEditor`].
 ///
 /// [`TextEditor`]: crate::TextEditor
@@ -1020,6 +1181,34 @@ where
     MouseArea::new(widget)
 }
 
+/// Creates a new [`Draggable`] carrying the given payload.
+///
+/// [`Draggable`]: crate::Draggable
+pub fn draggable<'a, Payload, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    payload: Payload,
+) -> Draggable<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Renderer: core::Renderer,
+{
+    Draggable::new(content, payload)
+}
+
+/// Creates a new [`DropZone`] with the given content.
+///
+/// [`DropZone`]: crate::DropZone
+pub fn drop_zone<'a, Payload, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DropZone<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: 'static,
+    Theme: drop_zone::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    DropZone::new(content)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, OldTheme, NewTheme, Renderer>(
     new_theme: NewTheme,
@@ -4,11 +4,15 @@
 //! in code blocks.
 //!
 //! Only the variants of [`Item`] are currently supported.
+use crate::core::alignment;
 use crate::core::font::{self, Font};
 use crate::core::padding;
 use crate::core::theme::{self, Theme};
 use crate::core::{self, Element, Length, Pixels};
-use crate::{column, container, rich_text, row, scrollable, span, text};
+use crate::{
+    checkbox, column, container, horizontal_rule, rich_text, row, scrollable,
+    span, text, vertical_rule,
+};
 
 pub use pulldown_cmark::HeadingLevel;
 pub use url::Url;
@@ -29,8 +33,113 @@ pub enum Item {
         /// The first number of the list, if it is ordered.
         start: Option<u64>,
         /// The items of the list.
-        items: Vec<Vec<Item>>,
+        items: Vec<Bullet>,
     },
+    /// A block quote.
+    Quote(Vec<Item>),
+    /// A horizontal rule.
+    Rule,
+    /// An image standing in a paragraph of its own.
+    ///
+    /// Images surrounded by other content are kept inline in their
+    /// paragraph, displayed with their alternative text.
+    Image {
+        /// The destination of the image, as written in the Markdown source.
+        url: String,
+        /// The title of the image.
+        title: String,
+        /// The alternative text of the image.
+        alt: Vec<text::Span<'static, Url>>,
+    },
+    /// A table.
+    Table {
+        /// The columns of the table.
+        columns: Vec<Column>,
+        /// The rows of the table, each one with a cell per column.
+        rows: Vec<Vec<Vec<text::Span<'static, Url>>>>,
+    },
+    /// A footnote definition.
+    Footnote {
+        /// The label of the footnote.
+        label: String,
+        /// The contents of the footnote.
+        items: Vec<Item>,
+    },
+}
+
+/// An item of a [`Item::List`].
+#[derive(Debug, Clone)]
+pub enum Bullet {
+    /// A regular item.
+    Point {
+        /// The contents of the item.
+        items: Vec<Item>,
+    },
+    /// An item with a checkbox, written as `- [ ]` or `- [x]`.
+    Task {
+        /// The contents of the item.
+        items: Vec<Item>,
+        /// Whether the task is done or not.
+        done: bool,
+    },
+}
+
+impl Bullet {
+    /// Returns the contents of the [`Bullet`].
+    pub fn items(&self) -> &[Item] {
+        match self {
+            Bullet::Point { items } | Bullet::Task { items, .. } => items,
+        }
+    }
+
+    fn items_mut(&mut self) -> &mut Vec<Item> {
+        match self {
+            Bullet::Point { items } | Bullet::Task { items, .. } => items,
+        }
+    }
+}
+
+/// A column of an [`Item::Table`].
+#[derive(Debug, Clone)]
+pub struct Column {
+    /// The header of the column.
+    pub header: Vec<text::Span<'static, Url>>,
+    /// The alignment of the contents of the column.
+    pub alignment: alignment::Horizontal,
+}
+
+/// An interaction with a link of some Markdown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Link {
+    /// The link was clicked.
+    Clicked(Url),
+    /// The mouse cursor entered the link.
+    Hovered(Url),
+    /// The mouse cursor exited the link.
+    Exited(Url),
+    /// The link was pressed with the right mouse button.
+    RightPressed(Url),
+}
+
+impl Link {
+    /// Returns the [`Url`] of the [`Link`].
+    pub fn url(&self) -> &Url {
+        match self {
+            Link::Clicked(url)
+            | Link::Hovered(url)
+            | Link::Exited(url)
+            | Link::RightPressed(url) => url,
+        }
+    }
+
+    fn into_url(self) -> Url {
+        match self {
+            Link::Clicked(url)
+            | Link::Hovered(url)
+            | Link::Exited(url)
+            | Link::RightPressed(url) => url,
+        }
+    }
 }
 
 /// Parse the given Markdown content.
@@ -38,18 +147,82 @@ pub fn parse(
     markdown: &str,
     palette: theme::Palette,
 ) -> impl Iterator<Item = Item> + '_ {
-    struct List {
-        start: Option<u64>,
-        items: Vec<Vec<Item>>,
+    enum Scope {
+        List {
+            start: Option<u64>,
+            items: Vec<Bullet>,
+        },
+        Quote(Vec<Item>),
+        Footnote {
+            label: String,
+            items: Vec<Item>,
+        },
+    }
+
+    struct Table {
+        columns: Vec<Column>,
+        rows: Vec<Vec<Vec<text::Span<'static, Url>>>>,
+        row: Vec<Vec<text::Span<'static, Url>>>,
+    }
+
+    fn produce(scopes: &mut [Scope], item: Item) -> Option<Item> {
+        match scopes.last_mut() {
+            None => Some(item),
+            Some(Scope::List { items, .. }) => {
+                items
+                    .last_mut()
+                    .expect("item context")
+                    .items_mut()
+                    .push(item);
+
+                None
+            }
+            Some(Scope::Quote(items) | Scope::Footnote { items, .. }) => {
+                items.push(item);
+
+                None
+            }
+        }
+    }
+
+    // We want to keep the `spans` capacity
+    #[allow(clippy::drain_collect)]
+    fn paragraph(
+        scopes: &mut [Scope],
+        spans: &mut Vec<text::Span<'static, Url>>,
+        standalone: &mut Option<(String, String, usize)>,
+    ) -> Option<Item> {
+        // An image is only a block of its own when nothing else shares its
+        // paragraph; otherwise its alternative text stays inline
+        if let Some((url, title, alt_end)) = standalone.take() {
+            if alt_end == spans.len() {
+                return produce(
+                    scopes,
+                    Item::Image {
+                        url,
+                        title,
+                        alt: spans.drain(..).collect(),
+                    },
+                );
+            }
+        }
+
+        if spans.is_empty() {
+            None
+        } else {
+            produce(scopes, Item::Paragraph(spans.drain(..).collect()))
+        }
     }
 
     let mut spans = Vec::new();
     let mut strong = false;
     let mut emphasis = false;
     let mut metadata = false;
-    let mut table = false;
     let mut link = None;
-    let mut lists = Vec::new();
+    let mut image = None;
+    let mut standalone = None;
+    let mut table = None;
+    let mut scopes = Vec::new();
 
     #[cfg(feature = "highlighter")]
     let mut highlighter = None;
@@ -58,40 +231,23 @@ pub fn parse(
         markdown,
         pulldown_cmark::Options::ENABLE_YAML_STYLE_METADATA_BLOCKS
             | pulldown_cmark::Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS
-            | pulldown_cmark::Options::ENABLE_TABLES,
+            | pulldown_cmark::Options::ENABLE_TABLES
+            | pulldown_cmark::Options::ENABLE_FOOTNOTES
+            | pulldown_cmark::Options::ENABLE_TASKLISTS,
     );
 
-    let produce = |lists: &mut Vec<List>, item| {
-        if lists.is_empty() {
-            Some(item)
-        } else {
-            lists
-                .last_mut()
-                .expect("list context")
-                .items
-                .last_mut()
-                .expect("item context")
-                .push(item);
-
-            None
-        }
-    };
-
-    // We want to keep the `spans` capacity
     #[allow(clippy::drain_collect)]
     parser.filter_map(move |event| match event {
         pulldown_cmark::Event::Start(tag) => match tag {
-            pulldown_cmark::Tag::Strong if !metadata && !table => {
+            pulldown_cmark::Tag::Strong if !metadata => {
                 strong = true;
                 None
             }
-            pulldown_cmark::Tag::Emphasis if !metadata && !table => {
+            pulldown_cmark::Tag::Emphasis if !metadata => {
                 emphasis = true;
                 None
             }
-            pulldown_cmark::Tag::Link { dest_url, .. }
-                if !metadata && !table =>
-            {
+            pulldown_cmark::Tag::Link { dest_url, .. } if !metadata => {
                 match Url::parse(&dest_url) {
                     Ok(url)
                         if url.scheme() == "http"
@@ -104,25 +260,52 @@ pub fn parse(
 
                 None
             }
-            pulldown_cmark::Tag::List(first_item) if !metadata && !table => {
-                lists.push(List {
+            pulldown_cmark::Tag::Image {
+                dest_url, title, ..
+            } if !metadata && table.is_none() => {
+                image = Some((
+                    dest_url.into_string(),
+                    title.into_string(),
+                    spans.len(),
+                ));
+
+                None
+            }
+            pulldown_cmark::Tag::List(first_item) if !metadata => {
+                let item = paragraph(&mut scopes, &mut spans, &mut standalone);
+
+                scopes.push(Scope::List {
                     start: first_item,
                     items: Vec::new(),
                 });
 
-                None
+                item
             }
             pulldown_cmark::Tag::Item => {
-                lists
-                    .last_mut()
-                    .expect("list context")
-                    .items
-                    .push(Vec::new());
+                if let Some(Scope::List { items, .. }) = scopes.last_mut() {
+                    items.push(Bullet::Point { items: Vec::new() });
+                }
+
+                None
+            }
+            pulldown_cmark::Tag::BlockQuote(_) if !metadata => {
+                let item = paragraph(&mut scopes, &mut spans, &mut standalone);
+
+                scopes.push(Scope::Quote(Vec::new()));
+
+                item
+            }
+            pulldown_cmark::Tag::FootnoteDefinition(label) if !metadata => {
+                scopes.push(Scope::Footnote {
+                    label: label.into_string(),
+                    items: Vec::new(),
+                });
+
                 None
             }
             pulldown_cmark::Tag::CodeBlock(
                 pulldown_cmark::CodeBlockKind::Fenced(_language),
-            ) if !metadata && !table => {
+            ) if !metadata => {
                 #[cfg(feature = "highlighter")]
                 {
                     use iced_highlighter::{self, Highlighter};
@@ -141,74 +324,137 @@ pub fn parse(
                 metadata = true;
                 None
             }
-            pulldown_cmark::Tag::Table(_) => {
-                table = true;
+            pulldown_cmark::Tag::Table(alignments) if !metadata => {
+                table = Some(Table {
+                    columns: alignments
+                        .into_iter()
+                        .map(|alignment| Column {
+                            header: Vec::new(),
+                            alignment: match alignment {
+                                pulldown_cmark::Alignment::None
+                                | pulldown_cmark::Alignment::Left => {
+                                    alignment::Horizontal::Left
+                                }
+                                pulldown_cmark::Alignment::Center => {
+                                    alignment::Horizontal::Center
+                                }
+                                pulldown_cmark::Alignment::Right => {
+                                    alignment::Horizontal::Right
+                                }
+                            },
+                        })
+                        .collect(),
+                    rows: Vec::new(),
+                    row: Vec::new(),
+                });
+
                 None
             }
             _ => None,
         },
         pulldown_cmark::Event::End(tag) => match tag {
-            pulldown_cmark::TagEnd::Heading(level) if !metadata && !table => {
+            pulldown_cmark::TagEnd::Heading(level) if !metadata => {
+                standalone = None;
+
                 produce(
-                    &mut lists,
+                    &mut scopes,
                     Item::Heading(level, spans.drain(..).collect()),
                 )
             }
-            pulldown_cmark::TagEnd::Emphasis if !metadata && !table => {
+            pulldown_cmark::TagEnd::Emphasis if !metadata => {
                 emphasis = false;
                 None
             }
-            pulldown_cmark::TagEnd::Strong if !metadata && !table => {
+            pulldown_cmark::TagEnd::Strong if !metadata => {
                 strong = false;
                 None
             }
-            pulldown_cmark::TagEnd::Link if !metadata && !table => {
+            pulldown_cmark::TagEnd::Link if !metadata => {
                 link = None;
                 None
             }
-            pulldown_cmark::TagEnd::Paragraph if !metadata && !table => {
-                produce(&mut lists, Item::Paragraph(spans.drain(..).collect()))
-            }
-            pulldown_cmark::TagEnd::Item if !metadata && !table => {
-                if spans.is_empty() {
-                    None
-                } else {
-                    produce(
-                        &mut lists,
-                        Item::Paragraph(spans.drain(..).collect()),
-                    )
+            pulldown_cmark::TagEnd::Image if !metadata => {
+                let (url, title, alt_start) = image.take()?;
+
+                if alt_start == 0 {
+                    standalone = Some((url, title, spans.len()));
                 }
+
+                None
+            }
+            pulldown_cmark::TagEnd::Paragraph
+            | pulldown_cmark::TagEnd::Item
+                if !metadata && table.is_none() =>
+            {
+                paragraph(&mut scopes, &mut spans, &mut standalone)
             }
-            pulldown_cmark::TagEnd::List(_) if !metadata && !table => {
-                let list = lists.pop().expect("list context");
+            pulldown_cmark::TagEnd::List(_) if !metadata => {
+                let Some(Scope::List { start, items }) = scopes.pop() else {
+                    return None;
+                };
 
-                produce(
-                    &mut lists,
-                    Item::List {
-                        start: list.start,
-                        items: list.items,
-                    },
-                )
+                produce(&mut scopes, Item::List { start, items })
             }
-            pulldown_cmark::TagEnd::CodeBlock if !metadata && !table => {
+            pulldown_cmark::TagEnd::BlockQuote if !metadata => {
+                let Some(Scope::Quote(items)) = scopes.pop() else {
+                    return None;
+                };
+
+                produce(&mut scopes, Item::Quote(items))
+            }
+            pulldown_cmark::TagEnd::FootnoteDefinition if !metadata => {
+                let Some(Scope::Footnote { label, items }) = scopes.pop()
+                else {
+                    return None;
+                };
+
+                produce(&mut scopes, Item::Footnote { label, items })
+            }
+            pulldown_cmark::TagEnd::CodeBlock if !metadata => {
                 #[cfg(feature = "highlighter")]
                 {
                     highlighter = None;
                 }
 
-                produce(&mut lists, Item::CodeBlock(spans.drain(..).collect()))
+                produce(&mut scopes, Item::CodeBlock(spans.drain(..).collect()))
             }
             pulldown_cmark::TagEnd::MetadataBlock(_) => {
                 metadata = false;
                 None
             }
-            pulldown_cmark::TagEnd::Table => {
-                table = false;
+            pulldown_cmark::TagEnd::TableCell => {
+                if let Some(table) = &mut table {
+                    table.row.push(spans.drain(..).collect());
+                }
+
+                None
+            }
+            pulldown_cmark::TagEnd::TableHead => {
+                if let Some(table) = &mut table {
+                    for (column, header) in
+                        table.columns.iter_mut().zip(table.row.drain(..))
+                    {
+                        column.header = header;
+                    }
+                }
+
                 None
             }
+            pulldown_cmark::TagEnd::TableRow => {
+                if let Some(table) = &mut table {
+                    table.rows.push(table.row.drain(..).collect());
+                }
+
+                None
+            }
+            pulldown_cmark::TagEnd::Table => {
+                let Table { columns, rows, .. } = table.take()?;
+
+                produce(&mut scopes, Item::Table { columns, rows })
+            }
             _ => None,
         },
-        pulldown_cmark::Event::Text(text) if !metadata && !table => {
+        pulldown_cmark::Event::Text(text) if !metadata => {
             #[cfg(feature = "highlighter")]
             if let Some(highlighter) = &mut highlighter {
                 use text::Highlighter as _;
@@ -256,7 +502,7 @@ pub fn parse(
 
             None
         }
-        pulldown_cmark::Event::Code(code) if !metadata && !table => {
+        pulldown_cmark::Event::Code(code) if !metadata => {
             let span = span(code.into_string()).font(Font::MONOSPACE);
 
             let span = if let Some(link) = link.as_ref() {
@@ -268,14 +514,32 @@ pub fn parse(
             spans.push(span);
             None
         }
-        pulldown_cmark::Event::SoftBreak if !metadata && !table => {
+        pulldown_cmark::Event::SoftBreak if !metadata => {
             spans.push(span(" "));
             None
         }
-        pulldown_cmark::Event::HardBreak if !metadata && !table => {
+        pulldown_cmark::Event::HardBreak if !metadata => {
             spans.push(span("\n"));
             None
         }
+        pulldown_cmark::Event::Rule if !metadata => {
+            produce(&mut scopes, Item::Rule)
+        }
+        pulldown_cmark::Event::TaskListMarker(done) if !metadata => {
+            if let Some(Scope::List { items, .. }) = scopes.last_mut() {
+                if let Some(bullet) = items.last_mut() {
+                    let items = std::mem::take(bullet.items_mut());
+
+                    *bullet = Bullet::Task { items, done };
+                }
+            }
+
+            None
+        }
+        pulldown_cmark::Event::FootnoteReference(label) if !metadata => {
+            spans.push(span(format!("[{label}]")).color(palette.primary));
+            None
+        }
         _ => None,
     })
 }
@@ -332,11 +596,77 @@ impl Default for Settings {
 /// Display a bunch of Markdown items.
 ///
 /// You can obtain the items with [`parse`].
+///
+/// Images are displayed with their alternative text. Use
+/// [`view_with_images`] to display them.
 pub fn view<'a, Message, Renderer>(
     items: impl IntoIterator<Item = &'a Item>,
     settings: Settings,
     on_link: impl Fn(Url) -> Message + Copy + 'a,
 ) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    view_with_images(items, settings, on_link, |_| None)
+}
+
+/// Display a bunch of Markdown items, loading images with the given
+/// function.
+///
+/// The `load_image` function receives the URL of an [`Item::Image`] and
+/// produces the widget displaying it, if available. Otherwise, the
+/// alternative text of the image is displayed.
+pub fn view_with_images<'a, Message, Renderer>(
+    items: impl IntoIterator<Item = &'a Item>,
+    settings: Settings,
+    on_link: impl Fn(Url) -> Message + Copy + 'a,
+    load_image: impl Fn(&str) -> Option<Element<'a, Message, Theme, Renderer>>
+        + Copy
+        + 'a,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    view_with(
+        items,
+        settings,
+        move |link: Link| on_link(link.into_url()),
+        false,
+        load_image,
+    )
+}
+
+/// Display a bunch of Markdown items, producing a message for every
+/// [`Link`] interaction, loading images with the given function.
+///
+/// Besides clicks, the links notify when they are hovered, exited, or
+/// pressed with the right mouse button.
+pub fn view_with_links<'a, Message, Renderer>(
+    items: impl IntoIterator<Item = &'a Item>,
+    settings: Settings,
+    on_link: impl Fn(Link) -> Message + Copy + 'a,
+    load_image: impl Fn(&str) -> Option<Element<'a, Message, Theme, Renderer>>
+        + Copy
+        + 'a,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    view_with(items, settings, on_link, true, load_image)
+}
+
+fn view_with<'a, Message, Renderer>(
+    items: impl IntoIterator<Item = &'a Item>,
+    settings: Settings,
+    on_link: impl Fn(Link) -> Message + Copy + 'a,
+    is_interactive: bool,
+    load_image: impl Fn(&str) -> Option<Element<'a, Message, Theme, Renderer>>
+        + Copy
+        + 'a,
+) -> Element<'a, Message, Theme, Renderer>
 where
     Message: 'a,
     Renderer: core::text::Renderer<Font = Font> + 'a,
@@ -354,9 +684,27 @@ where
 
     let spacing = text_size * 0.625;
 
+    let view = |items: &'a [Item]| {
+        view_with(items, settings, on_link, is_interactive, load_image)
+    };
+
+    let links = |text: text::Rich<'a, Message, Url, Theme, Renderer>| {
+        let text = text.on_link(move |url| on_link(Link::Clicked(url)));
+
+        if is_interactive {
+            text.on_link_hover(move |url| on_link(Link::Hovered(url)))
+                .on_link_exit(move |url| on_link(Link::Exited(url)))
+                .on_link_right_press(move |url| {
+                    on_link(Link::RightPressed(url))
+                })
+        } else {
+            text
+        }
+    };
+
     let blocks = items.into_iter().enumerate().map(|(i, item)| match item {
         Item::Heading(level, heading) => {
-            container(rich_text(heading).on_link(on_link).size(match level {
+            container(links(rich_text(heading)).size(match level {
                 pulldown_cmark::HeadingLevel::H1 => h1_size,
                 pulldown_cmark::HeadingLevel::H2 => h2_size,
                 pulldown_cmark::HeadingLevel::H3 => h3_size,
@@ -372,37 +720,37 @@ where
             .into()
         }
         Item::Paragraph(paragraph) => {
-            rich_text(paragraph).on_link(on_link).size(text_size).into()
+            links(rich_text(paragraph)).size(text_size).into()
         }
-        Item::List { start: None, items } => {
-            column(items.iter().map(|items| {
-                row![text("•").size(text_size), view(items, settings, on_link)]
-                    .spacing(spacing)
-                    .into()
+        Item::List { start, items } => {
+            column(items.iter().enumerate().map(|(i, bullet)| {
+                let marker: Element<'a, Message, Theme, Renderer> =
+                    match (bullet, start) {
+                        (Bullet::Task { done, .. }, _) => checkbox("", *done)
+                            .size(text_size)
+                            .spacing(0)
+                            .into(),
+                        (Bullet::Point { .. }, None) => {
+                            text("•").size(text_size).into()
+                        }
+                        (Bullet::Point { .. }, Some(start)) => {
+                            text!("{}.", i as u64 + *start)
+                                .size(text_size)
+                                .into()
+                        }
+                    };
+
+                row![marker, view(bullet.items())].spacing(spacing).into()
             }))
             .spacing(spacing)
             .into()
         }
-        Item::List {
-            start: Some(start),
-            items,
-        } => column(items.iter().enumerate().map(|(i, items)| {
-            row![
-                text!("{}.", i as u64 + *start).size(text_size),
-                view(items, settings, on_link)
-            ]
-            .spacing(spacing)
-            .into()
-        }))
-        .spacing(spacing)
-        .into(),
         Item::CodeBlock(code) => container(
             scrollable(
                 container(
-                    rich_text(code)
+                    links(rich_text(code))
                         .font(Font::MONOSPACE)
-                        .size(code_size)
-                        .on_link(on_link),
+                        .size(code_size),
                 )
                 .padding(spacing.0 / 2.0),
             )
@@ -416,6 +764,57 @@ where
         .padding(spacing.0 / 2.0)
         .style(container::rounded_box)
         .into(),
+        Item::Quote(items) => row![vertical_rule(spacing), view(items)]
+            .spacing(spacing)
+            .into(),
+        Item::Rule => horizontal_rule(spacing).into(),
+        Item::Image { url, alt, .. } => load_image(url)
+            .unwrap_or_else(|| links(rich_text(alt)).size(text_size).into()),
+        Item::Table { columns, rows } => {
+            let cell = |content: &'a [text::Span<'static, Url>],
+                        alignment: alignment::Horizontal,
+                        font: Font| {
+                container(links(rich_text(content)).size(text_size).font(font))
+                    .width(Length::Fill)
+                    .align_x(alignment)
+                    .into()
+            };
+
+            let header = row(columns.iter().map(|column| {
+                cell(
+                    &column.header,
+                    column.alignment,
+                    Font {
+                        weight: font::Weight::Bold,
+                        ..Font::default()
+                    },
+                )
+            }))
+            .spacing(spacing);
+
+            let rows = rows.iter().map(|cells| {
+                row(cells.iter().zip(columns).map(|(content, column)| {
+                    cell(content, column.alignment, Font::default())
+                }))
+                .spacing(spacing)
+                .into()
+            });
+
+            container(
+                column![header, horizontal_rule(1)]
+                    .extend(rows)
+                    .spacing(spacing.0 / 2.0),
+            )
+            .width(Length::Fill)
+            .padding(spacing.0 / 2.0)
+            .style(container::bordered_box)
+            .into()
+        }
+        Item::Footnote { label, items } => {
+            row![text!("[{label}]").size(code_size), view(items)]
+                .spacing(spacing)
+                .into()
+        }
     });
 
     Element::new(column(blocks).width(Length::Fill).spacing(text_size))
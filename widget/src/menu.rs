@@ -0,0 +1,1466 @@
+//! Show nested menus of actions, from a menu bar or a right click.
+use crate::core::alignment;
+use crate::core::border::Border;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
+};
+
+/// The padding of the items of a menu.
+const PADDING: Padding = Padding {
+    top: 4.0,
+    right: 10.0,
+    bottom: 4.0,
+    left: 10.0,
+};
+
+/// The height of a separator.
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+/// The space between the label and the shortcut of an item.
+const SHORTCUT_SPACING: f32 = 30.0;
+
+/// An item of a menu.
+///
+/// It can be an action, a submenu, or a separator.
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    label: String,
+    kind: Kind<Message>,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Action {
+        on_press: Option<Message>,
+        shortcut: Option<String>,
+        checked: Option<bool>,
+    },
+    Submenu(Vec<Item<Message>>),
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new action [`Item`] with the given label.
+    ///
+    /// The [`Item`] will be disabled until [`Item::on_press`] is set.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            kind: Kind::Action {
+                on_press: None,
+                shortcut: None,
+                checked: None,
+            },
+        }
+    }
+
+    /// Creates a new [`Item`] that opens a submenu with the given items.
+    pub fn submenu(
+        label: impl Into<String>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            kind: Kind::Submenu(items.into_iter().collect()),
+        }
+    }
+
+    /// Creates a new separator [`Item`].
+    pub fn separator() -> Self {
+        Self {
+            label: String::new(),
+            kind: Kind::Separator,
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is chosen.
+    pub fn on_press(self, message: Message) -> Self {
+        self.on_press_maybe(Some(message))
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is chosen,
+    /// if `Some`.
+    ///
+    /// If `None`, the [`Item`] will be disabled.
+    pub fn on_press_maybe(mut self, message: Option<Message>) -> Self {
+        if let Kind::Action { on_press, .. } = &mut self.kind {
+            *on_press = message;
+        }
+
+        self
+    }
+
+    /// Sets the keyboard shortcut of the [`Item`], displayed next to its
+    /// label.
+    ///
+    /// The shortcut is only shown; you will need to listen to the keyboard
+    /// to trigger it.
+    pub fn shortcut(mut self, text: impl Into<String>) -> Self {
+        if let Kind::Action { shortcut, .. } = &mut self.kind {
+            *shortcut = Some(text.into());
+        }
+
+        self
+    }
+
+    /// Makes the [`Item`] checkable, showing a checkmark when `is_checked`.
+    pub fn checked(mut self, is_checked: bool) -> Self {
+        if let Kind::Action { checked, .. } = &mut self.kind {
+            *checked = Some(is_checked);
+        }
+
+        self
+    }
+
+    fn items(&self) -> &[Item<Message>] {
+        match &self.kind {
+            Kind::Submenu(items) => items,
+            Kind::Action { .. } | Kind::Separator => &[],
+        }
+    }
+
+    fn is_selectable(&self) -> bool {
+        !matches!(self.kind, Kind::Separator)
+    }
+
+    fn is_enabled(&self) -> bool {
+        match &self.kind {
+            Kind::Action { on_press, .. } => on_press.is_some(),
+            Kind::Submenu(items) => !items.is_empty(),
+            Kind::Separator => false,
+        }
+    }
+}
+
+/// A horizontal bar of menus.
+///
+/// Every submenu [`Item`] of the bar opens its menu when clicked. Once
+/// open, the menus can be navigated with the arrow keys, chosen with
+/// `Enter` or `Space` and closed with `Escape`.
+///
+/// Optionally, `F10` can open the first menu; see [`MenuBar::open_on_f10`].
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// # use iced_widget::menu_bar;
+/// use iced_widget::menu::Item;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Open,
+///     Save,
+///     ToggleWrap,
+/// }
+///
+/// fn view<'a>(wrap: bool) -> Element<'a, Message> {
+///     menu_bar([
+///         Item::submenu(
+///             "File",
+///             [
+///                 Item::new("Open").on_press(Message::Open).shortcut("Ctrl+O"),
+///                 Item::new("Save").on_press(Message::Save).shortcut("Ctrl+S"),
+///             ],
+///         ),
+///         Item::submenu(
+///             "View",
+///             [Item::new("Wrap lines")
+///                 .on_press(Message::ToggleWrap)
+///                 .checked(wrap)],
+///         ),
+///     ])
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<'a, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    roots: Vec<Item<Message>>,
+    width: Length,
+    padding: Padding,
+    open_on_f10: bool,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> MenuBar<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`MenuBar`] with the given menus.
+    pub fn new(roots: impl IntoIterator<Item = Item<Message>>) -> Self {
+        Self {
+            roots: roots.into_iter().collect(),
+            width: Length::Fill,
+            padding: PADDING,
+            open_on_f10: false,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets whether pressing `F10` anywhere in the window opens the first
+    /// menu of the [`MenuBar`].
+    ///
+    /// By default, it does not.
+    pub fn open_on_f10(mut self, open_on_f10: bool) -> Self {
+        self.open_on_f10 = open_on_f10;
+        self
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the titles of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`] and its menus.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`MenuBar`] and its menus.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MenuBar<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = renderer.default_size();
+        let height = text::LineHeight::default().to_absolute(size).0
+            + self.padding.vertical();
+
+        let mut x = 0.0;
+
+        let titles = self
+            .roots
+            .iter()
+            .map(|root| {
+                let width = measure(renderer, &root.label, size)
+                    + self.padding.horizontal();
+
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size =
+            limits.resolve(self.width, Length::Shrink, Size::new(x, height));
+
+        layout::Node::with_children(size, titles)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let title = layout
+            .children()
+            .position(|title| cursor.is_over(title.bounds()));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(index) = title {
+                    let root = &self.roots[index];
+
+                    if state.is_open() && state.root == index {
+                        state.close();
+                    } else if let Kind::Action {
+                        on_press: Some(on_press),
+                        ..
+                    } = &root.kind
+                    {
+                        state.close();
+                        shell.publish(on_press.clone());
+                    } else if root.is_enabled() {
+                        state.open(index, Point::ORIGIN, None);
+                    }
+
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                } else if state.is_open() {
+                    state.close();
+                    shell.invalidate_layout();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(index) = title {
+                    if state.is_open()
+                        && state.root != index
+                        && self.roots[index].is_enabled()
+                        && !self.roots[index].items().is_empty()
+                    {
+                        state.open(index, Point::ORIGIN, None);
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::F10),
+                ..
+            }) if self.open_on_f10 => {
+                if let Some(index) =
+                    self.roots.iter().position(|root| !root.items().is_empty())
+                {
+                    state.open(
+                        index,
+                        Point::ORIGIN,
+                        first(self.roots[index].items()),
+                    );
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.children().zip(&self.roots).any(|(title, root)| {
+            root.is_enabled() && cursor.is_over(title.bounds())
+        }) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let size = renderer.default_size();
+
+        if let Some(background) = style.bar_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        for (index, (root, title)) in
+            self.roots.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = title.bounds();
+
+            let is_selected = if state.is_open() {
+                state.root == index
+            } else {
+                root.is_enabled() && cursor.is_over(bounds)
+            };
+
+            let color = if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            radius: style.border.radius,
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.selected_background,
+                );
+
+                style.selected_text_color
+            } else if root.is_enabled() {
+                style.text_color
+            } else {
+                style.text_color.scale_alpha(0.5)
+            };
+
+            fill_text(
+                renderer,
+                root.label.clone(),
+                renderer.default_font(),
+                size,
+                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                alignment::Horizontal::Left,
+                color,
+                *viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open() {
+            return None;
+        }
+
+        let title = layout.children().nth(state.root)?.bounds();
+
+        Some(overlay::Element::new(Box::new(Menus {
+            state,
+            roots: &self.roots,
+            is_bar: true,
+            origin: Point::new(title.x, title.y + title.height) + translation,
+            class: &self.class,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MenuBar<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(menu_bar: MenuBar<'a, Message, Theme>) -> Self {
+        Self::new(menu_bar)
+    }
+}
+
+/// A widget that shows a menu when its content is right clicked.
+///
+/// The menu can also be opened with the context menu key, or `Shift+F10`,
+/// while the mouse cursor is over the content.
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    items: Vec<Item<Message>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`ContextMenu`] for the given content, showing the
+    /// given items.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            items: items.into_iter().collect(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the style of the [`ContextMenu`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ContextMenu`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ContextMenu<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    state.open(0, position, None);
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) if state.is_open() => {
+                state.close();
+                shell.invalidate_layout();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) => {
+                let is_shortcut = match key {
+                    keyboard::Key::Named(key::Named::ContextMenu) => true,
+                    keyboard::Key::Named(key::Named::F10) => modifiers.shift(),
+                    _ => false,
+                };
+
+                if let Some(position) = cursor
+                    .position_over(layout.bounds())
+                    .filter(|_| is_shortcut)
+                {
+                    state.open(0, position, first(&self.items));
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (state, content) = (&mut tree.state, &mut tree.children[0]);
+        let state = state.downcast_mut::<State>();
+
+        if !state.is_open() {
+            return self.content.as_widget_mut().overlay(
+                content,
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        let origin = state.origin + translation;
+
+        Some(overlay::Element::new(Box::new(Menus {
+            state,
+            roots: &self.items,
+            is_bar: false,
+            origin,
+            class: &self.class,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<ContextMenu<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(context_menu: ContextMenu<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(context_menu)
+    }
+}
+
+/// The state of the open menus of a [`MenuBar`] or a [`ContextMenu`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    root: usize,
+    origin: Point,
+    levels: Vec<Option<usize>>,
+}
+
+impl State {
+    fn is_open(&self) -> bool {
+        !self.levels.is_empty()
+    }
+
+    fn open(&mut self, root: usize, origin: Point, selected: Option<usize>) {
+        self.root = root;
+        self.origin = origin;
+        self.levels = vec![selected];
+    }
+
+    fn close(&mut self) {
+        self.levels.clear();
+    }
+}
+
+/// The open menus, one next to the other.
+///
+/// Each level is the submenu of the selected item of the previous level.
+struct Menus<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    state: &'b mut State,
+    roots: &'b [Item<Message>],
+    is_bar: bool,
+    origin: Point,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<'a, 'b, Message, Theme> Menus<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Returns the items of the menu at the given level.
+    fn menu(&self, level: usize) -> &'b [Item<Message>] {
+        let roots: &'b [Item<Message>] = self.roots;
+
+        let mut items = if self.is_bar {
+            roots.get(self.state.root).map(Item::items).unwrap_or(&[])
+        } else {
+            roots
+        };
+
+        for selected in &self.state.levels[..level] {
+            items = selected
+                .and_then(|index| items.get(index))
+                .map(Item::items)
+                .unwrap_or(&[]);
+        }
+
+        items
+    }
+
+    /// Returns the level that receives keyboard input.
+    fn active(&self) -> usize {
+        let last = self.state.levels.len() - 1;
+
+        if last > 0 && self.state.levels[last].is_none() {
+            last - 1
+        } else {
+            last
+        }
+    }
+
+    /// Selects the given item, opening its submenu if it has one.
+    ///
+    /// Returns `true` if the open menus changed.
+    fn select(&mut self, level: usize, index: usize) -> bool {
+        let Some(item) = self.menu(level).get(index) else {
+            return false;
+        };
+
+        if !item.is_selectable() || self.state.levels[level] == Some(index) {
+            return false;
+        }
+
+        self.state.levels.truncate(level + 1);
+        self.state.levels[level] = Some(index);
+
+        if item.is_enabled() && !item.items().is_empty() {
+            self.state.levels.push(None);
+        }
+
+        true
+    }
+
+    /// Chooses the given item, producing its message or opening its submenu.
+    fn choose(
+        &mut self,
+        level: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) where
+        Message: Clone,
+    {
+        let Some(item) = self.menu(level).get(index) else {
+            return;
+        };
+
+        match &item.kind {
+            Kind::Action {
+                on_press: Some(on_press),
+                ..
+            } => {
+                shell.publish(on_press.clone());
+                self.state.close();
+            }
+            Kind::Submenu(items) if !items.is_empty() => {
+                self.state.levels.truncate(level + 1);
+                self.state.levels[level] = Some(index);
+                self.state.levels.push(first(items));
+            }
+            _ => return,
+        }
+
+        shell.invalidate_layout();
+    }
+
+    /// Opens the next or the previous menu of the bar.
+    fn cycle(&mut self, forward: bool) {
+        if !self.is_bar {
+            return;
+        }
+
+        let count = self.roots.len();
+
+        let next = (1..count)
+            .map(|offset| {
+                if forward {
+                    (self.state.root + offset) % count
+                } else {
+                    (self.state.root + count - offset) % count
+                }
+            })
+            .find(|index| {
+                let root = &self.roots[*index];
+
+                root.is_enabled() && !root.items().is_empty()
+            });
+
+        if let Some(index) = next {
+            self.state.open(
+                index,
+                self.state.origin,
+                first(self.roots[index].items()),
+            );
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer> for Menus<'a, 'b, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let size = renderer.default_size();
+        let row_height = text::LineHeight::default().to_absolute(size).0
+            + PADDING.vertical();
+
+        let mut parent: Option<Rectangle> = None;
+        let mut menus = Vec::with_capacity(self.state.levels.len());
+
+        for level in 0..self.state.levels.len() {
+            let items = self.menu(level);
+
+            if items.is_empty() {
+                break;
+            }
+
+            let (check, arrow) = columns(items, size);
+
+            let label = items
+                .iter()
+                .map(|item| measure(renderer, &item.label, size))
+                .fold(0.0, f32::max);
+
+            let shortcut = items
+                .iter()
+                .filter_map(|item| match &item.kind {
+                    Kind::Action {
+                        shortcut: Some(shortcut),
+                        ..
+                    } => Some(measure(renderer, shortcut, size)),
+                    _ => None,
+                })
+                .fold(0.0, f32::max);
+
+            let width = PADDING.horizontal()
+                + check
+                + label
+                + if shortcut > 0.0 {
+                    SHORTCUT_SPACING + shortcut
+                } else {
+                    0.0
+                }
+                + arrow;
+
+            let mut height = 0.0;
+
+            let nodes: Vec<_> = items
+                .iter()
+                .map(|item| {
+                    let item_height = if item.is_selectable() {
+                        row_height
+                    } else {
+                        SEPARATOR_HEIGHT
+                    };
+
+                    let node = layout::Node::new(Size::new(width, item_height))
+                        .move_to(Point::new(0.0, height));
+
+                    height += item_height;
+
+                    node
+                })
+                .collect();
+
+            let mut position = match parent {
+                Some(parent) => Point::new(parent.x + parent.width, parent.y),
+                None => self.origin,
+            };
+
+            if position.x + width > bounds.width {
+                position.x = match parent {
+                    Some(parent) => parent.x - width,
+                    None => bounds.width - width,
+                }
+                .max(0.0);
+            }
+
+            if position.y + height > bounds.height {
+                position.y = (bounds.height - height).max(0.0);
+            }
+
+            parent = self.state.levels[level].and_then(|index| {
+                nodes.get(index).map(|node| {
+                    Rectangle::new(position, node.size())
+                        + Vector::new(0.0, node.bounds().y)
+                })
+            });
+
+            menus.push(
+                layout::Node::with_children(Size::new(width, height), nodes)
+                    .move_to(position),
+            );
+        }
+
+        layout::Node::with_children(bounds, menus)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let menus: Vec<_> = layout.children().collect();
+
+        let hovered =
+            menus.iter().enumerate().rev().find_map(|(level, menu)| {
+                let position = cursor.position_over(menu.bounds())?;
+
+                Some((
+                    level,
+                    menu.children()
+                        .position(|item| item.bounds().contains(position)),
+                ))
+            });
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((level, Some(index))) = hovered {
+                    if self.select(level, index) {
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                if hovered.is_some() =>
+            {
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some((level, index)) = hovered {
+                    if let Some(index) = index {
+                        self.choose(level, index, shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) => {
+                let level = self.active();
+                let items = self.menu(level);
+                let selected = self.state.levels[level];
+
+                match named {
+                    key::Named::ArrowDown | key::Named::ArrowUp => {
+                        let forward = named == key::Named::ArrowDown;
+
+                        self.state.levels.truncate(level + 1);
+                        self.state.levels[level] =
+                            step(items, selected, forward);
+                    }
+                    key::Named::ArrowRight => {
+                        match selected.and_then(|index| items.get(index)) {
+                            Some(item)
+                                if item.is_enabled()
+                                    && !item.items().is_empty() =>
+                            {
+                                self.state.levels.truncate(level + 1);
+                                self.state.levels.push(first(item.items()));
+                            }
+                            _ => self.cycle(true),
+                        }
+                    }
+                    key::Named::ArrowLeft => {
+                        if level > 0 {
+                            self.state.levels.truncate(level);
+                        } else {
+                            self.cycle(false);
+                        }
+                    }
+                    key::Named::Enter | key::Named::Space => {
+                        if let Some(index) = selected {
+                            self.choose(level, index, shell);
+                        }
+                    }
+                    key::Named::Escape => {
+                        if level > 0 {
+                            self.state.levels.truncate(level);
+                        } else {
+                            self.state.close();
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let size = renderer.default_size();
+        let viewport = layout.bounds();
+
+        for (level, menu) in layout.children().enumerate() {
+            let items = self.menu(level);
+            let (check, arrow) = columns(items, size);
+            let bounds = menu.bounds();
+
+            renderer.with_layer(viewport, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        shadow: style.shadow,
+                    },
+                    style.background,
+                );
+
+                for (index, (item, layout)) in
+                    items.iter().zip(menu.children()).enumerate()
+                {
+                    let bounds = layout.bounds();
+
+                    if !item.is_selectable() {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x + PADDING.left / 2.0,
+                                    y: bounds.center_y().floor(),
+                                    width: bounds.width
+                                        - PADDING.horizontal() / 2.0,
+                                    height: 1.0,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            style.separator,
+                        );
+
+                        continue;
+                    }
+
+                    let is_selected = self.state.levels[level] == Some(index);
+
+                    let (text_color, shortcut_color) = if is_selected {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds,
+                                ..renderer::Quad::default()
+                            },
+                            style.selected_background,
+                        );
+
+                        (style.selected_text_color, style.selected_text_color)
+                    } else {
+                        (style.text_color, style.shortcut_color)
+                    };
+
+                    let (text_color, shortcut_color) = if item.is_enabled() {
+                        (text_color, shortcut_color)
+                    } else {
+                        (
+                            text_color.scale_alpha(0.5),
+                            shortcut_color.scale_alpha(0.5),
+                        )
+                    };
+
+                    let left = bounds.x + PADDING.left;
+                    let right = bounds.x + bounds.width - PADDING.right;
+
+                    match &item.kind {
+                        Kind::Action {
+                            shortcut, checked, ..
+                        } => {
+                            if *checked == Some(true) {
+                                fill_text(
+                                    renderer,
+                                    Renderer::CHECKMARK_ICON.to_string(),
+                                    Renderer::ICON_FONT,
+                                    size,
+                                    Point::new(left, bounds.center_y()),
+                                    alignment::Horizontal::Left,
+                                    text_color,
+                                    viewport,
+                                );
+                            }
+
+                            if let Some(shortcut) = shortcut {
+                                fill_text(
+                                    renderer,
+                                    shortcut.clone(),
+                                    renderer.default_font(),
+                                    size,
+                                    Point::new(
+                                        right - arrow,
+                                        bounds.center_y(),
+                                    ),
+                                    alignment::Horizontal::Right,
+                                    shortcut_color,
+                                    viewport,
+                                );
+                            }
+                        }
+                        Kind::Submenu(_) => {
+                            fill_text(
+                                renderer,
+                                String::from("›"),
+                                renderer.default_font(),
+                                size,
+                                Point::new(right, bounds.center_y()),
+                                alignment::Horizontal::Right,
+                                text_color,
+                                viewport,
+                            );
+                        }
+                        Kind::Separator => {}
+                    }
+
+                    fill_text(
+                        renderer,
+                        item.label.clone(),
+                        renderer.default_font(),
+                        size,
+                        Point::new(left + check, bounds.center_y()),
+                        alignment::Horizontal::Left,
+                        text_color,
+                        viewport,
+                    );
+                }
+            });
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout
+            .children()
+            .any(|menu| menu.bounds().contains(cursor_position))
+    }
+}
+
+/// Returns the widths of the checkmark and the submenu arrow columns of the
+/// given items.
+fn columns<Message>(items: &[Item<Message>], size: Pixels) -> (f32, f32) {
+    let check = items.iter().any(|item| {
+        matches!(
+            item.kind,
+            Kind::Action {
+                checked: Some(_),
+                ..
+            }
+        )
+    });
+
+    let arrow = items
+        .iter()
+        .any(|item| matches!(item.kind, Kind::Submenu(_)));
+
+    (
+        if check { size.0 * 1.5 } else { 0.0 },
+        if arrow { size.0 * 1.5 } else { 0.0 },
+    )
+}
+
+/// Returns the first selectable item of the given items.
+fn first<Message>(items: &[Item<Message>]) -> Option<usize> {
+    items.iter().position(Item::is_selectable)
+}
+
+/// Returns the next selectable item after `selected`, wrapping around.
+fn step<Message>(
+    items: &[Item<Message>],
+    selected: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let count = items.len();
+
+    if count == 0 {
+        return None;
+    }
+
+    let start = selected.unwrap_or(if forward { count - 1 } else { 0 });
+
+    (1..=count)
+        .map(|offset| {
+            if forward {
+                (start + offset) % count
+            } else {
+                (start + count - offset % count) % count
+            }
+        })
+        .find(|index| items[*index].is_selectable())
+}
+
+fn measure<Renderer>(renderer: &Renderer, content: &str, size: Pixels) -> f32
+where
+    Renderer: text::Renderer,
+{
+    Renderer::Paragraph::with_text(Text {
+        content,
+        bounds: Size::INFINITY,
+        size,
+        line_height: text::LineHeight::default(),
+        font: renderer.default_font(),
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        shaping: text::Shaping::Advanced,
+        wrapping: text::Wrapping::default(),
+        ellipsis: text::Ellipsis::default(),
+        max_lines: None,
+    })
+    .min_width()
+}
+
+fn fill_text<Renderer>(
+    renderer: &mut Renderer,
+    content: String,
+    font: Renderer::Font,
+    size: Pixels,
+    position: Point,
+    horizontal_alignment: alignment::Horizontal,
+    color: Color,
+    clip_bounds: Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    let line_height = text::LineHeight::default();
+
+    renderer.fill_text(
+        Text {
+            content,
+            bounds: Size::new(f32::INFINITY, line_height.to_absolute(size).0),
+            size,
+            line_height,
+            font,
+            horizontal_alignment,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::default(),
+            ellipsis: text::Ellipsis::default(),
+            max_lines: None,
+        },
+        position,
+        color,
+        clip_bounds,
+    );
+}
+
+/// The appearance of a [`MenuBar`] and its menus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the bar of a [`MenuBar`], if any.
+    pub bar_background: Option<Background>,
+    /// The [`Background`] of a menu.
+    pub background: Background,
+    /// The [`Border`] of a menu.
+    pub border: Border,
+    /// The [`Shadow`] of a menu.
+    pub shadow: Shadow,
+    /// The text [`Color`] of the items.
+    pub text_color: Color,
+    /// The text [`Color`] of the keyboard shortcuts.
+    pub shortcut_color: Color,
+    /// The [`Background`] of the selected item.
+    pub selected_background: Background,
+    /// The text [`Color`] of the selected item.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the separators.
+    pub separator: Color,
+}
+
+/// The theme catalog of a [`MenuBar`] and a [`ContextMenu`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`MenuBar`] and a [`ContextMenu`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`MenuBar`] and a [`ContextMenu`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        bar_background: Some(palette.background.weak.color.into()),
+        background: palette.background.base.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 2.0.into(),
+            color: palette.background.strong.color,
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        text_color: palette.background.base.text,
+        shortcut_color: palette.background.base.text.scale_alpha(0.6),
+        selected_background: palette.primary.strong.color.into(),
+        selected_text_color: palette.primary.strong.text,
+        separator: palette.background.strong.color,
+    }
+}
@@ -0,0 +1,766 @@
+//! Edit numbers with a text input, steppable with arrows and the mouse wheel.
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Theme, Widget,
+};
+use crate::text_input::{self, TextInput, Value};
+
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// The amount of pixels of a scroll delta that make up a step.
+const PIXELS_PER_LINE: f32 = 60.0;
+
+/// A text input for numbers.
+///
+/// Only valid numbers within the bounds of the [`NumberInput`] produce
+/// messages; any other text is kept as typed and shown as invalid, until it
+/// is fixed or the input loses focus.
+///
+/// The value can also be stepped with the buttons on the right, the arrow
+/// keys or the mouse wheel, while holding `Shift` to use the
+/// [`NumberInput::shift_step`].
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// # use iced_widget::number_input;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QuantityChanged(u32),
+/// }
+///
+/// fn view<'a>(quantity: u32) -> Element<'a, Message> {
+///     number_input(quantity, Message::QuantityChanged)
+///         .min(1)
+///         .max(99)
+///         .shift_step(10)
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog + text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    text_input: TextInput<'a, Edit, Theme, Renderer>,
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    shift_step: Option<T>,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    size: Option<Pixels>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+/// An edit of the inner [`TextInput`] of a [`NumberInput`].
+#[derive(Debug, Clone)]
+enum Edit {
+    Input(String),
+    Submit,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: num_traits::Num
+        + num_traits::Bounded
+        + Copy
+        + PartialOrd
+        + Display
+        + FromStr,
+    Theme: Catalog + text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`NumberInput`] with the given value and a function
+    /// producing a message when a new valid value is entered.
+    pub fn new(value: T, on_change: impl Fn(T) -> Message + 'a) -> Self {
+        Self {
+            text_input: TextInput::new("", &value.to_string())
+                .on_input(Edit::Input)
+                .on_submit(Edit::Submit),
+            value,
+            min: None,
+            max: None,
+            step: T::one(),
+            shift_step: None,
+            on_change: Box::new(on_change),
+            size: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets both the minimum and maximum values of the [`NumberInput`].
+    pub fn bounds(self, bounds: RangeInclusive<T>) -> Self {
+        let (min, max) = bounds.into_inner();
+
+        self.min(min).max(max)
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the optional "shift" step for the [`NumberInput`].
+    ///
+    /// If set, this value is used as the step while the shift key is pressed.
+    pub fn shift_step(mut self, shift_step: T) -> Self {
+        self.shift_step = Some(shift_step);
+        self
+    }
+
+    /// Sets the [`text_input::Id`] of the [`NumberInput`].
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`NumberInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.text_input = self.text_input.size(size);
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`NumberInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the style of the step buttons and the invalid state of the
+    /// [`NumberInput`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the input of the [`NumberInput`].
+    #[must_use]
+    pub fn input_style(
+        mut self,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Style + 'a,
+    ) -> Self
+    where
+        <Theme as text_input::Catalog>::Class<'a>:
+            From<text_input::StyleFn<'a, Theme>>,
+    {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// Sets the style class of the step buttons and the invalid state of the
+    /// [`NumberInput`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the input of the [`NumberInput`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn input_class(
+        mut self,
+        class: impl Into<<Theme as text_input::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.text_input = self.text_input.class(class);
+        self
+    }
+
+    /// Parses the given text, returning the value only if it is within
+    /// bounds.
+    fn parse(&self, text: &str) -> Option<T> {
+        text.trim().parse().ok().filter(|value| {
+            self.min.unwrap_or_else(T::min_value) <= *value
+                && *value <= self.max.unwrap_or_else(T::max_value)
+        })
+    }
+
+    /// Returns the given value moved one step up or down, clamped to the
+    /// bounds.
+    fn stepped(
+        &self,
+        value: T,
+        is_up: bool,
+        modifiers: keyboard::Modifiers,
+    ) -> T {
+        let step = if modifiers.shift() {
+            self.shift_step.unwrap_or(self.step)
+        } else {
+            self.step
+        };
+
+        let min = self.min.unwrap_or_else(T::min_value);
+        let max = self.max.unwrap_or_else(T::max_value);
+        let zero = T::zero();
+
+        // Only subtract values of the same sign, so neither signed nor
+        // unsigned numbers can overflow
+        if is_up {
+            if value >= max {
+                max
+            } else if value < zero {
+                let next = value + step;
+
+                if next > max {
+                    max
+                } else {
+                    next
+                }
+            } else if step > max - value {
+                max
+            } else {
+                value + step
+            }
+        } else if value <= min {
+            min
+        } else if min < zero {
+            if value < min + step {
+                min
+            } else {
+                value - step
+            }
+        } else if step > value - min {
+            min
+        } else {
+            value - step
+        }
+    }
+
+    fn button_width(&self, renderer: &Renderer) -> f32 {
+        (self.size.unwrap_or_else(|| renderer.default_size()).0 * 1.25).round()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State<T> {
+    text: Value,
+    value: Option<T>,
+    modifiers: keyboard::Modifiers,
+    scrolled: f32,
+}
+
+impl<T> State<T>
+where
+    T: Copy + PartialEq + Display,
+{
+    /// Replaces the text with the given value if it changed from the outside.
+    fn sync(&mut self, value: T) {
+        if self.value != Some(value) {
+            self.text = Value::new(&value.to_string());
+            self.value = Some(value);
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: num_traits::Num
+        + num_traits::Bounded
+        + Copy
+        + PartialOrd
+        + Display
+        + FromStr
+        + 'static,
+    Theme: Catalog + text_input::Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<T> {
+            text: Value::new(&self.value.to_string()),
+            value: Some(self.value),
+            modifiers: keyboard::Modifiers::default(),
+            scrolled: 0.0,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children[0].diff(&self.text_input as &dyn Widget<_, _, _>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<Edit, Theme, Renderer>::size(&self.text_input)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<T>>();
+        state.sync(self.value);
+
+        let button_width = self.button_width(renderer);
+
+        let input = self.text_input.layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(Size::new(button_width, 0.0)),
+            Some(&state.text),
+        );
+
+        let size = input.size();
+
+        let buttons = layout::Node::new(Size::new(button_width, size.height))
+            .move_to(Point::new(size.width, 0.0));
+
+        layout::Node::with_children(
+            Size::new(size.width + button_width, size.height),
+            vec![input, buttons],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        Widget::<Edit, Theme, Renderer>::operate(
+            &self.text_input,
+            &mut tree.children[0],
+            layout.children().next().expect("Number input layout"),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let (state, input_tree) = (&mut tree.state, &mut tree.children[0]);
+        let state = state.downcast_mut::<State<T>>();
+        state.sync(self.value);
+
+        let mut children = layout.children();
+        let input = children.next().expect("Number input layout");
+        let buttons = children.next().expect("Number input layout").bounds();
+
+        let is_focused = |tree: &Tree| {
+            tree.state
+                .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+                .is_focused()
+        };
+
+        let step = match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                cursor
+                    .position_over(buttons)
+                    .map(|position| (position.y < buttons.center_y(), 1))
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if is_focused(input_tree)
+                    && cursor.is_over(layout.bounds()) =>
+            {
+                // Pixel deltas from touchpads are accumulated until they
+                // add up to a whole line
+                state.scrolled += match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => *y,
+                    mouse::ScrollDelta::Pixels { y, .. } => {
+                        *y / PIXELS_PER_LINE
+                    }
+                };
+
+                let lines = state.scrolled.trunc();
+                state.scrolled -= lines;
+
+                if lines == 0.0 {
+                    return event::Status::Captured;
+                }
+
+                Some((lines > 0.0, lines.abs() as usize))
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key:
+                    keyboard::Key::Named(
+                        named @ (key::Named::ArrowUp | key::Named::ArrowDown),
+                    ),
+                ..
+            }) if is_focused(input_tree) => {
+                Some((*named == key::Named::ArrowUp, 1))
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+
+                None
+            }
+            _ => None,
+        };
+
+        if let Some((is_up, count)) = step {
+            let value = (0..count).fold(self.value, |value, _| {
+                self.stepped(value, is_up, state.modifiers)
+            });
+
+            if value != self.value {
+                shell.publish((self.on_change)(value));
+            }
+
+            state.text = Value::new(&value.to_string());
+            state.value = Some(value);
+
+            input_tree
+                .state
+                .downcast_mut::<text_input::State<Renderer::Paragraph>>()
+                .move_cursor_to_end();
+
+            shell.invalidate_layout();
+
+            return event::Status::Captured;
+        }
+
+        let was_focused = is_focused(input_tree);
+
+        self.text_input.set_value(state.text.clone());
+
+        let mut edits = Vec::new();
+        let mut local_shell = Shell::new(&mut edits);
+
+        let status = self.text_input.on_event(
+            input_tree,
+            event,
+            input,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        let is_focused = is_focused(input_tree);
+
+        for edit in edits {
+            match edit {
+                Edit::Input(text) => {
+                    if let Some(value) = self.parse(&text) {
+                        state.value = Some(value);
+
+                        if value != self.value {
+                            shell.publish((self.on_change)(value));
+                        }
+                    }
+
+                    state.text = Value::new(&text);
+                }
+                Edit::Submit => {
+                    if self.parse(&state.text.to_string()).is_none() {
+                        state.text = Value::new(&self.value.to_string());
+                    }
+                }
+            }
+
+            shell.invalidate_layout();
+        }
+
+        // Discard invalid text once the input loses focus
+        if was_focused
+            && !is_focused
+            && self.parse(&state.text.to_string()).is_none()
+        {
+            state.text = Value::new(&self.value.to_string());
+            state.value = Some(self.value);
+
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let input = children.next().expect("Number input layout");
+        let buttons = children.next().expect("Number input layout");
+
+        if cursor.is_over(buttons.bounds()) {
+            return mouse::Interaction::Pointer;
+        }
+
+        Widget::<Edit, Theme, Renderer>::mouse_interaction(
+            &self.text_input,
+            &tree.children[0],
+            input,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<T>>();
+        let style = <Theme as Catalog>::style(theme, &self.class);
+
+        let mut children = layout.children();
+        let input = children.next().expect("Number input layout");
+        let buttons = children.next().expect("Number input layout").bounds();
+
+        self.text_input.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            input,
+            cursor,
+            Some(&state.text),
+            viewport,
+        );
+
+        if self.parse(&state.text.to_string()).is_none() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: input.bounds(),
+                    border: style.invalid_border,
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: buttons,
+                border: style.button_border,
+                ..renderer::Quad::default()
+            },
+            style.button_background,
+        );
+
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        for (label, is_up) in [("▲", true), ("▼", false)] {
+            let bounds = Rectangle {
+                y: if is_up { buttons.y } else { buttons.center_y() },
+                height: buttons.height / 2.0,
+                ..buttons
+            };
+
+            if cursor.is_over(bounds) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: border::rounded(style.button_border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.button_hovered_background,
+                );
+            }
+
+            let is_enabled = if is_up {
+                self.value < self.max.unwrap_or_else(T::max_value)
+            } else {
+                self.value > self.min.unwrap_or_else(T::min_value)
+            };
+
+            renderer.fill_text(
+                Text {
+                    content: label.to_owned(),
+                    bounds: bounds.size(),
+                    size: Pixels(size.0 * 0.5),
+                    line_height: text::LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::default(),
+                    ellipsis: text::Ellipsis::default(),
+                    max_lines: None,
+                },
+                bounds.center(),
+                if is_enabled {
+                    style.icon_color
+                } else {
+                    style.icon_color.scale_alpha(0.4)
+                },
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<NumberInput<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: num_traits::Num
+        + num_traits::Bounded
+        + Copy
+        + PartialOrd
+        + Display
+        + FromStr
+        + 'static,
+    Message: 'a,
+    Theme: Catalog + text_input::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Theme, Renderer>,
+    ) -> Self {
+        Self::new(number_input)
+    }
+}
+
+/// The appearance of the step buttons and the invalid state of a
+/// [`NumberInput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the step buttons.
+    pub button_background: Background,
+    /// The [`Background`] of a hovered step button.
+    pub button_hovered_background: Background,
+    /// The [`Border`] of the step buttons.
+    pub button_border: Border,
+    /// The [`Color`] of the arrows of the step buttons.
+    pub icon_color: Color,
+    /// The [`Border`] drawn around the input while its text is not valid.
+    pub invalid_border: Border,
+}
+
+/// The theme catalog of a [`NumberInput`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`NumberInput`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`NumberInput`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        button_background: palette.background.weak.color.into(),
+        button_hovered_background: palette.background.strong.color.into(),
+        button_border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        icon_color: palette.background.weak.text,
+        invalid_border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.danger.base.color,
+        },
+    }
+}
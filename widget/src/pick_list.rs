@@ -1,7 +1,9 @@
 //! Display a dropdown list of selectable values.
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
@@ -9,6 +11,7 @@ use crate::core::renderer;
 use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
@@ -250,6 +253,9 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
             shaping: self.text_shaping,
+            wrapping: text::Wrapping::default(),
+            ellipsis: text::Ellipsis::default(),
+            max_lines: None,
         };
 
         for (option, paragraph) in options.iter().zip(state.options.iter_mut())
@@ -302,6 +308,37 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable_with_bounds(state, None, layout.bounds());
+
+        let node = || {
+            let mut node =
+                accessibility::Node::new(accessibility::Role::ComboBox)
+                    .expanded(state.is_open)
+                    .action(accessibility::Action::Click);
+
+            if let Some(placeholder) = &self.placeholder {
+                node = node.label(placeholder.clone());
+            }
+
+            if let Some(selected) = &self.selected {
+                node = node.value(selected.borrow().to_string());
+            }
+
+            node
+        };
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -319,6 +356,8 @@ where
                 let state =
                     tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
+                state.is_focused = false;
+
                 if state.is_open {
                     // Event wasn't processed by overlay, so cursor was clicked either outside its
                     // bounds or on the drop-down, either way we close the overlay.
@@ -403,6 +442,81 @@ where
 
                 event::Status::Ignored
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                if !state.is_focused {
+                    return event::Status::Ignored;
+                }
+
+                let options = self.options.borrow();
+
+                match (state.is_open, named) {
+                    (
+                        false,
+                        key::Named::Space
+                        | key::Named::Enter
+                        | key::Named::ArrowDown,
+                    ) => {
+                        let selected =
+                            self.selected.as_ref().map(Borrow::borrow);
+
+                        state.is_open = true;
+                        state.hovered_option = options
+                            .iter()
+                            .position(|option| Some(option) == selected);
+
+                        if let Some(on_open) = &self.on_open {
+                            shell.publish(on_open.clone());
+                        }
+
+                        event::Status::Captured
+                    }
+                    (true, key::Named::ArrowDown) => {
+                        state.hovered_option =
+                            Some(state.hovered_option.map_or(0, |index| {
+                                (index + 1).min(options.len().saturating_sub(1))
+                            }));
+
+                        event::Status::Captured
+                    }
+                    (true, key::Named::ArrowUp) => {
+                        state.hovered_option = Some(
+                            state
+                                .hovered_option
+                                .map_or(0, |index| index.saturating_sub(1)),
+                        );
+
+                        event::Status::Captured
+                    }
+                    (true, key::Named::Space | key::Named::Enter) => {
+                        if let Some(option) = state
+                            .hovered_option
+                            .and_then(|index| options.get(index))
+                        {
+                            shell.publish((self.on_select)(option.clone()));
+                        }
+
+                        state.is_open = false;
+
+                        event::Status::Captured
+                    }
+                    (true, key::Named::Escape) => {
+                        state.is_open = false;
+
+                        if let Some(on_close) = &self.on_close {
+                            shell.publish(on_close.clone());
+                        }
+
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -445,6 +559,8 @@ where
 
         let status = if state.is_open {
             Status::Opened
+        } else if state.is_focused {
+            Status::Focused
         } else if is_mouse_over {
             Status::Hovered
         } else {
@@ -515,6 +631,9 @@ where
                     horizontal_alignment: alignment::Horizontal::Right,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping,
+                    wrapping: text::Wrapping::default(),
+                    ellipsis: text::Ellipsis::default(),
+                    max_lines: None,
                 },
                 Point::new(
                     bounds.x + bounds.width - self.padding.right,
@@ -544,6 +663,9 @@ where
                     horizontal_alignment: alignment::Horizontal::Left,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
+                    wrapping: text::Wrapping::default(),
+                    ellipsis: text::Ellipsis::default(),
+                    max_lines: None,
                 },
                 Point::new(bounds.x + self.padding.left, bounds.center_y()),
                 if is_selected {
@@ -622,6 +744,7 @@ struct State<P: text::Paragraph> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    is_focused: bool,
     hovered_option: Option<usize>,
     options: Vec<paragraph::Plain<P>>,
     placeholder: paragraph::Plain<P>,
@@ -634,6 +757,7 @@ impl<P: text::Paragraph> State<P> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            is_focused: bool::default(),
             hovered_option: Option::default(),
             options: Vec::new(),
             placeholder: paragraph::Plain::default(),
@@ -647,6 +771,20 @@ impl<P: text::Paragraph> Default for State<P> {
     }
 }
 
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 /// The handle to the right side of the [`PickList`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Handle<Font> {
@@ -700,6 +838,8 @@ pub enum Status {
     Hovered,
     /// The [`PickList`] is open.
     Opened,
+    /// The [`PickList`] has keyboard focus.
+    Focused,
 }
 
 /// The appearance of a pick list.
@@ -780,5 +920,13 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             },
             ..active
         },
+        Status::Focused => Style {
+            border: Border {
+                color: palette.primary.strong.color,
+                width: 2.0,
+                ..active.border
+            },
+            ..active
+        },
     }
 }
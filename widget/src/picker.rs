@@ -0,0 +1,225 @@
+//! Anchor the panel of a picker to its trigger.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::{
+    Clipboard, Color, Layout, Pixels, Point, Rectangle, Shell, Size,
+};
+
+/// The gap between a trigger and the panel of its picker.
+const GAP: f32 = 4.0;
+
+/// The contents of the overlay of a picker.
+pub(crate) trait Panel<Message, Theme, Renderer> {
+    /// Returns the [`Size`] of the panel.
+    fn size(&self, renderer: &Renderer) -> Size;
+
+    /// Processes an [`Event`] over the panel with the given bounds.
+    fn on_event(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> Action;
+
+    /// Returns the [`mouse::Interaction`] of the panel.
+    fn mouse_interaction(
+        &self,
+        _bounds: Rectangle,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+
+    /// Draws the panel in the given bounds.
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    );
+}
+
+/// The outcome of an [`Event`] processed by a [`Panel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Action {
+    /// The event was ignored.
+    Ignored,
+    /// The event was captured.
+    Captured,
+    /// The event was captured and the panel should be closed.
+    Close,
+}
+
+/// An overlay showing a [`Panel`] right below its trigger.
+///
+/// The panel is closed when `Escape` is pressed or a mouse button is pressed
+/// outside of both the panel and the trigger.
+pub(crate) struct Popup<'b, P> {
+    is_open: &'b mut bool,
+    panel: P,
+    anchor: Rectangle,
+}
+
+impl<'b, P> Popup<'b, P> {
+    /// Creates a new [`Popup`] for the given [`Panel`], anchored to the
+    /// bounds of its trigger.
+    pub fn new(is_open: &'b mut bool, panel: P, anchor: Rectangle) -> Self {
+        Self {
+            is_open,
+            panel,
+            anchor,
+        }
+    }
+}
+
+impl<'b, P, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Popup<'b, P>
+where
+    P: Panel<Message, Theme, Renderer>,
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let size = self.panel.size(renderer);
+
+        let below = self.anchor.y + self.anchor.height + GAP;
+        let above = self.anchor.y - GAP - size.height;
+
+        let y = if below + size.height > bounds.height && above >= 0.0 {
+            above
+        } else {
+            below
+        };
+
+        let x = self.anchor.x.min(bounds.width - size.width).max(0.0);
+
+        layout::Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match self
+            .panel
+            .on_event(&event, bounds, cursor, renderer, clipboard, shell)
+        {
+            Action::Captured => return event::Status::Captured,
+            Action::Close => {
+                *self.is_open = false;
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+            Action::Ignored => {}
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                if !cursor.is_over(bounds) && !cursor.is_over(self.anchor) =>
+            {
+                *self.is_open = false;
+                shell.invalidate_layout();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                *self.is_open = false;
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        if cursor.is_over(bounds) {
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.panel
+            .mouse_interaction(layout.bounds(), cursor, renderer)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.panel.draw(renderer, theme, layout.bounds(), cursor);
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+}
+
+/// Draws the given content, vertically centered at the given position.
+pub(crate) fn fill_text<Renderer>(
+    renderer: &mut Renderer,
+    content: impl Into<String>,
+    size: Pixels,
+    position: Point,
+    horizontal_alignment: alignment::Horizontal,
+    color: Color,
+    clip_bounds: Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    let line_height = text::LineHeight::default();
+
+    renderer.fill_text(
+        Text {
+            content: content.into(),
+            bounds: Size::new(f32::INFINITY, line_height.to_absolute(size).0),
+            size,
+            line_height,
+            font: renderer.default_font(),
+            horizontal_alignment,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::default(),
+            ellipsis: text::Ellipsis::default(),
+            max_lines: None,
+        },
+        position,
+        color,
+        clip_bounds,
+    );
+}
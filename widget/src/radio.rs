@@ -2,12 +2,15 @@
 use crate::core::alignment;
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Pixels, Rectangle,
@@ -82,6 +85,7 @@ where
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
+    text_wrapping: text::Wrapping,
     font: Option<Renderer::Font>,
     class: Theme::Class<'a>,
 }
@@ -126,6 +130,7 @@ where
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::Basic,
+            text_wrapping: text::Wrapping::default(),
             font: None,
             class: Theme::default(),
         }
@@ -170,6 +175,12 @@ where
         self
     }
 
+    /// Sets the [`text::Wrapping`] strategy of the [`Radio`] button.
+    pub fn text_wrapping(mut self, wrapping: text::Wrapping) -> Self {
+        self.text_wrapping = wrapping;
+        self
+    }
+
     /// Sets the text font of the [`Radio`] button.
     pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
         self.font = Some(font.into());
@@ -203,11 +214,11 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -228,12 +239,11 @@ where
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
-                widget::text::layout(
-                    state,
+                widget::text::layout_with(
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -245,14 +255,30 @@ where
                     alignment::Horizontal::Left,
                     alignment::Vertical::Top,
                     self.text_shaping,
+                    widget::text::Settings {
+                        wrapping: self.text_wrapping,
+                        ..widget::text::Settings::default()
+                    },
                 )
             },
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable_with_bounds(state, None, layout.bounds());
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -261,15 +287,27 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = false;
+
                 if cursor.is_over(layout.bounds()) {
                     shell.publish(self.on_click.clone());
 
                     return event::Status::Captured;
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Space | key::Named::Enter),
+                ..
+            }) if state.is_focused => {
+                shell.publish(self.on_click.clone());
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -301,12 +339,16 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
         let is_mouse_over = cursor.is_over(layout.bounds());
         let is_selected = self.is_selected;
 
         let mut children = layout.children();
 
-        let status = if is_mouse_over {
+        let status = if state.is_focused {
+            Status::Focused { is_selected }
+        } else if is_mouse_over {
             Status::Hovered { is_selected }
         } else {
             Status::Active { is_selected }
@@ -353,16 +395,15 @@ where
 
         {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> =
-                tree.state.downcast_ref();
 
             crate::text::draw(
                 renderer,
                 defaults,
                 label_layout,
-                state.0.raw(),
+                state.label.0.raw(),
                 crate::text::Style {
                     color: style.text_color,
+                    ..crate::text::Style::default()
                 },
                 viewport,
             );
@@ -397,6 +438,11 @@ pub enum Status {
         /// Indicates whether the [`Radio`] button is currently selected.
         is_selected: bool,
     },
+    /// The [`Radio`] button has keyboard focus.
+    Focused {
+        /// Indicates whether the [`Radio`] button is currently selected.
+        is_selected: bool,
+    },
 }
 
 /// The appearance of a radio button.
@@ -460,5 +506,29 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             background: palette.primary.weak.color.into(),
             ..active
         },
+        Status::Focused { .. } => Style {
+            border_width: 2.0,
+            ..active
+        },
+    }
+}
+
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    is_focused: bool,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
     }
 }
@@ -7,7 +7,7 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget::{Operation, Tree};
 use crate::core::{
-    Clipboard, Element, Length, Padding, Pixels, Rectangle, Shell, Size,
+    Clipboard, Element, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
     Vector, Widget,
 };
 
@@ -142,6 +142,17 @@ where
     ) -> Self {
         children.into_iter().fold(self, Self::push)
     }
+
+    /// Turns the [`Row`] into a [`Wrapping`] row.
+    ///
+    /// A [`Wrapping`] row breaks its contents into multiple lines when
+    /// they do not fit in the available width.
+    pub fn wrap(self) -> Wrapping<'a, Message, Theme, Renderer> {
+        Wrapping {
+            row: self,
+            vertical_spacing: None,
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Default for Row<'a, Message, Renderer>
@@ -339,3 +350,444 @@ where
         Self::new(row)
     }
 }
+
+/// A [`Row`] that wraps its contents.
+///
+/// Children are placed from left to right, breaking into a new line
+/// whenever the next child does not fit in the available width. Each line
+/// is aligned vertically according to [`Row::align_y`].
+///
+/// Children with a [`Length::Fill`] width do not take any space when
+/// breaking lines. Instead, they share the remaining space of their line
+/// and only start a new line when there is no space left.
+#[allow(missing_debug_implementations)]
+pub struct Wrapping<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    row: Row<'a, Message, Theme, Renderer>,
+    vertical_spacing: Option<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> Wrapping<'a, Message, Theme, Renderer> {
+    /// Sets the vertical spacing _between_ lines.
+    ///
+    /// By default, it is the same as the spacing of the [`Row`].
+    pub fn vertical_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.vertical_spacing = Some(amount.into().0);
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Wrapping<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.row.children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.row.diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.row.size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Row {
+            spacing,
+            padding,
+            width,
+            height,
+            align,
+            ref children,
+            ..
+        } = self.row;
+
+        let limits = limits.width(width).height(height).shrink(padding);
+        let max = limits.max();
+        let vertical_spacing = self.vertical_spacing.unwrap_or(spacing);
+
+        let mut nodes = vec![layout::Node::default(); children.len()];
+
+        for ((child, tree), node) in
+            children.iter().zip(&mut tree.children).zip(&mut nodes)
+        {
+            if child.as_widget().size().width.fill_factor() == 0 {
+                *node = child.as_widget().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, max),
+                );
+            }
+        }
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+        let mut x = 0.0;
+
+        for (i, (child, node)) in children.iter().zip(&nodes).enumerate() {
+            let width = node.size().width;
+
+            let overflows = if child.as_widget().size().width.fill_factor() == 0
+            {
+                x + spacing + width > max.width
+            } else {
+                x + spacing >= max.width
+            };
+
+            if i > start && overflows {
+                lines.push(start..i);
+                start = i;
+                x = width;
+            } else {
+                x += if i > start { spacing + width } else { width };
+            }
+        }
+
+        if start < nodes.len() {
+            lines.push(start..nodes.len());
+        }
+
+        let mut intrinsic = Size::ZERO;
+
+        for (i, line) in lines.into_iter().enumerate() {
+            if i > 0 {
+                intrinsic.height += vertical_spacing;
+            }
+
+            let fill_sum: u16 = children[line.clone()]
+                .iter()
+                .map(|child| child.as_widget().size().width.fill_factor())
+                .sum();
+
+            let used = nodes[line.clone()]
+                .iter()
+                .map(|node| node.size().width)
+                .sum::<f32>()
+                + spacing * (line.len() - 1) as f32;
+
+            let remaining = (max.width - used).max(0.0);
+
+            let fill_width = |factor: u16| {
+                let max_width = remaining * factor as f32 / fill_sum as f32;
+
+                let min_width = if max_width.is_infinite() {
+                    0.0
+                } else {
+                    max_width
+                };
+
+                (min_width, max_width)
+            };
+
+            let mut line_height = 0.0f32;
+
+            for ((child, tree), node) in children[line.clone()]
+                .iter()
+                .zip(&mut tree.children[line.clone()])
+                .zip(&mut nodes[line.clone()])
+            {
+                let size = child.as_widget().size();
+
+                if size.height.fill_factor() != 0 {
+                    continue;
+                }
+
+                let factor = size.width.fill_factor();
+
+                if factor != 0 {
+                    let (min_width, max_width) = fill_width(factor);
+
+                    *node = child.as_widget().layout(
+                        tree,
+                        renderer,
+                        &layout::Limits::new(
+                            Size::new(min_width, 0.0),
+                            Size::new(max_width, max.height),
+                        ),
+                    );
+                }
+
+                line_height = line_height.max(node.size().height);
+            }
+
+            for ((child, tree), node) in children[line.clone()]
+                .iter()
+                .zip(&mut tree.children[line.clone()])
+                .zip(&mut nodes[line.clone()])
+            {
+                let size = child.as_widget().size();
+
+                if size.height.fill_factor() == 0 {
+                    continue;
+                }
+
+                let factor = size.width.fill_factor();
+
+                let (min_width, max_width) = if factor != 0 {
+                    fill_width(factor)
+                } else {
+                    (0.0, max.width)
+                };
+
+                *node = child.as_widget().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(
+                        Size::new(min_width, 0.0),
+                        Size::new(max_width, line_height),
+                    ),
+                );
+
+                line_height = line_height.max(node.size().height);
+            }
+
+            let mut x = 0.0;
+
+            for (j, node) in nodes[line].iter_mut().enumerate() {
+                if j > 0 {
+                    x += spacing;
+                }
+
+                node.move_to_mut(Point::new(
+                    padding.left + x,
+                    padding.top + intrinsic.height,
+                ));
+
+                node.align_mut(
+                    Alignment::Start,
+                    align,
+                    Size::new(0.0, line_height),
+                );
+
+                x += node.size().width;
+            }
+
+            intrinsic.width = intrinsic.width.max(x);
+            intrinsic.height += line_height;
+        }
+
+        let size = limits.resolve(width, height, intrinsic);
+
+        layout::Node::with_children(size.expand(padding), nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.row.operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.row.on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.row
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.row
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.row.overlay(tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Wrapping<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(row: Wrapping<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::alignment;
+    use crate::core::Rectangle;
+    use crate::Space;
+
+    type Row<'a> = super::Row<'a, (), crate::Theme, ()>;
+
+    fn space(
+        width: impl Into<Length>,
+        height: impl Into<Length>,
+    ) -> Element<'static, (), crate::Theme, ()> {
+        Space::new(width, height).into()
+    }
+
+    /// Lays out the row in the given width and returns its size and the
+    /// bounds of its children.
+    fn layout(
+        row: Wrapping<'_, (), crate::Theme, ()>,
+        width: f32,
+    ) -> (Size, Vec<Rectangle>) {
+        let mut tree = Tree::new(&row as &dyn Widget<_, _, _>);
+
+        let node = row.layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY)),
+        );
+
+        (
+            node.size(),
+            node.children().iter().map(layout::Node::bounds).collect(),
+        )
+    }
+
+    fn positions(bounds: &[Rectangle]) -> Vec<(f32, f32)> {
+        bounds.iter().map(|bounds| (bounds.x, bounds.y)).collect()
+    }
+
+    #[test]
+    fn breaks_lines() {
+        let (size, children) = layout(
+            Row::with_children((0..5).map(|_| space(30, 10)))
+                .spacing(10)
+                .wrap(),
+            100.0,
+        );
+
+        assert_eq!(size, Size::new(70.0, 50.0));
+        assert_eq!(
+            positions(&children),
+            [
+                (0.0, 0.0),
+                (40.0, 0.0),
+                (0.0, 20.0),
+                (40.0, 20.0),
+                (0.0, 40.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn spaces_lines() {
+        let (size, children) = layout(
+            Row::with_children((0..3).map(|_| space(30, 10)))
+                .spacing(10)
+                .padding(5)
+                .wrap()
+                .vertical_spacing(2),
+            80.0,
+        );
+
+        // The padding is not available to the children
+        assert_eq!(size, Size::new(80.0, 32.0));
+        assert_eq!(
+            positions(&children),
+            [(5.0, 5.0), (45.0, 5.0), (5.0, 17.0)]
+        );
+    }
+
+    #[test]
+    fn aligns_each_line() {
+        let children = || [space(30, 10), space(30, 20), space(30, 30)];
+
+        let (_, start) = layout(Row::with_children(children()).wrap(), 60.0);
+
+        let (_, center) = layout(
+            Row::with_children(children())
+                .align_y(alignment::Vertical::Center)
+                .wrap(),
+            60.0,
+        );
+
+        let (_, end) = layout(
+            Row::with_children(children())
+                .align_y(alignment::Vertical::Bottom)
+                .wrap(),
+            60.0,
+        );
+
+        // The second line only has the tallest child
+        assert_eq!(positions(&start), [(0.0, 0.0), (30.0, 0.0), (0.0, 20.0)]);
+        assert_eq!(positions(&center), [(0.0, 5.0), (30.0, 0.0), (0.0, 20.0)]);
+        assert_eq!(positions(&end), [(0.0, 10.0), (30.0, 0.0), (0.0, 20.0)]);
+    }
+
+    #[test]
+    fn fills_the_rest_of_a_line() {
+        let (_, children) = layout(
+            Row::with_children([
+                space(30, 10),
+                space(Length::Fill, 10),
+                space(30, 10),
+                space(30, 10),
+            ])
+            .spacing(10)
+            .wrap(),
+            100.0,
+        );
+
+        assert_eq!(
+            children,
+            [
+                Rectangle::new(Point::new(0.0, 0.0), Size::new(30.0, 10.0)),
+                Rectangle::new(Point::new(40.0, 0.0), Size::new(20.0, 10.0)),
+                Rectangle::new(Point::new(70.0, 0.0), Size::new(30.0, 10.0)),
+                Rectangle::new(Point::new(0.0, 20.0), Size::new(30.0, 10.0)),
+            ]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::container;
+use crate::core::accessibility;
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
@@ -424,9 +425,29 @@ where
             translation,
         );
 
-        operation.container(
+        let node = || {
+            let mut node =
+                accessibility::Node::new(accessibility::Role::ScrollView);
+
+            if self.direction.vertical().is_some() {
+                node = node
+                    .action(accessibility::Action::ScrollUp)
+                    .action(accessibility::Action::ScrollDown);
+            }
+
+            if self.direction.horizontal().is_some() {
+                node = node
+                    .action(accessibility::Action::ScrollLeft)
+                    .action(accessibility::Action::ScrollRight);
+            }
+
+            node
+        };
+
+        operation.accessible(
             self.id.as_ref().map(|id| &id.0),
             bounds,
+            &node,
             &mut |operation| {
                 self.content.as_widget().operate(
                     &mut tree.children[0],
@@ -1131,7 +1152,7 @@ fn notify_on_scroll<Message>(
 }
 
 #[derive(Debug, Clone, Copy)]
-struct State {
+pub(crate) struct State {
     scroll_area_touched_at: Option<Point>,
     offset_y: Offset,
     y_scroller_grabbed_at: Option<f32>,
@@ -1358,6 +1379,19 @@ impl State {
         );
     }
 
+    /// Returns the absolute offset of the [`State`], given the size of the
+    /// [`Scrollable`] and its contents.
+    pub(crate) fn absolute_offset(
+        &self,
+        bounds: Size,
+        content_bounds: Size,
+    ) -> Vector {
+        Vector::new(
+            self.offset_x.absolute(bounds.width, content_bounds.width),
+            self.offset_y.absolute(bounds.height, content_bounds.height),
+        )
+    }
+
     /// Returns the scrolling translation of the [`State`], given a [`Direction`],
     /// the bounds of the [`Scrollable`] and its contents.
     fn translation(
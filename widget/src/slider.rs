@@ -1,4 +1,5 @@
 //! Display an interactive selector of a single value from a range of values.
+use crate::core::accessibility;
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
@@ -7,6 +8,7 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     self, Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle,
@@ -198,6 +200,33 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable_with_bounds(state, None, layout.bounds());
+
+        let node = || {
+            accessibility::Node::new(accessibility::Role::Slider)
+                .value(accessibility::Value::Numeric {
+                    value: self.value.into(),
+                    min: (*self.range.start()).into(),
+                    max: (*self.range.end()).into(),
+                    step: self.step.into(),
+                })
+                .action(accessibility::Action::SetValue)
+                .action(accessibility::Action::Increment)
+                .action(accessibility::Action::Decrement)
+        };
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -290,6 +319,8 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = false;
+
                 if let Some(cursor_position) =
                     cursor.position_over(layout.bounds())
                 {
@@ -325,18 +356,26 @@ where
                 }
             }
             Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
-                if cursor.position_over(layout.bounds()).is_some() {
+                if state.is_focused
+                    || cursor.position_over(layout.bounds()).is_some()
+                {
                     match key {
-                        Key::Named(key::Named::ArrowUp) => {
+                        Key::Named(
+                            key::Named::ArrowUp | key::Named::ArrowRight,
+                        ) => {
                             let _ = increment(current_value).map(change);
+
+                            return event::Status::Captured;
                         }
-                        Key::Named(key::Named::ArrowDown) => {
+                        Key::Named(
+                            key::Named::ArrowDown | key::Named::ArrowLeft,
+                        ) => {
                             let _ = decrement(current_value).map(change);
+
+                            return event::Status::Captured;
                         }
                         _ => (),
                     }
-
-                    return event::Status::Captured;
                 }
             }
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
@@ -366,6 +405,8 @@ where
             &self.class,
             if state.is_dragging {
                 Status::Dragged
+            } else if state.is_focused {
+                Status::Focused
             } else if is_mouse_over {
                 Status::Hovered
             } else {
@@ -487,9 +528,24 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct State {
     is_dragging: bool,
+    is_focused: bool,
     keyboard_modifiers: keyboard::Modifiers,
 }
 
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 /// The possible status of a [`Slider`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -499,6 +555,8 @@ pub enum Status {
     Hovered,
     /// The [`Slider`] is being dragged.
     Dragged,
+    /// The [`Slider`] has keyboard focus.
+    Focused,
 }
 
 /// The appearance of a slider.
@@ -594,11 +652,18 @@ pub fn default(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
 
     let color = match status {
-        Status::Active => palette.primary.strong.color,
+        Status::Active | Status::Focused => palette.primary.strong.color,
         Status::Hovered => palette.primary.base.color,
         Status::Dragged => palette.primary.strong.color,
     };
 
+    let (border_width, border_color) = match status {
+        Status::Focused => (2.0, palette.background.base.text),
+        Status::Active | Status::Hovered | Status::Dragged => {
+            (0.0, Color::TRANSPARENT)
+        }
+    };
+
     Style {
         rail: Rail {
             colors: (color, palette.secondary.base.color),
@@ -608,8 +673,8 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         handle: Handle {
             shape: HandleShape::Circle { radius: 7.0 },
             color,
-            border_color: Color::TRANSPARENT,
-            border_width: 0.0,
+            border_color,
+            border_width,
         },
     }
 }
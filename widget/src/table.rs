@@ -0,0 +1,1615 @@
+//! Display data in columns, with a header row.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::scrollable::{self, Scrollable, Scrollbar};
+
+/// A table of rows, with a header row describing its columns.
+///
+/// Its header stays on top while scrolling vertically, and it follows the
+/// rows while scrolling horizontally.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// # use iced_widget::{table, text};
+/// # use iced_widget::core::Length;
+/// #
+/// struct User {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Sort(usize),
+///     Select(usize),
+/// }
+///
+/// fn view(users: &[User]) -> Element<'_, Message> {
+///     let columns = [
+///         table::column(text("Name"), |user: &&User| text(&user.name).into())
+///             .width(Length::Fill),
+///         table::column(text("Age"), |user: &&User| text(user.age).into()),
+///     ];
+///
+///     table(columns, users)
+///         .on_sort(Message::Sort)
+///         .on_select(Message::Select)
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    header: Vec<Element<'a, Message, Theme, Renderer>>,
+    cells: Vec<Element<'a, Message, Theme, Renderer>>,
+    columns: Vec<Properties>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    selected: Option<usize>,
+    on_sort: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_resize: Option<(f32, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Table<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default padding of the cells of a [`Table`].
+    pub const DEFAULT_PADDING: f32 = 5.0;
+
+    /// Creates a new [`Table`] with the given columns and rows.
+    ///
+    /// Every [`Column`] builds one cell for each row.
+    pub fn new<T>(
+        columns: impl IntoIterator<Item = Column<'a, T, Message, Theme, Renderer>>,
+        rows: impl IntoIterator<Item = T>,
+    ) -> Self {
+        let columns: Vec<_> = columns.into_iter().collect();
+
+        let mut cells = Vec::new();
+
+        for row in rows {
+            cells.extend(columns.iter().map(|column| (column.view)(&row)));
+        }
+
+        let (header, columns) = columns
+            .into_iter()
+            .map(|column| {
+                (
+                    column.header,
+                    Properties {
+                        width: column.width,
+                        align_x: column.align_x,
+                        sort: column.sort,
+                    },
+                )
+            })
+            .unzip();
+
+        Self {
+            header,
+            cells,
+            columns,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Padding::new(Self::DEFAULT_PADDING),
+            selected: None,
+            on_sort: None,
+            on_resize: None,
+            on_select: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of every cell of the [`Table`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the index of the selected row of the [`Table`], if any.
+    pub fn selected(mut self, row: Option<usize>) -> Self {
+        self.selected = row;
+        self
+    }
+
+    /// Sets the message that will be produced when the header of a column
+    /// is clicked.
+    ///
+    /// The closure receives the index of the clicked column. A sort indicator
+    /// is shown for every [`Column`] with a [`Sort`] order.
+    pub fn on_sort(mut self, on_sort: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Enables the resize interactions of the [`Table`], which will use the
+    /// provided function to produce messages.
+    ///
+    /// The `leeway` describes the amount of space around a column divider
+    /// that can be used to grab it.
+    ///
+    /// The new width of a column is not applied automatically; you will need
+    /// to set it with [`Column::width`] in your `view`.
+    pub fn on_resize<F>(mut self, leeway: impl Into<Pixels>, f: F) -> Self
+    where
+        F: 'a + Fn(ResizeEvent) -> Message,
+    {
+        self.on_resize = Some((leeway.into().0, Box::new(f)));
+        self
+    }
+
+    /// Sets the message that will be produced when a row of the [`Table`]
+    /// is clicked.
+    ///
+    /// The closure receives the index of the clicked row.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Table`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// A column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    header: Element<'a, Message, Theme, Renderer>,
+    view: Box<dyn Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    width: Length,
+    align_x: alignment::Horizontal,
+    sort: Option<Sort>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Column<'a, T, Message, Theme, Renderer> {
+    /// Creates a new [`Column`] with the given header and a closure that
+    /// builds the cell of each row out of a reference to it.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+        view: impl Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            view: Box::new(view),
+            width: Length::Shrink,
+            align_x: alignment::Horizontal::Left,
+            sort: None,
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    ///
+    /// A [`Length::Shrink`] column fits its widest cell, while
+    /// [`Length::Fill`] columns share the remaining width of the [`Table`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the horizontal alignment of the cells of the [`Column`].
+    pub fn align_x(
+        mut self,
+        alignment: impl Into<alignment::Horizontal>,
+    ) -> Self {
+        self.align_x = alignment.into();
+        self
+    }
+
+    /// Sets the [`Sort`] order of the [`Column`], shown in its header.
+    pub fn sort(mut self, sort: impl Into<Option<Sort>>) -> Self {
+        self.sort = sort.into();
+        self
+    }
+}
+
+/// Creates a new [`Column`] with the given header and a closure that builds
+/// the cell of each row.
+pub fn column<'a, T, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    view: impl Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> Column<'a, T, Message, Theme, Renderer> {
+    Column::new(header, view)
+}
+
+/// The sort order of a [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sort {
+    /// The rows are sorted in ascending order.
+    Ascending,
+    /// The rows are sorted in descending order.
+    Descending,
+}
+
+/// The resize event of a [`Column`].
+#[derive(Debug, Clone, Copy)]
+pub struct ResizeEvent {
+    /// The index of the resized [`Column`].
+    pub column: usize,
+
+    /// The new width of the [`Column`].
+    pub width: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Properties {
+    width: Length,
+    align_x: alignment::Horizontal,
+    sort: Option<Sort>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    header_height: f32,
+    resizing: Option<Resize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resize {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+/// The header and the scrollable rows of a [`Table`].
+struct Sheet<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    header: Vec<Element<'a, Message, Theme, Renderer>>,
+    body: Element<'a, Message, Theme, Renderer>,
+    columns: Vec<Properties>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    selected: Option<usize>,
+    on_sort: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_resize: Option<(f32, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Sheet<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn indicator(&self, renderer: &Renderer) -> f32 {
+        if self.on_sort.is_some() {
+            renderer.default_size().0
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the bounds of the header row and the rows of the [`Table`].
+    fn bounds(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> (Rectangle, Rectangle) {
+        let bounds = layout.bounds();
+        let header_height = tree.state.downcast_ref::<State>().header_height;
+
+        (
+            Rectangle {
+                height: header_height,
+                ..bounds
+            },
+            Rectangle {
+                y: bounds.y + header_height,
+                height: bounds.height - header_height,
+                ..bounds
+            },
+        )
+    }
+
+    /// Returns the current scroll offset of the rows.
+    fn offset(&self, tree: &Tree, layout: Layout<'_>) -> Vector {
+        let (_, body_bounds) = self.bounds(tree, layout);
+        let grid = self.body(tree);
+
+        let content = Size::new(
+            grid.widths.iter().sum(),
+            grid.rows.last().map_or(0.0, |(top, height)| top + height),
+        );
+
+        tree.children[self.header.len()]
+            .state
+            .downcast_ref::<scrollable::State>()
+            .absolute_offset(body_bounds.size(), content)
+    }
+
+    fn body<'b>(&self, tree: &'b Tree) -> &'b Grid {
+        tree.children[self.header.len()].children[0]
+            .state
+            .downcast_ref::<Grid>()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Sheet<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.header
+            .iter()
+            .chain(std::iter::once(&self.body))
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = self
+            .header
+            .iter()
+            .chain(std::iter::once(&self.body))
+            .map(Element::as_widget)
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max = limits.max();
+        let padding = self.padding;
+        let indicator = self.indicator(renderer);
+
+        let (header, body) = tree.children.split_at_mut(self.header.len());
+        let body = &mut body[0];
+
+        let headers: Vec<Size> = self
+            .header
+            .iter()
+            .zip(header.iter_mut())
+            .map(|(cell, tree)| intrinsic(cell, tree, renderer).0)
+            .collect();
+
+        {
+            let grid = body.children[0].state.downcast_mut::<Grid>();
+
+            grid.columns = self.columns.clone();
+            grid.padding = padding;
+            grid.header = headers
+                .iter()
+                .map(|size| size.width + padding.horizontal() + indicator)
+                .collect();
+            grid.available = max.width;
+        }
+
+        let body_limits = |header_height: f32| {
+            layout::Limits::new(
+                Size::ZERO,
+                Size::new(max.width, (max.height - header_height).max(0.0)),
+            )
+        };
+
+        let estimated_height =
+            headers.iter().map(|size| size.height).fold(0.0, f32::max)
+                + padding.vertical();
+
+        let mut rows = self.body.as_widget().layout(
+            body,
+            renderer,
+            &body_limits(estimated_height),
+        );
+
+        let widths =
+            body.children[0].state.downcast_ref::<Grid>().widths.clone();
+
+        let mut cells: Vec<_> = self
+            .header
+            .iter()
+            .zip(header.iter_mut())
+            .zip(&widths)
+            .map(|((cell, tree), width)| {
+                cell.as_widget().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(
+                            (width - padding.horizontal() - indicator).max(0.0),
+                            f32::INFINITY,
+                        ),
+                    ),
+                )
+            })
+            .collect();
+
+        let header_height = cells
+            .iter()
+            .map(|cell| cell.size().height)
+            .fold(0.0, f32::max)
+            + padding.vertical();
+
+        if header_height != estimated_height {
+            // The cells are the same, so there is no need to measure them again
+            body.children[0].state.downcast_mut::<Grid>().is_measured = true;
+
+            rows = self.body.as_widget().layout(
+                body,
+                renderer,
+                &body_limits(header_height),
+            );
+        }
+
+        let mut x = 0.0;
+
+        for (cell, width) in cells.iter_mut().zip(&widths) {
+            let height = cell.size().height;
+
+            cell.move_to_mut(Point::new(
+                x + padding.left,
+                (header_height - height) / 2.0,
+            ));
+
+            x += width;
+        }
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(
+                x.max(rows.size().width),
+                header_height + rows.size().height,
+            ),
+        );
+
+        tree.state.downcast_mut::<State>().header_height = header_height;
+
+        // The header cells are followed by the rows, like the children of
+        // the tree
+        cells.push(rows.move_to(Point::new(0.0, header_height)));
+
+        layout::Node::with_children(size, cells)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.header
+                .iter()
+                .chain(std::iter::once(&self.body))
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let (header_bounds, body_bounds) = self.bounds(tree, layout);
+        let offset = self.offset(tree, layout);
+        let widths = self.body(tree).widths.clone();
+
+        if let Some((leeway, on_resize)) = &self.on_resize {
+            let state = tree.state.downcast_mut::<State>();
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left,
+                )) => {
+                    if let Some(column) = cursor
+                        .position_over(header_bounds)
+                        .and_then(|position| {
+                            divider(
+                                &widths,
+                                header_bounds.x - offset.x,
+                                position.x,
+                                *leeway,
+                            )
+                        })
+                    {
+                        state.resizing = Some(Resize {
+                            column,
+                            origin: cursor.position().unwrap_or_default().x,
+                            width: widths[column],
+                        });
+
+                        return event::Status::Captured;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                )) if state.resizing.is_some() => {
+                    state.resizing = None;
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    if let Some(resize) = state.resizing {
+                        shell.publish(on_resize(ResizeEvent {
+                            column: resize.column,
+                            width: (resize.width + position.x - resize.origin)
+                                .max(self.padding.horizontal()),
+                        }));
+
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let header_cursor = header_cursor(cursor, header_bounds, offset);
+        let header_viewport = header_bounds + Vector::new(offset.x, 0.0);
+
+        let header_status = self
+            .header
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((cell, state), layout)| {
+                cell.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    header_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &header_viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let body_status = std::iter::once(&mut self.body)
+            .zip(&mut tree.children[self.header.len()..])
+            .zip(layout.children().skip(self.header.len()))
+            .map(|((body, state), layout)| {
+                body.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let status = header_status.merge(body_status);
+
+        if status == event::Status::Captured
+            || !matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            )
+        {
+            return status;
+        }
+
+        if let (Some(on_sort), Some(position)) =
+            (&self.on_sort, header_cursor.position())
+        {
+            if let Some(column) =
+                column_at(&widths, header_bounds.x, position.x)
+            {
+                shell.publish(on_sort(column));
+
+                return event::Status::Captured;
+            }
+        }
+
+        if let (Some(on_select), Some(position)) =
+            (&self.on_select, cursor.position_over(body_bounds))
+        {
+            let y = position.y - body_bounds.y + offset.y;
+
+            if let Some(row) = self
+                .body(tree)
+                .rows
+                .iter()
+                .position(|(top, height)| y >= *top && y < top + height)
+            {
+                shell.publish(on_select(row));
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (header_bounds, _) = self.bounds(tree, layout);
+        let offset = self.offset(tree, layout);
+        let widths = &self.body(tree).widths;
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.resizing.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        if let Some((leeway, _)) = &self.on_resize {
+            if cursor
+                .position_over(header_bounds)
+                .and_then(|position| {
+                    divider(
+                        widths,
+                        header_bounds.x - offset.x,
+                        position.x,
+                        *leeway,
+                    )
+                })
+                .is_some()
+            {
+                return mouse::Interaction::ResizingHorizontally;
+            }
+        }
+
+        let header_cursor = header_cursor(cursor, header_bounds, offset);
+        let header_viewport = header_bounds + Vector::new(offset.x, 0.0);
+
+        let interaction = self
+            .header
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((cell, state), layout)| {
+                cell.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    header_cursor,
+                    &header_viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::default()
+            && self.on_sort.is_some()
+            && header_cursor.is_over(Rectangle {
+                width: widths.iter().sum(),
+                ..header_bounds
+            })
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        std::iter::once(&self.body)
+            .zip(&tree.children[self.header.len()..])
+            .zip(layout.children().skip(self.header.len()))
+            .map(|((body, state), layout)| {
+                body.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .fold(interaction, mouse::Interaction::max)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let (header_bounds, body_bounds) = self.bounds(tree, layout);
+        let offset = self.offset(tree, layout);
+        let grid = self.body(tree);
+        let appearance = theme.style(&self.class);
+
+        renderer.with_layer(body_bounds, |renderer| {
+            for (i, (top, height)) in grid.rows.iter().enumerate() {
+                let row = Rectangle {
+                    x: body_bounds.x,
+                    y: body_bounds.y + top - offset.y,
+                    width: body_bounds.width,
+                    height: *height,
+                };
+
+                if !row.intersects(&body_bounds) {
+                    continue;
+                }
+
+                let background = if self.selected == Some(i) {
+                    Some(appearance.selected_row_background)
+                } else if i % 2 == 1 {
+                    appearance.alternate_row_background
+                } else {
+                    appearance.row_background
+                };
+
+                if let Some(background) = background {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: row,
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+                }
+            }
+        });
+
+        for ((body, state), layout) in std::iter::once(&self.body)
+            .zip(&tree.children[self.header.len()..])
+            .zip(layout.children().skip(self.header.len()))
+        {
+            body.as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: header_bounds,
+                ..renderer::Quad::default()
+            },
+            appearance.header_background,
+        );
+
+        let header_cursor = header_cursor(cursor, header_bounds, offset);
+        let header_viewport = header_bounds + Vector::new(offset.x, 0.0);
+
+        renderer.with_layer(header_bounds, |renderer| {
+            renderer.with_translation(
+                Vector::new(-offset.x, 0.0),
+                |renderer| {
+                    for ((cell, state), layout) in self
+                        .header
+                        .iter()
+                        .zip(&tree.children)
+                        .zip(layout.children())
+                    {
+                        cell.as_widget().draw(
+                            state,
+                            renderer,
+                            theme,
+                            style,
+                            layout,
+                            header_cursor,
+                            &header_viewport,
+                        );
+                    }
+
+                    let mut x = header_bounds.x;
+
+                    for (width, column) in grid.widths.iter().zip(&self.columns)
+                    {
+                        x += width;
+
+                        if let Some(sort) = column.sort {
+                            let size = renderer.default_size();
+                            let line_height = text::LineHeight::default();
+
+                            renderer.fill_text(
+                                Text {
+                                    content: match sort {
+                                        Sort::Ascending => "↑",
+                                        Sort::Descending => "↓",
+                                    }
+                                    .to_owned(),
+                                    bounds: Size::new(
+                                        *width,
+                                        line_height.to_absolute(size).into(),
+                                    ),
+                                    size,
+                                    line_height,
+                                    font: renderer.default_font(),
+                                    horizontal_alignment:
+                                        alignment::Horizontal::Right,
+                                    vertical_alignment:
+                                        alignment::Vertical::Center,
+                                    shaping: text::Shaping::Advanced,
+                                    wrapping: text::Wrapping::default(),
+                                    ellipsis: text::Ellipsis::default(),
+                                    max_lines: None,
+                                },
+                                Point::new(
+                                    x - self.padding.right,
+                                    header_bounds.center_y(),
+                                ),
+                                appearance.sort_indicator,
+                                header_viewport,
+                            );
+                        }
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: x - 1.0,
+                                    y: header_bounds.y,
+                                    width: 1.0,
+                                    height: header_bounds.height,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            appearance.divider,
+                        );
+                    }
+                },
+            );
+        });
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: header_bounds.y + header_bounds.height - 1.0,
+                    width: bounds.width,
+                    height: 1.0,
+                },
+                ..renderer::Quad::default()
+            },
+            appearance.divider,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let offset = self.offset(tree, layout);
+        let columns = self.header.len();
+        let (header, body) = tree.children.split_at_mut(columns);
+
+        let overlays: Vec<_> = self
+            .header
+            .iter_mut()
+            .zip(header)
+            .zip(layout.children())
+            .filter_map(|((cell, state), layout)| {
+                cell.as_widget_mut().overlay(
+                    state,
+                    layout,
+                    renderer,
+                    translation - Vector::new(offset.x, 0.0),
+                )
+            })
+            .chain(
+                std::iter::once(&mut self.body)
+                    .zip(body)
+                    .zip(layout.children().skip(columns))
+                    .filter_map(|((body, state), layout)| {
+                        body.as_widget_mut().overlay(
+                            state,
+                            layout,
+                            renderer,
+                            translation,
+                        )
+                    }),
+            )
+            .collect();
+
+        (!overlays.is_empty())
+            .then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+/// The cells of a [`Table`], laid out inside its [`Scrollable`].
+struct Body<'a, Message, Theme, Renderer> {
+    columns: usize,
+    cells: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+/// The layout state of the cells of a [`Table`].
+#[derive(Debug, Clone, Default)]
+struct Grid {
+    columns: Vec<Properties>,
+    padding: Padding,
+    header: Vec<f32>,
+    available: f32,
+    widths: Vec<f32>,
+    rows: Vec<(f32, f32)>,
+    intrinsic: Vec<f32>,
+    is_measured: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Body<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Grid>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Grid::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.cells.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.cells);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        let grid = tree.state.downcast_mut::<Grid>();
+        let padding = grid.padding;
+        let width_of = |column: usize| {
+            grid.columns
+                .get(column)
+                .map(|properties| properties.width)
+                .unwrap_or(Length::Shrink)
+        };
+
+        let mut minimums = grid.header.clone();
+        minimums.resize(self.columns, 0.0);
+
+        // Cells laid out without limits can be reused when they fit
+        let mut measured = Vec::new();
+
+        if !std::mem::take(&mut grid.is_measured)
+            || grid.intrinsic.len() != self.cells.len()
+        {
+            grid.intrinsic.clear();
+
+            for (cell, tree) in self.cells.iter().zip(&mut tree.children) {
+                let (size, node) = intrinsic(cell, tree, renderer);
+
+                grid.intrinsic.push(size.width);
+                measured.push(Some(node));
+            }
+        }
+
+        measured.resize_with(self.cells.len(), || None);
+
+        for (i, width) in grid.intrinsic.iter().enumerate() {
+            let column = i % self.columns;
+
+            if !matches!(width_of(column), Length::Fixed(_)) {
+                minimums[column] =
+                    minimums[column].max(width + padding.horizontal());
+            }
+        }
+
+        let mut widths: Vec<f32> = (0..self.columns)
+            .map(|column| match width_of(column) {
+                Length::Fixed(width) => width,
+                _ => minimums[column],
+            })
+            .collect();
+
+        let fill_sum: u16 =
+            (0..self.columns).map(|i| width_of(i).fill_factor()).sum();
+
+        if fill_sum > 0 && grid.available.is_finite() {
+            let used: f32 = (0..self.columns)
+                .filter(|i| !width_of(*i).is_fill())
+                .map(|i| widths[i])
+                .sum();
+
+            let remaining = (grid.available - used).max(0.0);
+
+            for (i, width) in widths.iter_mut().enumerate() {
+                let factor = width_of(i).fill_factor();
+
+                if factor != 0 {
+                    *width = (remaining * f32::from(factor)
+                        / f32::from(fill_sum))
+                    .max(minimums[i]);
+                }
+            }
+        }
+
+        let mut nodes = Vec::with_capacity(self.cells.len());
+        let mut rows = Vec::new();
+        let mut top = 0.0;
+
+        for ((cells, trees), measured) in self
+            .cells
+            .chunks(self.columns.max(1))
+            .zip(tree.children.chunks_mut(self.columns.max(1)))
+            .zip(measured.chunks_mut(self.columns.max(1)))
+        {
+            let mut row: Vec<_> = cells
+                .iter()
+                .zip(trees.iter_mut())
+                .zip(&widths)
+                .enumerate()
+                .map(|(i, ((cell, tree), width))| {
+                    let available = (width - padding.horizontal()).max(0.0);
+
+                    let node = measured[i].take().filter(|node| {
+                        !cell.as_widget().size().width.is_fill()
+                            && node.size().width <= available
+                    });
+
+                    if let Some(node) = node {
+                        return node;
+                    }
+
+                    cell.as_widget().layout(
+                        tree,
+                        renderer,
+                        &layout::Limits::new(
+                            Size::ZERO,
+                            Size::new(available, f32::INFINITY),
+                        ),
+                    )
+                })
+                .collect();
+
+            let content_height = cells
+                .iter()
+                .zip(&row)
+                .filter(|(cell, _)| !cell.as_widget().size().height.is_fill())
+                .map(|(_, node)| node.size().height)
+                .fold(0.0, f32::max);
+
+            let mut x = 0.0;
+
+            for (i, ((cell, tree), node)) in
+                cells.iter().zip(trees.iter_mut()).zip(&mut row).enumerate()
+            {
+                let width = widths[i];
+
+                if cell.as_widget().size().height.is_fill() {
+                    *node = cell.as_widget().layout(
+                        tree,
+                        renderer,
+                        &layout::Limits::new(
+                            Size::ZERO,
+                            Size::new(
+                                (width - padding.horizontal()).max(0.0),
+                                content_height,
+                            ),
+                        ),
+                    );
+                }
+
+                let size = node.size();
+                let space =
+                    (width - padding.horizontal() - size.width).max(0.0);
+
+                let align_x = grid
+                    .columns
+                    .get(i)
+                    .map(|properties| properties.align_x)
+                    .unwrap_or(alignment::Horizontal::Left);
+
+                node.move_to_mut(Point::new(
+                    x + padding.left
+                        + match align_x {
+                            alignment::Horizontal::Left => 0.0,
+                            alignment::Horizontal::Center => space / 2.0,
+                            alignment::Horizontal::Right => space,
+                        },
+                    top + padding.top + (content_height - size.height) / 2.0,
+                ));
+
+                x += width;
+            }
+
+            let height = content_height + padding.vertical();
+
+            rows.push((top, height));
+            nodes.extend(row);
+
+            top += height;
+        }
+
+        let width = widths.iter().sum();
+
+        grid.widths = widths;
+        grid.rows = rows;
+
+        layout::Node::with_children(Size::new(width, top), nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.cells
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((cell, state), layout)| {
+                    cell.as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.cells
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((cell, state), layout)| {
+                cell.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.cells
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((cell, state), layout)| {
+                cell.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((cell, state), layout) in
+            self.cells.iter().zip(&tree.children).zip(layout.children())
+        {
+            if layout.bounds().intersects(viewport) {
+                cell.as_widget().draw(
+                    state, renderer, theme, style, layout, cursor, viewport,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.cells,
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Table<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + scrollable::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(table: Table<'a, Message, Theme, Renderer>) -> Self {
+        let body = Body {
+            columns: table.header.len(),
+            cells: table.cells,
+        };
+
+        let fill_or_shrink = |length: Length| {
+            if length == Length::Shrink {
+                Length::Shrink
+            } else {
+                Length::Fill
+            }
+        };
+
+        let body = Scrollable::new(Element::new(body))
+            .direction(scrollable::Direction::Both {
+                vertical: Scrollbar::default(),
+                horizontal: Scrollbar::default(),
+            })
+            .width(fill_or_shrink(table.width))
+            .height(fill_or_shrink(table.height));
+
+        Element::new(Sheet {
+            header: table.header,
+            body: body.into(),
+            columns: table.columns,
+            width: table.width,
+            height: table.height,
+            padding: table.padding,
+            selected: table.selected,
+            on_sort: table.on_sort,
+            on_resize: table.on_resize,
+            on_select: table.on_select,
+            class: table.class,
+        })
+    }
+}
+
+/// Returns the size of the given cell with unbounded limits, together with
+/// its layout.
+///
+/// Cells filling an axis do not contribute to it.
+fn intrinsic<Message, Theme, Renderer>(
+    cell: &Element<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    renderer: &Renderer,
+) -> (Size, layout::Node)
+where
+    Renderer: crate::core::Renderer,
+{
+    let size = cell.as_widget().size();
+    let node = cell.as_widget().layout(
+        tree,
+        renderer,
+        &layout::Limits::new(Size::ZERO, Size::INFINITY),
+    );
+
+    let intrinsic = Size::new(
+        if size.width.is_fill() {
+            0.0
+        } else {
+            node.size().width
+        },
+        if size.height.is_fill() {
+            0.0
+        } else {
+            node.size().height
+        },
+    );
+
+    (intrinsic, node)
+}
+
+/// Returns the cursor of the header, which scrolls horizontally with the
+/// rows.
+fn header_cursor(
+    cursor: mouse::Cursor,
+    bounds: Rectangle,
+    offset: Vector,
+) -> mouse::Cursor {
+    match cursor.position_over(bounds) {
+        Some(position) => {
+            mouse::Cursor::Available(position + Vector::new(offset.x, 0.0))
+        }
+        None => mouse::Cursor::Unavailable,
+    }
+}
+
+/// Returns the column whose right divider is within `leeway` of `x`.
+fn divider(widths: &[f32], start: f32, x: f32, leeway: f32) -> Option<usize> {
+    widths
+        .iter()
+        .scan(start, |edge, width| {
+            *edge += width;
+            Some(*edge)
+        })
+        .position(|edge| (edge - x).abs() <= leeway)
+}
+
+/// Returns the column containing `x`.
+fn column_at(widths: &[f32], start: f32, x: f32) -> Option<usize> {
+    let mut left = start;
+
+    widths.iter().position(|width| {
+        let right = left + width;
+        let is_inside = x >= left && x < right;
+        left = right;
+
+        is_inside
+    })
+}
+
+/// The appearance of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the header row.
+    pub header_background: Background,
+    /// The [`Background`] of even rows, if any.
+    pub row_background: Option<Background>,
+    /// The [`Background`] of odd rows, if any.
+    pub alternate_row_background: Option<Background>,
+    /// The [`Background`] of the selected row.
+    pub selected_row_background: Background,
+    /// The [`Color`] of the column dividers and the header border.
+    pub divider: Color,
+    /// The [`Color`] of the sort indicators.
+    pub sort_indicator: Color,
+}
+
+/// The theme catalog of a [`Table`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Table`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Table`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        header_background: palette.background.weak.color.into(),
+        row_background: None,
+        alternate_row_background: Some(
+            palette.background.weak.color.scale_alpha(0.5).into(),
+        ),
+        selected_row_background: palette.primary.weak.color.into(),
+        divider: palette.background.strong.color,
+        sort_indicator: palette.background.base.text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Space;
+
+    type Table<'a> = super::Table<'a, (), Theme, ()>;
+
+    fn space(
+        width: impl Into<Length>,
+        height: impl Into<Length>,
+    ) -> Element<'static, (), Theme, ()> {
+        Space::new(width, height).into()
+    }
+
+    /// Lays out the table and returns the bounds of its header cells and
+    /// its body cells.
+    fn layout(table: Table<'_>) -> (Size, Vec<Rectangle>, Vec<Rectangle>) {
+        let table: Element<'_, (), Theme, ()> = table.into();
+        let mut tree = Tree::new(&table);
+
+        let node = table.as_widget().layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(400.0, 300.0)),
+        );
+
+        let layout = Layout::new(&node);
+        let mut children: Vec<_> = layout.children().collect();
+        let body = children.pop().expect("Table body");
+
+        let header = children.iter().map(Layout::bounds).collect();
+        let cells = body
+            .children()
+            .flat_map(|content| content.children())
+            .map(|cell| cell.bounds())
+            .collect();
+
+        (node.size(), header, cells)
+    }
+
+    #[test]
+    fn sizes_columns() {
+        let columns = [
+            column(space(30, 10), |width: &f32| space(*width, *width / 2.0)),
+            column(space(10, 10), |_: &f32| space(10, 10)).width(100),
+            column(space(10, 10), |_: &f32| space(Length::Fill, 10))
+                .width(Length::Fill),
+        ];
+
+        let (size, header, cells) = layout(Table::new(columns, [40.0, 80.0]));
+
+        let rectangle = |x, y, width, height| {
+            Rectangle::new(Point::new(x, y), Size::new(width, height))
+        };
+
+        // The shrink column fits its widest cell and the fill column takes
+        // the rest, all with 5 units of padding
+        assert_eq!(size, Size::new(400.0, 300.0));
+        assert_eq!(
+            header,
+            [
+                rectangle(5.0, 5.0, 30.0, 10.0),
+                rectangle(95.0, 5.0, 10.0, 10.0),
+                rectangle(195.0, 5.0, 10.0, 10.0),
+            ]
+        );
+        assert_eq!(
+            cells,
+            [
+                rectangle(5.0, 25.0, 40.0, 20.0),
+                rectangle(95.0, 30.0, 10.0, 10.0),
+                rectangle(195.0, 30.0, 200.0, 10.0),
+                rectangle(5.0, 55.0, 80.0, 40.0),
+                rectangle(95.0, 70.0, 10.0, 10.0),
+                rectangle(195.0, 70.0, 200.0, 10.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn aligns_cells() {
+        let columns = [
+            column(space(10, 10), |_: &()| space(20, 10))
+                .width(100)
+                .align_x(alignment::Horizontal::Center),
+            column(space(10, 10), |_: &()| space(20, 10))
+                .width(100)
+                .align_x(alignment::Horizontal::Right),
+        ];
+
+        let (size, _, cells) = layout(
+            Table::new(columns, [()])
+                .width(Length::Shrink)
+                .height(Length::Shrink),
+        );
+
+        assert_eq!(size, Size::new(200.0, 40.0));
+        assert_eq!(
+            cells.iter().map(|cell| cell.x).collect::<Vec<_>>(),
+            [40.0, 175.0]
+        );
+    }
+}
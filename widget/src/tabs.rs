@@ -0,0 +1,927 @@
+//! Switch between documents with a strip of closable, reorderable tabs.
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+/// The distance the cursor needs to travel before a tab starts being dragged.
+const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
+
+/// The space between the icon, the label and the close button of a tab.
+const SPACING: f32 = 8.0;
+
+/// The glyph of the close button of a tab.
+const CLOSE_ICON: &str = "×";
+
+/// A tab of some [`Tabs`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab {
+    label: String,
+    icon: Option<char>,
+    is_closable: bool,
+}
+
+impl Tab {
+    /// Creates a new [`Tab`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            is_closable: true,
+        }
+    }
+
+    /// Sets the code point of the icon of the [`Tab`].
+    ///
+    /// The icon is drawn with the icon font of the [`Tabs`].
+    pub fn icon(mut self, code_point: char) -> Self {
+        self.icon = Some(code_point);
+        self
+    }
+
+    /// Sets whether the [`Tab`] shows a close button.
+    ///
+    /// By default, every tab can be closed as long as
+    /// [`Tabs::on_close`] is set.
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+}
+
+/// A tab that was dragged to a new position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reorder {
+    /// The original index of the tab.
+    pub from: usize,
+    /// The new index of the tab.
+    pub to: usize,
+}
+
+impl Reorder {
+    /// Moves the item at `from` to `to` in the given list, matching the new
+    /// order of the [`Tabs`].
+    pub fn apply<T>(self, items: &mut Vec<T>) {
+        let item = items.remove(self.from);
+        items.insert(self.to, item);
+    }
+}
+
+/// A strip of tabs.
+///
+/// A [`Tab`] can be selected by clicking it, closed with its close button
+/// and dragged to a new position. When the tabs do not fit, the strip can be
+/// scrolled with the mouse wheel; the active tab is always scrolled into view.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// # use iced_widget::tabs;
+/// use iced_widget::tabs::{Reorder, Tab};
+///
+/// struct State {
+///    documents: Vec<String>,
+///    active: usize,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(usize),
+///     Close(usize),
+///     Reorder(Reorder),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     tabs(state.documents.iter().map(Tab::new), state.active)
+///         .on_select(Message::Select)
+///         .on_close(Message::Close)
+///         .on_reorder(Message::Reorder)
+///         .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::Select(index) => {
+///             state.active = index;
+///         }
+///         Message::Close(index) => {
+///             let _ = state.documents.remove(index);
+///         }
+///         Message::Reorder(reorder) => {
+///             reorder.apply(&mut state.documents);
+///         }
+///     }
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    tabs: Vec<Tab>,
+    active: usize,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    icon_font: Option<Renderer::Font>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(Reorder) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Tabs<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of a [`Tab`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates new [`Tabs`] with the given [`Tab`]s and the index of the
+    /// active one.
+    pub fn new(tabs: impl IntoIterator<Item = Tab>, active: usize) -> Self {
+        Self {
+            tabs: tabs.into_iter().collect(),
+            active,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 2.0,
+            text_size: None,
+            font: None,
+            icon_font: None,
+            on_select: None,
+            on_close: None,
+            on_reorder: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of every [`Tab`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the [`Tab`]s.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`Tabs`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the labels of the [`Tabs`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the font used to draw the icons of the [`Tabs`].
+    pub fn icon_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.icon_font = Some(font.into());
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Tab`] is selected.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message that should be produced when the close button of a
+    /// [`Tab`] is pressed.
+    ///
+    /// If this method is not called, no close buttons will be shown.
+    pub fn on_close(
+        mut self,
+        on_close: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Enables dragging the [`Tab`]s to reorder them, producing the given
+    /// message when a [`Tab`] is dropped in a new position.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(Reorder) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Tabs`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn is_closable(&self, index: usize) -> bool {
+        self.on_close.is_some() && self.tabs[index].is_closable
+    }
+
+    /// Returns the bounds of the close button of the [`Tab`] with the given
+    /// bounds.
+    fn close_bounds(&self, bounds: Rectangle, size: f32) -> Rectangle {
+        Rectangle {
+            x: bounds.x + bounds.width - self.padding.right - size,
+            y: bounds.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    offset: f32,
+    content_width: f32,
+    active: Option<usize>,
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    grab: f32,
+    cursor: f32,
+    is_dragging: bool,
+}
+
+impl State {
+    /// Returns the bounds of the given tab, taking the scroll offset into
+    /// account.
+    fn bounds(&self, tab: Layout<'_>) -> Rectangle {
+        tab.bounds() - Vector::new(self.offset, 0.0)
+    }
+
+    fn scroll(&mut self, delta: f32, width: f32) {
+        self.offset = (self.offset - delta)
+            .min(self.content_width - width)
+            .max(0.0);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tabs<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let icon_font = self.icon_font.unwrap_or(font);
+        let line_height = text::LineHeight::default().to_absolute(size).0;
+        let height = line_height + self.padding.vertical();
+
+        let mut x = 0.0;
+
+        let tabs: Vec<_> = self
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(index, tab)| {
+                let icon = tab.icon.map_or(0.0, |icon| {
+                    measure::<Renderer>(&icon.to_string(), icon_font, size)
+                        + SPACING
+                });
+
+                let close = if self.is_closable(index) {
+                    SPACING + line_height
+                } else {
+                    0.0
+                };
+
+                let width = icon
+                    + measure::<Renderer>(&tab.label, font, size)
+                    + close
+                    + self.padding.horizontal();
+
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to(Point::new(x, 0.0));
+
+                x += width + self.spacing;
+
+                node
+            })
+            .collect();
+
+        let content_width = (x - self.spacing).max(0.0);
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(content_width, height),
+        );
+
+        state.content_width = content_width;
+        state.scroll(0.0, size.width);
+
+        if state.active != Some(self.active) {
+            if let Some(tab) = tabs.get(self.active) {
+                let bounds = tab.bounds();
+
+                if bounds.x < state.offset {
+                    state.offset = bounds.x;
+                } else if bounds.x + bounds.width > state.offset + size.width {
+                    state.offset = bounds.x + bounds.width - size.width;
+                }
+            }
+
+            state.active = Some(self.active);
+        }
+
+        layout::Node::with_children(size, tabs)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        // The dragged tab may have been removed in the meantime
+        if state
+            .drag
+            .is_some_and(|drag| drag.index >= layout.children().count())
+        {
+            state.drag = None;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let Some((index, tab)) = layout
+                    .children()
+                    .map(|tab| state.bounds(tab))
+                    .enumerate()
+                    .find(|(_, tab)| tab.contains(position))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let size = text::LineHeight::default()
+                    .to_absolute(
+                        self.text_size
+                            .unwrap_or_else(|| renderer.default_size()),
+                    )
+                    .0;
+
+                if self.is_closable(index)
+                    && self.close_bounds(tab, size).contains(position)
+                {
+                    if let Some(on_close) = &self.on_close {
+                        shell.publish(on_close(index));
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                if index != self.active {
+                    if let Some(on_select) = &self.on_select {
+                        shell.publish(on_select(index));
+                    }
+                }
+
+                if self.on_reorder.is_some() {
+                    state.drag = Some(Drag {
+                        index,
+                        origin: position.x,
+                        grab: position.x - tab.x,
+                        cursor: position.x,
+                        is_dragging: false,
+                    });
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(drag) = &mut state.drag {
+                    drag.cursor = position.x;
+
+                    if !drag.is_dragging
+                        && (position.x - drag.origin).abs()
+                            > DRAG_DEADBAND_DISTANCE
+                    {
+                        drag.is_dragging = true;
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(drag) = state.drag.take() {
+                    if drag.is_dragging {
+                        let to = drop_index(state, layout, drag)
+                            .unwrap_or(drag.index);
+
+                        if to != drag.index {
+                            if let Some(on_reorder) = &self.on_reorder {
+                                shell.publish(on_reorder(Reorder {
+                                    from: drag.index,
+                                    to,
+                                }));
+                            }
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if state.content_width > bounds.width
+                    && cursor.is_over(bounds) =>
+            {
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (x * 60.0, y * 60.0),
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                };
+
+                let delta = if x.abs() > y.abs() { x } else { y };
+
+                state.scroll(delta, bounds.width);
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return mouse::Interaction::default();
+        };
+
+        if layout
+            .children()
+            .any(|tab| state.bounds(tab).contains(position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let Some(clip_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let tabs: Vec<_> =
+            layout.children().map(|tab| state.bounds(tab)).collect();
+        let drag = state
+            .drag
+            .filter(|drag| drag.is_dragging && drag.index < tabs.len());
+
+        // While dragging, the rest of the tabs make room for the dragged one
+        let positions: Vec<_> = if let Some(drag) = drag {
+            let to = drop_index(state, layout, drag).unwrap_or(drag.index);
+
+            let mut order: Vec<_> = (0..tabs.len()).collect();
+            let dragged = order.remove(drag.index);
+            order.insert(to, dragged);
+
+            let mut positions = vec![0.0; tabs.len()];
+            let mut x = bounds.x - state.offset;
+
+            for index in order {
+                positions[index] = x;
+                x += tabs[index].width + self.spacing;
+            }
+
+            positions
+        } else {
+            tabs.iter().map(|tab| tab.x).collect()
+        };
+
+        let cursor = if drag.is_some() {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        renderer.with_layer(clip_bounds, |renderer| {
+            for (index, (tab, x)) in tabs.iter().zip(&positions).enumerate() {
+                if drag.is_some_and(|drag| drag.index == index) {
+                    continue;
+                }
+
+                self.draw_tab(
+                    renderer,
+                    theme,
+                    index,
+                    Rectangle { x: *x, ..*tab },
+                    cursor,
+                    clip_bounds,
+                );
+            }
+        });
+
+        if let Some((drag, tab)) =
+            drag.and_then(|drag| Some((drag, *tabs.get(drag.index)?)))
+        {
+            let x = (drag.cursor - drag.grab)
+                .max(bounds.x)
+                .min(bounds.x + bounds.width - tab.width);
+
+            renderer.with_layer(clip_bounds, |renderer| {
+                self.draw_tab(
+                    renderer,
+                    theme,
+                    drag.index,
+                    Rectangle { x, ..tab },
+                    cursor,
+                    clip_bounds,
+                );
+            });
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Tabs<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn draw_tab(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        index: usize,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        clip_bounds: Rectangle,
+    ) {
+        let tab = &self.tabs[index];
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let icon_font = self.icon_font.unwrap_or(font);
+        let line_height = text::LineHeight::default().to_absolute(size).0;
+
+        let status = if index == self.active {
+            Status::Active
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Inactive
+        };
+
+        let style = theme.style(&self.class, status);
+
+        if style.background.is_some() || style.border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        let mut x = bounds.x + self.padding.left;
+
+        if let Some(icon) = tab.icon {
+            let icon = icon.to_string();
+            let width = measure::<Renderer>(&icon, icon_font, size);
+
+            fill_text(
+                renderer,
+                icon,
+                icon_font,
+                size,
+                Point::new(x, bounds.center_y()),
+                alignment::Horizontal::Left,
+                style.text_color,
+                clip_bounds,
+            );
+
+            x += width + SPACING;
+        }
+
+        fill_text(
+            renderer,
+            tab.label.clone(),
+            font,
+            size,
+            Point::new(x, bounds.center_y()),
+            alignment::Horizontal::Left,
+            style.text_color,
+            clip_bounds,
+        );
+
+        if self.is_closable(index) {
+            let close = self.close_bounds(bounds, line_height);
+
+            let color = if cursor.is_over(close) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: close,
+                        border: border::rounded(line_height / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    style.close_hovered_background,
+                );
+
+                style.text_color
+            } else {
+                style.close_color
+            };
+
+            fill_text(
+                renderer,
+                CLOSE_ICON.to_owned(),
+                font,
+                size,
+                close.center(),
+                alignment::Horizontal::Center,
+                color,
+                clip_bounds,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tabs<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tabs: Tabs<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tabs)
+    }
+}
+
+/// Returns the index the dragged tab would be dropped at, if the tab still
+/// exists.
+fn drop_index(state: &State, layout: Layout<'_>, drag: Drag) -> Option<usize> {
+    let tabs: Vec<_> = layout.children().map(|tab| state.bounds(tab)).collect();
+    let center = drag.cursor - drag.grab + tabs.get(drag.index)?.width / 2.0;
+
+    Some(
+        tabs.iter()
+            .enumerate()
+            .filter(|(index, tab)| {
+                *index != drag.index && tab.center_x() < center
+            })
+            .count(),
+    )
+}
+
+fn measure<Renderer>(content: &str, font: Renderer::Font, size: Pixels) -> f32
+where
+    Renderer: text::Renderer,
+{
+    Renderer::Paragraph::with_text(Text {
+        content,
+        bounds: Size::INFINITY,
+        size,
+        line_height: text::LineHeight::default(),
+        font,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        shaping: text::Shaping::Advanced,
+        wrapping: text::Wrapping::default(),
+        ellipsis: text::Ellipsis::default(),
+        max_lines: None,
+    })
+    .min_width()
+}
+
+fn fill_text<Renderer>(
+    renderer: &mut Renderer,
+    content: String,
+    font: Renderer::Font,
+    size: Pixels,
+    position: Point,
+    horizontal_alignment: alignment::Horizontal,
+    color: Color,
+    clip_bounds: Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    let line_height = text::LineHeight::default();
+
+    renderer.fill_text(
+        Text {
+            content,
+            bounds: Size::new(f32::INFINITY, line_height.to_absolute(size).0),
+            size,
+            line_height,
+            font,
+            horizontal_alignment,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::default(),
+            ellipsis: text::Ellipsis::default(),
+            max_lines: None,
+        },
+        position,
+        color,
+        clip_bounds,
+    );
+}
+
+/// The possible status of a [`Tab`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Tab`] is the active one.
+    Active,
+    /// The [`Tab`] is not active and it is being hovered.
+    Hovered,
+    /// The [`Tab`] is not active.
+    Inactive,
+}
+
+/// The appearance of a [`Tab`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the tab, if any.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the tab.
+    pub text_color: Color,
+    /// The [`Border`] of the tab.
+    pub border: Border,
+    /// The [`Color`] of the close button.
+    pub close_color: Color,
+    /// The [`Background`] of the close button when hovered.
+    pub close_hovered_background: Background,
+}
+
+/// The theme catalog of [`Tabs`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for [`Tabs`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of [`Tabs`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let base = Style {
+        background: None,
+        text_color: palette.background.base.text.scale_alpha(0.7),
+        border: Border {
+            radius: border::Radius {
+                top_left: 4.0,
+                top_right: 4.0,
+                bottom_right: 0.0,
+                bottom_left: 0.0,
+            },
+            ..Border::default()
+        },
+        close_color: palette.background.base.text.scale_alpha(0.5),
+        close_hovered_background: palette.background.strong.color.into(),
+    };
+
+    match status {
+        Status::Active => Style {
+            background: Some(palette.background.base.color.into()),
+            text_color: palette.background.base.text,
+            border: Border {
+                width: 1.0,
+                color: palette.background.strong.color,
+                ..base.border
+            },
+            ..base
+        },
+        Status::Hovered => Style {
+            background: Some(palette.background.weak.color.into()),
+            text_color: palette.background.base.text,
+            ..base
+        },
+        Status::Inactive => base,
+    }
+}
@@ -1,16 +1,20 @@
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::event;
 use crate::core::layout;
 use crate::core::mouse;
+use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::{Paragraph, Span};
 use crate::core::widget::text::{
-    self, Catalog, LineHeight, Shaping, Style, StyleFn,
+    self, Catalog, Ellipsis, LineHeight, Selection, Shaping, Style, StyleFn,
+    Wrapping,
 };
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
-    self, Clipboard, Color, Element, Event, Layout, Length, Pixels, Rectangle,
-    Shell, Size, Widget,
+    self, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
 };
 
 use std::borrow::Cow;
@@ -36,8 +40,17 @@ pub struct Rich<
     font: Option<Renderer::Font>,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
+    wrapping: Wrapping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
+    is_selectable: bool,
     class: Theme::Class<'a>,
     on_link: Option<Box<dyn Fn(Link) -> Message + 'a>>,
+    on_link_hover: Option<Box<dyn Fn(Link) -> Message + 'a>>,
+    on_link_exit: Option<Box<dyn Fn(Link) -> Message + 'a>>,
+    on_link_right_press: Option<Box<dyn Fn(Link) -> Message + 'a>>,
+    inlines: Vec<Element<'a, Message, Theme, Renderer>>,
+    inline_spans: Vec<usize>,
 }
 
 impl<'a, Message, Link, Theme, Renderer>
@@ -58,8 +71,17 @@ where
             font: None,
             align_x: alignment::Horizontal::Left,
             align_y: alignment::Vertical::Top,
+            wrapping: Wrapping::default(),
+            ellipsis: Ellipsis::default(),
+            max_lines: None,
+            is_selectable: false,
             class: Theme::default(),
             on_link: None,
+            on_link_hover: None,
+            on_link_exit: None,
+            on_link_right_press: None,
+            inlines: Vec::new(),
+            inline_spans: Vec::new(),
         }
     }
 
@@ -127,6 +149,35 @@ where
         self
     }
 
+    /// Sets the [`Wrapping`] strategy of the [`Rich`] text.
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Sets the [`Ellipsis`] strategy of the [`Rich`] text, used to
+    /// truncate it when it overflows its bounds or its maximum amount of
+    /// lines.
+    pub fn ellipsis(mut self, ellipsis: Ellipsis) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Sets the maximum amount of lines of the [`Rich`] text.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Sets whether the [`Rich`] text can be selected and copied by the
+    /// user.
+    ///
+    /// By default, it cannot.
+    pub fn selectable(mut self, is_selectable: bool) -> Self {
+        self.is_selectable = is_selectable;
+        self
+    }
+
     /// Sets the default style of the [`Rich`] text.
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -152,7 +203,10 @@ where
     {
         let color = color.map(Into::into);
 
-        self.style(move |_theme| Style { color })
+        self.style(move |_theme| Style {
+            color,
+            ..Style::default()
+        })
     }
 
     /// Sets the message handler for link clicks on the [`Rich`] text.
@@ -170,6 +224,55 @@ where
         self
     }
 
+    /// Sets the message handler for when the mouse cursor enters a link of
+    /// the [`Rich`] text.
+    pub fn on_link_hover(
+        mut self,
+        on_link_hover: impl Fn(Link) -> Message + 'a,
+    ) -> Self {
+        self.on_link_hover = Some(Box::new(on_link_hover));
+        self
+    }
+
+    /// Sets the message handler for when the mouse cursor exits a link of
+    /// the [`Rich`] text.
+    pub fn on_link_exit(
+        mut self,
+        on_link_exit: impl Fn(Link) -> Message + 'a,
+    ) -> Self {
+        self.on_link_exit = Some(Box::new(on_link_exit));
+        self
+    }
+
+    /// Sets the message handler for right button presses on the links of
+    /// the [`Rich`] text.
+    pub fn on_link_right_press(
+        mut self,
+        on_link_right_press: impl Fn(Link) -> Message + 'a,
+    ) -> Self {
+        self.on_link_right_press = Some(Box::new(on_link_right_press));
+        self
+    }
+
+    fn has_link_handlers(&self) -> bool {
+        self.on_link.is_some()
+            || self.on_link_hover.is_some()
+            || self.on_link_exit.is_some()
+            || self.on_link_right_press.is_some()
+    }
+
+    fn hovered_link(
+        &self,
+        paragraph: &Renderer::Paragraph,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<&Link> {
+        let position = cursor.position_in(bounds)?;
+        let span = paragraph.hit_span(position)?;
+
+        self.spans.get(span)?.link.as_ref()
+    }
+
     /// Sets the default style class of the [`Rich`] text.
     #[cfg(feature = "advanced")]
     #[must_use]
@@ -186,6 +289,26 @@ where
         self.spans.to_mut().push(span.into());
         self
     }
+
+    /// Adds a new inline element to the [`Rich`] text, flowing along with
+    /// the rest of its [`Span`]s.
+    ///
+    /// The element is laid out within the bounds of the [`Rich`] text and
+    /// its size is reserved in the layout of the text. It is vertically
+    /// centered in its line.
+    pub fn push_inline(
+        mut self,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.inline_spans.push(self.spans.len());
+        self.spans.to_mut().push(
+            Span::new(Renderer::CHECKMARK_ICON.to_string())
+                .font(Renderer::ICON_FONT)
+                .color(Color::TRANSPARENT),
+        );
+        self.inlines.push(element.into());
+        self
+    }
 }
 
 impl<'a, Message, Link, Theme, Renderer> Default
@@ -202,14 +325,18 @@ where
 
 struct State<Link, P: Paragraph> {
     spans: Vec<Span<'static, Link, P::Font>>,
-    span_pressed: Option<usize>,
+    link_pressed: Option<Link>,
+    link_hovered: Option<Link>,
+    selection: Selection,
     paragraph: P,
+    visible_inlines: Vec<bool>,
+    placeholder_advance: Option<f32>,
 }
 
 impl<'a, Message, Link, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Rich<'a, Message, Link, Theme, Renderer>
 where
-    Link: Clone + 'static,
+    Link: Clone + PartialEq + 'static,
     Theme: Catalog,
     Renderer: core::text::Renderer,
 {
@@ -220,11 +347,23 @@ where
     fn state(&self) -> tree::State {
         tree::State::new(State::<Link, _> {
             spans: Vec::new(),
-            span_pressed: None,
+            link_pressed: None,
+            link_hovered: None,
+            selection: Selection::default(),
             paragraph: Renderer::Paragraph::default(),
+            visible_inlines: Vec::new(),
+            placeholder_advance: None,
         })
     }
 
+    fn children(&self) -> Vec<Tree> {
+        self.inlines.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.inlines);
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -238,20 +377,113 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(
-            tree.state
-                .downcast_mut::<State<Link, Renderer::Paragraph>>(),
+        let inlines: Vec<_> = self
+            .inlines
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(inline, tree)| {
+                inline.as_widget().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, limits.max()),
+                )
+            })
+            .collect();
+
+        let state = tree
+            .state
+            .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+        // Spans of a hovered link may change their color
+        let link_hovered = state.link_hovered.clone().filter(|link| {
+            self.spans.iter().any(|span| {
+                span.hovered_color.is_some() && span.link.as_ref() == Some(link)
+            })
+        });
+
+        let spans = if inlines.is_empty() && link_hovered.is_none() {
+            Cow::Borrowed(self.spans.as_ref())
+        } else {
+            let mut spans = self.spans.to_vec();
+
+            for span in &mut spans {
+                if link_hovered.is_some() && span.link == link_hovered {
+                    span.color = span.hovered_color.or(span.color);
+                }
+            }
+
+            let advance = *state
+                .placeholder_advance
+                .get_or_insert_with(placeholder_advance::<Renderer>);
+
+            let line_height = self.line_height.to_absolute(
+                self.size.unwrap_or_else(|| renderer.default_size()),
+            );
+
+            for (index, inline) in self.inline_spans.iter().zip(&inlines) {
+                let span = &mut spans[*index];
+                let size = inline.size();
+
+                // The placeholder is scaled to be as wide as the element,
+                // while its line is made tall enough to fit it
+                span.size =
+                    Some(Pixels((size.width / advance).max(f32::MIN_POSITIVE)));
+                span.line_height = Some(LineHeight::Absolute(Pixels(
+                    size.height.max(line_height.0),
+                )));
+            }
+
+            Cow::Owned(spans)
+        };
+
+        let node = layout(
+            state,
             renderer,
             limits,
             self.width,
             self.height,
-            self.spans.as_ref(),
+            &spans,
             self.line_height,
             self.size,
             self.font,
             self.align_x,
             self.align_y,
-        )
+            self.wrapping,
+            self.ellipsis,
+            self.max_lines,
+        );
+
+        let origin =
+            text::origin(Rectangle::with_size(node.size()), &state.paragraph)
+                - Point::ORIGIN;
+
+        state.visible_inlines.clear();
+
+        let inlines = self
+            .inline_spans
+            .iter()
+            .zip(inlines)
+            .map(|(index, inline)| {
+                // The space of the element may have been truncated
+                let Some(bounds) =
+                    state.paragraph.span_bounds(*index).first().copied()
+                else {
+                    state.visible_inlines.push(false);
+                    return inline;
+                };
+
+                state.visible_inlines.push(true);
+
+                let size = inline.size();
+
+                inline.move_to(
+                    Point::new(bounds.x, bounds.center_y() - size.height / 2.0)
+                        + origin,
+                )
+            })
+            .collect();
+
+        layout::Node::with_children(node.size(), inlines)
     }
 
     fn draw(
@@ -261,7 +493,7 @@ where
         theme: &Theme,
         defaults: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: mouse::Cursor,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         let state = tree
@@ -269,6 +501,40 @@ where
             .downcast_ref::<State<Link, Renderer::Paragraph>>();
 
         let style = theme.style(&self.class);
+        let translation =
+            text::origin(layout.bounds(), &state.paragraph) - Point::ORIGIN;
+
+        for (index, span) in self.spans.iter().enumerate() {
+            let Some(highlight) = span.highlight else {
+                continue;
+            };
+
+            for bounds in state.paragraph.span_bounds(index) {
+                let bounds = Rectangle {
+                    x: bounds.x - span.padding.left,
+                    y: bounds.y - span.padding.top,
+                    width: bounds.width + span.padding.horizontal(),
+                    height: bounds.height + span.padding.vertical(),
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: bounds + translation,
+                        border: highlight.border,
+                        ..renderer::Quad::default()
+                    },
+                    highlight.background,
+                );
+            }
+        }
+
+        state.selection.draw(
+            renderer,
+            defaults,
+            layout,
+            &state.paragraph,
+            style,
+        );
 
         text::draw(
             renderer,
@@ -278,6 +544,120 @@ where
             style,
             viewport,
         );
+
+        for (index, span) in self.spans.iter().enumerate() {
+            let is_hovered =
+                state.link_hovered.is_some() && span.link == state.link_hovered;
+
+            if !span.underline && !span.strikethrough && !is_hovered {
+                continue;
+            }
+
+            let size = span
+                .size
+                .or(self.size)
+                .unwrap_or_else(|| renderer.default_size());
+
+            let color = span
+                .decoration_color
+                .or(span.color)
+                .or(style.color)
+                .unwrap_or(defaults.text_color);
+
+            let thickness = (size.0 / 16.0).max(1.0);
+
+            for (bounds, baseline) in state
+                .paragraph
+                .span_bounds(index)
+                .into_iter()
+                .zip(state.paragraph.span_baselines(index))
+            {
+                let underline = if span.underline {
+                    Some(color)
+                } else if is_hovered {
+                    Some(style.hovered_link.unwrap_or(color))
+                } else {
+                    None
+                };
+
+                // Underlines sit just below the baseline, while strikethroughs
+                // cross the lowercase letters halfway up
+                let lines = [
+                    underline.map(|color| (baseline + thickness, color)),
+                    span.strikethrough
+                        .then_some((baseline - size.0 * 0.3, color)),
+                ];
+
+                for (y, color) in lines.into_iter().flatten() {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x,
+                                y: y.round(),
+                                width: bounds.width,
+                                height: thickness,
+                            } + translation,
+                            ..renderer::Quad::default()
+                        },
+                        color,
+                    );
+                }
+            }
+        }
+
+        for (((inline, tree), layout), is_visible) in self
+            .inlines
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .zip(&state.visible_inlines)
+        {
+            if *is_visible {
+                inline.as_widget().draw(
+                    tree, renderer, theme, defaults, layout, cursor, viewport,
+                );
+            }
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        let text: String = self
+            .spans
+            .iter()
+            .enumerate()
+            .filter(|(index, _span)| !self.inline_spans.contains(index))
+            .map(|(_index, span)| span.text.as_ref())
+            .collect();
+
+        operation.text(None, layout.bounds(), &text);
+
+        let node = || {
+            accessibility::Node::new(accessibility::Role::Label)
+                .label(text.as_str())
+        };
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+
+        if !self.inlines.is_empty() {
+            operation.container(None, layout.bounds(), &mut |operation| {
+                for ((inline, tree), layout) in self
+                    .inlines
+                    .iter()
+                    .zip(&mut tree.children)
+                    .zip(layout.children())
+                {
+                    inline
+                        .as_widget()
+                        .operate(tree, layout, renderer, operation);
+                }
+            });
+        }
     }
 
     fn on_event(
@@ -286,24 +666,165 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
-        let Some(on_link_click) = self.on_link.as_ref() else {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let visible_inlines = &state
+            .downcast_ref::<State<Link, Renderer::Paragraph>>()
+            .visible_inlines;
+
+        let status = self
+            .inlines
+            .iter_mut()
+            .zip(children)
+            .zip(layout.children())
+            .zip(visible_inlines)
+            .filter(|(_, is_visible)| **is_visible)
+            .map(|(((inline, tree), layout), _)| {
+                inline.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if self.is_selectable {
+            let state = tree
+                .state
+                .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+            let is_link_pressed = matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            ) && self.on_link.is_some()
+                && cursor
+                    .position_in(layout.bounds())
+                    .and_then(|position| state.paragraph.hit_span(position))
+                    .and_then(|span| self.spans.get(span))
+                    .is_some_and(|span| span.link.is_some());
+
+            if !is_link_pressed && state.selection.handles(&event) {
+                // Inline elements are selected as object replacement
+                // characters, which are left out when copying
+                let text: String = state
+                    .spans
+                    .iter()
+                    .enumerate()
+                    .map(|(index, span)| {
+                        if self.inline_spans.contains(&index) {
+                            OBJECT_REPLACEMENT
+                        } else {
+                            span.text.as_ref()
+                        }
+                    })
+                    .collect();
+
+                if state.selection.update(
+                    &event,
+                    layout.bounds(),
+                    cursor,
+                    &state.paragraph,
+                    &text,
+                    clipboard,
+                ) == event::Status::Captured
+                {
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        if !self.has_link_handlers() {
             return event::Status::Ignored;
-        };
+        }
 
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+            Event::Mouse(
+                mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft,
+            ) => {
+                let state = tree
+                    .state
+                    .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+                let link_hovered = self
+                    .hovered_link(&state.paragraph, layout.bounds(), cursor)
+                    .cloned();
+
+                // Links may be split across many spans, so we compare the
+                // links themselves
+                if state.link_hovered != link_hovered {
+                    let link_exited = std::mem::replace(
+                        &mut state.link_hovered,
+                        link_hovered.clone(),
+                    );
+
+                    if self
+                        .spans
+                        .iter()
+                        .any(|span| span.hovered_color.is_some())
+                    {
+                        shell.invalidate_layout();
+                    }
+
+                    if let (Some(on_link_exit), Some(link)) =
+                        (&self.on_link_exit, link_exited)
+                    {
+                        shell.publish(on_link_exit(link));
+                    }
+
+                    if let (Some(on_link_hover), Some(link)) =
+                        (&self.on_link_hover, link_hovered)
+                    {
+                        shell.publish(on_link_hover(link));
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                let state = tree
+                    .state
+                    .downcast_ref::<State<Link, Renderer::Paragraph>>();
+
+                if let (Some(on_link_right_press), Some(link)) = (
+                    &self.on_link_right_press,
+                    self.hovered_link(
+                        &state.paragraph,
+                        layout.bounds(),
+                        cursor,
+                    ),
+                ) {
+                    shell.publish(on_link_right_press(link.clone()));
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if self.on_link.is_some() =>
+            {
                 if let Some(position) = cursor.position_in(layout.bounds()) {
                     let state = tree
                         .state
                         .downcast_mut::<State<Link, Renderer::Paragraph>>();
 
                     if let Some(span) = state.paragraph.hit_span(position) {
-                        state.span_pressed = Some(span);
+                        state.link_pressed = self
+                            .spans
+                            .get(span)
+                            .and_then(|span| span.link.clone());
 
                         return event::Status::Captured;
                     }
@@ -314,23 +835,16 @@ where
                     .state
                     .downcast_mut::<State<Link, Renderer::Paragraph>>();
 
-                if let Some(span_pressed) = state.span_pressed {
-                    state.span_pressed = None;
-
-                    if let Some(position) = cursor.position_in(layout.bounds())
+                if let (Some(on_link_click), Some(link_pressed)) =
+                    (&self.on_link, state.link_pressed.take())
+                {
+                    if self.hovered_link(
+                        &state.paragraph,
+                        layout.bounds(),
+                        cursor,
+                    ) == Some(&link_pressed)
                     {
-                        match state.paragraph.hit_span(position) {
-                            Some(span) if span == span_pressed => {
-                                if let Some(link) = self
-                                    .spans
-                                    .get(span)
-                                    .and_then(|span| span.link.clone())
-                                {
-                                    shell.publish(on_link_click(link));
-                                }
-                            }
-                            _ => {}
-                        }
+                        shell.publish(on_link_click(link_pressed));
                     }
                 }
             }
@@ -345,11 +859,39 @@ where
         tree: &Tree,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _viewport: &Rectangle,
-        _renderer: &Renderer,
+        viewport: &Rectangle,
+        renderer: &Renderer,
     ) -> mouse::Interaction {
-        if self.on_link.is_none() {
-            return mouse::Interaction::None;
+        let visible_inlines = &tree
+            .state
+            .downcast_ref::<State<Link, Renderer::Paragraph>>()
+            .visible_inlines;
+
+        let interaction = self
+            .inlines
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .zip(visible_inlines)
+            .filter(|(_, is_visible)| **is_visible)
+            .map(|(((inline, tree), layout), _)| {
+                inline
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction != mouse::Interaction::default() {
+            return interaction;
+        }
+
+        if !self.has_link_handlers() {
+            return if self.is_selectable && cursor.is_over(layout.bounds()) {
+                mouse::Interaction::Text
+            } else {
+                mouse::Interaction::None
+            };
         }
 
         if let Some(position) = cursor.position_in(layout.bounds()) {
@@ -366,10 +908,73 @@ where
                     return mouse::Interaction::Pointer;
                 }
             }
+
+            if self.is_selectable {
+                return mouse::Interaction::Text;
+            }
         }
 
         mouse::Interaction::None
     }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.inlines,
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+/// The object replacement character standing for an inline element in the
+/// text of a selection.
+///
+/// It must be as long as the [`Renderer::CHECKMARK_ICON`] reserving the space
+/// of the element in the paragraph, so the offsets of both texts match.
+///
+/// [`Renderer::CHECKMARK_ICON`]: core::text::Renderer::CHECKMARK_ICON
+const OBJECT_REPLACEMENT: &str = "\u{FFFC}";
+
+/// Returns the advance per pixel of font size of the glyph reserving the
+/// space of an inline element.
+///
+/// Any glyph of the built-in icon font has a known, nonzero advance; unlike
+/// the glyphs of the fonts of the system, which may be missing.
+fn placeholder_advance<Renderer>() -> f32
+where
+    Renderer: core::text::Renderer,
+{
+    const SIZE: f32 = 100.0;
+
+    let paragraph = Renderer::Paragraph::with_text(core::Text {
+        content: &Renderer::CHECKMARK_ICON.to_string(),
+        bounds: Size::INFINITY,
+        size: Pixels(SIZE),
+        line_height: LineHeight::default(),
+        font: Renderer::ICON_FONT,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        shaping: Shaping::Advanced,
+        wrapping: Wrapping::None,
+        ellipsis: Ellipsis::None,
+        max_lines: None,
+    });
+
+    let advance = paragraph.min_bounds().width / SIZE;
+
+    if advance > 0.0 {
+        advance
+    } else {
+        1.0
+    }
 }
 
 fn layout<Link, Renderer>(
@@ -384,6 +989,9 @@ fn layout<Link, Renderer>(
     font: Option<Renderer::Font>,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    wrapping: Wrapping,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
 ) -> layout::Node
 where
     Link: Clone,
@@ -404,6 +1012,9 @@ where
             horizontal_alignment,
             vertical_alignment,
             shaping: Shaping::Advanced,
+            wrapping,
+            ellipsis,
+            max_lines,
         };
 
         if state.spans != spans {
@@ -420,6 +1031,9 @@ where
                 horizontal_alignment,
                 vertical_alignment,
                 shaping: Shaping::Advanced,
+                wrapping,
+                ellipsis,
+                max_lines,
             }) {
                 core::text::Difference::None => {}
                 core::text::Difference::Bounds => {
@@ -459,7 +1073,7 @@ impl<'a, Message, Link, Theme, Renderer>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a,
-    Link: Clone + 'static,
+    Link: Clone + PartialEq + 'static,
     Theme: Catalog + 'a,
     Renderer: core::text::Renderer + 'a,
 {
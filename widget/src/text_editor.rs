@@ -661,13 +661,13 @@ where
     fn operate(
         &self,
         tree: &mut widget::Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn widget::Operation<()>,
     ) {
         let state = tree.state.downcast_mut::<State<Highlighter>>();
 
-        operation.focusable(state, None);
+        operation.focusable_with_bounds(state, None, layout.bounds());
     }
 }
 
@@ -796,6 +796,15 @@ impl Update {
                         {
                             return Some(Self::Action(Action::SelectAll));
                         }
+                        keyboard::Key::Character("z" | "Z")
+                            if modifiers.command() =>
+                        {
+                            return action(if modifiers.shift() {
+                                Action::Redo
+                            } else {
+                                Action::Undo
+                            });
+                        }
                         _ => {}
                     }
 
@@ -2,6 +2,7 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 mod editor;
+mod history;
 mod value;
 
 pub mod cursor;
@@ -10,8 +11,11 @@ pub use cursor::Cursor;
 pub use value::Value;
 
 use editor::Editor;
+use history::History;
 
+use crate::core::accessibility;
 use crate::core::alignment;
+use crate::core::animation::Interpolable;
 use crate::core::clipboard::{self, Clipboard};
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
@@ -33,6 +37,7 @@ use crate::core::{
 };
 use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
+use crate::transition::Transition;
 
 /// A field that can be filled with text.
 ///
@@ -78,6 +83,7 @@ pub struct TextInput<
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
     icon: Option<Icon<Renderer::Font>>,
+    transition: Option<Duration>,
     class: Theme::Class<'a>,
 }
 
@@ -107,6 +113,7 @@ where
             on_paste: None,
             on_submit: None,
             icon: None,
+            transition: None,
             class: Theme::default(),
         }
     }
@@ -193,6 +200,15 @@ where
         self
     }
 
+    /// Sets the duration of the transition between the styles of the
+    /// [`TextInput`] when its [`Status`] changes.
+    ///
+    /// By default, the style changes instantly.
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -210,6 +226,11 @@ where
         self
     }
 
+    /// Replaces the [`Value`] of the [`TextInput`].
+    pub(crate) fn set_value(&mut self, value: Value) {
+        self.value = value;
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -240,6 +261,9 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
             shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::default(),
+            ellipsis: text::Ellipsis::default(),
+            max_lines: None,
         };
 
         state.placeholder.update(placeholder_text);
@@ -264,6 +288,9 @@ where
                 horizontal_alignment: alignment::Horizontal::Center,
                 vertical_alignment: alignment::Vertical::Center,
                 shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::default(),
+                ellipsis: text::Ellipsis::default(),
+                max_lines: None,
             };
 
             state.icon.update(icon_text);
@@ -308,6 +335,23 @@ where
         }
     }
 
+    fn status(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Status {
+        if self.on_input.is_none() {
+            Status::Disabled
+        } else if state.is_focused() {
+            Status::Focused
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+
     /// Draws the [`TextInput`] with the given [`Renderer`], overriding its
     /// [`Value`] if provided.
     ///
@@ -324,7 +368,6 @@ where
     ) {
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let value = value.unwrap_or(&self.value);
-        let is_disabled = self.on_input.is_none();
 
         let secure_value = self.is_secure.then(|| value.secure());
         let value = secure_value.as_ref().unwrap_or(value);
@@ -334,20 +377,15 @@ where
         let mut children_layout = layout.children();
         let text_bounds = children_layout.next().unwrap().bounds();
 
-        let is_mouse_over = cursor.is_over(bounds);
-
-        let status = if is_disabled {
-            Status::Disabled
-        } else if state.is_focused() {
-            Status::Focused
-        } else if is_mouse_over {
-            Status::Hovered
+        let status = self.status(state, layout, cursor);
+        let style = if self.transition.is_some() {
+            state
+                .transition
+                .style(status, |status| theme.style(&self.class, status))
         } else {
-            Status::Active
+            theme.style(&self.class, status)
         };
 
-        let style = theme.style(&self.class, status);
-
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
@@ -540,14 +578,50 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<()>,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
-        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        let is_focused = state.is_focused();
+
+        let node = || {
+            let value = if self.is_secure {
+                self.value.secure()
+            } else {
+                self.value.clone()
+            };
+
+            accessibility::Node::new(accessibility::Role::TextInput)
+                .label(self.placeholder.clone())
+                .value(value.to_string())
+                .focused(is_focused)
+                .disabled(self.on_input.is_none())
+                .action(accessibility::Action::Focus)
+                .action(accessibility::Action::SetValue)
+        };
+
+        // The text input state is reported as the contents of its node, so
+        // assistive technologies can operate on it
+        operation.accessible(
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+            &node,
+            &mut |operation| {
+                operation.focusable_with_bounds(
+                    state,
+                    self.id.as_ref().map(|id| &id.0),
+                    layout.bounds(),
+                );
+
+                operation.text_input_with_bounds(
+                    state,
+                    self.id.as_ref().map(|id| &id.0),
+                    layout.bounds(),
+                );
+            },
+        );
     }
 
     fn on_event(
@@ -561,6 +635,34 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        // A value set by an operation replaces the current one as a single
+        // edit, once the widget gets a chance to publish it
+        if let Some(value) = state::<Renderer>(tree).pending_value.take() {
+            if let Some(on_input) = &self.on_input {
+                let state = state::<Renderer>(tree);
+
+                state.cursor.select_all(&self.value);
+                state.history.edit(
+                    history::Kind::Other,
+                    &mut self.value,
+                    &mut state.cursor,
+                    |mut editor| editor.paste(Value::new(&value)),
+                );
+
+                shell.publish(on_input(self.value.to_string()));
+
+                replace_paragraph(
+                    renderer,
+                    state,
+                    layout,
+                    &self.value,
+                    self.font,
+                    self.size,
+                    self.line_height,
+                );
+            }
+        }
+
         let update_cache = |state, value| {
             replace_paragraph(
                 renderer,
@@ -742,11 +844,14 @@ where
                                 );
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.delete();
+                            state.history.edit(
+                                history::Kind::Other,
+                                &mut self.value,
+                                &mut state.cursor,
+                                |mut editor| editor.delete(),
+                            );
 
-                            let message = (on_input)(editor.contents());
+                            let message = (on_input)(self.value.to_string());
                             shell.publish(message);
 
                             update_cache(state, &self.value);
@@ -771,15 +876,19 @@ where
                                 }
                             };
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
+                            state.history.edit(
+                                history::Kind::Other,
+                                &mut self.value,
+                                &mut state.cursor,
+                                |mut editor| editor.paste(content.clone()),
+                            );
 
-                            editor.paste(content.clone());
+                            let contents = self.value.to_string();
 
                             let message = if let Some(paste) = &self.on_paste {
-                                (paste)(editor.contents())
+                                (paste)(contents)
                             } else {
-                                (on_input)(editor.contents())
+                                (on_input)(contents)
                             };
                             shell.publish(message);
 
@@ -796,6 +905,30 @@ where
 
                             return event::Status::Captured;
                         }
+                        keyboard::Key::Character("z" | "Z")
+                            if state.keyboard_modifiers.command() =>
+                        {
+                            let is_changed = if state.keyboard_modifiers.shift()
+                            {
+                                state
+                                    .history
+                                    .redo(&mut self.value, &mut state.cursor)
+                            } else {
+                                state
+                                    .history
+                                    .undo(&mut self.value, &mut state.cursor)
+                            };
+
+                            if is_changed {
+                                let message =
+                                    (on_input)(self.value.to_string());
+                                shell.publish(message);
+
+                                update_cache(state, &self.value);
+                            }
+
+                            return event::Status::Captured;
+                        }
                         _ => {}
                     }
 
@@ -805,12 +938,14 @@ where
                         if let Some(c) =
                             text.chars().next().filter(|c| !c.is_control())
                         {
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-
-                            editor.insert(c);
-
-                            let message = (on_input)(editor.contents());
+                            state.history.edit(
+                                history::Kind::Insert(c),
+                                &mut self.value,
+                                &mut state.cursor,
+                                |mut editor| editor.insert(c),
+                            );
+
+                            let message = (on_input)(self.value.to_string());
                             shell.publish(message);
 
                             focus.updated_at = Instant::now();
@@ -842,11 +977,14 @@ where
                                 }
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.backspace();
+                            state.history.edit(
+                                history::Kind::Backspace,
+                                &mut self.value,
+                                &mut state.cursor,
+                                |mut editor| editor.backspace(),
+                            );
 
-                            let message = (on_input)(editor.contents());
+                            let message = (on_input)(self.value.to_string());
                             shell.publish(message);
 
                             update_cache(state, &self.value);
@@ -869,11 +1007,14 @@ where
                                 }
                             }
 
-                            let mut editor =
-                                Editor::new(&mut self.value, &mut state.cursor);
-                            editor.delete();
+                            state.history.edit(
+                                history::Kind::Delete,
+                                &mut self.value,
+                                &mut state.cursor,
+                                |mut editor| editor.delete(),
+                            );
 
-                            let message = (on_input)(editor.contents());
+                            let message = (on_input)(self.value.to_string());
                             shell.publish(message);
 
                             update_cache(state, &self.value);
@@ -1016,15 +1157,29 @@ where
                 let state = state::<Renderer>(tree);
 
                 if let Some(focus) = &mut state.is_focused {
+                    let now = Instant::now();
+
                     focus.is_window_focused = true;
-                    focus.updated_at = Instant::now();
+                    focus.updated_at = now;
+                    focus.now = now;
 
-                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                    // The cursor blinks back into view right away
+                    shell.request_redraw(window::RedrawRequest::At(now));
                 }
             }
             Event::Window(window::Event::RedrawRequested(now)) => {
                 let state = state::<Renderer>(tree);
 
+                if let Some(duration) = self.transition {
+                    let status = self.status(state, layout, cursor);
+
+                    if let Some(request) =
+                        state.transition.update(status, duration, now)
+                    {
+                        shell.request_redraw(request);
+                    }
+                }
+
                 if let Some(focus) = &mut state.is_focused {
                     if focus.is_window_focused {
                         focus.now = now;
@@ -1176,6 +1331,15 @@ pub fn select_all<T>(id: Id) -> Task<T> {
     task::effect(Action::widget(operation::text_input::select_all(id.0)))
 }
 
+/// Produces a [`Task`] that clears the undo and redo history of the [`TextInput`] with the
+/// given [`Id`].
+///
+/// This is useful when the application replaces the value of the [`TextInput`], since
+/// undoing would otherwise restore the previous edits.
+pub fn clear_history<T>(id: Id) -> Task<T> {
+    task::effect(Action::widget(operation::text_input::clear_history(id.0)))
+}
+
 /// The state of a [`TextInput`].
 #[derive(Debug, Default, Clone)]
 pub struct State<P: text::Paragraph> {
@@ -1188,6 +1352,9 @@ pub struct State<P: text::Paragraph> {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    transition: Transition<Status>,
+    history: History,
+    pending_value: Option<String>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1222,6 +1389,9 @@ impl<P: text::Paragraph> State<P> {
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            transition: Transition::default(),
+            history: History::default(),
+            pending_value: None,
         }
     }
 
@@ -1272,6 +1442,11 @@ impl<P: text::Paragraph> State<P> {
     pub fn select_all(&mut self) {
         self.cursor.select_range(0, usize::MAX);
     }
+
+    /// Forgets the undo and redo history of the [`TextInput`].
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
 }
 
 impl<P: text::Paragraph> operation::Focusable for State<P> {
@@ -1304,6 +1479,14 @@ impl<P: text::Paragraph> operation::TextInput for State<P> {
     fn select_all(&mut self) {
         State::select_all(self);
     }
+
+    fn clear_history(&mut self) {
+        State::clear_history(self);
+    }
+
+    fn set_value(&mut self, value: &str) {
+        self.pending_value = Some(value.to_owned());
+    }
 }
 
 fn offset<P: text::Paragraph>(
@@ -1397,6 +1580,9 @@ fn replace_paragraph<Renderer>(
         horizontal_alignment: alignment::Horizontal::Left,
         vertical_alignment: alignment::Vertical::Top,
         shaping: text::Shaping::Advanced,
+        wrapping: text::Wrapping::default(),
+        ellipsis: text::Ellipsis::default(),
+        max_lines: None,
     });
 }
 
@@ -1432,6 +1618,21 @@ pub struct Style {
     pub selection: Color,
 }
 
+impl Interpolable for Style {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, ratio),
+            border: self.border.interpolate(&other.border, ratio),
+            icon: self.icon.interpolate(&other.icon, ratio),
+            placeholder: self
+                .placeholder
+                .interpolate(&other.placeholder, ratio),
+            value: self.value.interpolate(&other.value, ratio),
+            selection: self.selection.interpolate(&other.selection, ratio),
+        }
+    }
+}
+
 /// The theme catalog of a [`TextInput`].
 pub trait Catalog: Sized {
     /// The item class of the [`Catalog`].
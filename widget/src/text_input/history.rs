@@ -0,0 +1,243 @@
+use crate::text_input::{Cursor, Editor, Value};
+
+/// The undo and redo stacks of a text input.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    last: Option<(Kind, Snapshot)>,
+}
+
+/// The kind of an edit, used to merge consecutive edits into a single
+/// undo step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Insert(char),
+    Backspace,
+    Delete,
+    Other,
+}
+
+#[derive(Debug, Clone)]
+struct Snapshot {
+    value: Value,
+    cursor: Cursor,
+}
+
+impl History {
+    /// The maximum amount of edits that can be undone.
+    const LIMIT: usize = 100;
+
+    /// Performs an edit with an [`Editor`] and records it.
+    ///
+    /// Consecutive edits of the same [`Kind`] are merged into a single undo
+    /// step, as long as the value and the cursor have not changed in between.
+    /// Edits replacing a selection always start a new step.
+    pub fn edit(
+        &mut self,
+        kind: Kind,
+        value: &mut Value,
+        cursor: &mut Cursor,
+        f: impl FnOnce(Editor<'_>),
+    ) {
+        let kind = if cursor.selection(value).is_some() {
+            Kind::Other
+        } else {
+            kind
+        };
+
+        let before = Snapshot {
+            value: value.clone(),
+            cursor: *cursor,
+        };
+
+        f(Editor::new(value, cursor));
+
+        if *value == before.value {
+            return;
+        }
+
+        let is_continuation = match &self.last {
+            Some((last_kind, last)) => {
+                last_kind.merges_with(kind)
+                    && last.value == before.value
+                    && last.cursor == before.cursor
+            }
+            None => false,
+        };
+
+        if !is_continuation {
+            if self.undo.len() == Self::LIMIT {
+                let _ = self.undo.remove(0);
+            }
+
+            self.undo.push(before);
+        }
+
+        self.redo.clear();
+        self.last = Some((
+            kind,
+            Snapshot {
+                value: value.clone(),
+                cursor: *cursor,
+            },
+        ));
+    }
+
+    /// Restores the value and the cursor before the last undo step.
+    ///
+    /// Returns `true` if there was anything to undo.
+    pub fn undo(&mut self, value: &mut Value, cursor: &mut Cursor) -> bool {
+        let Some(snapshot) = self.undo.pop() else {
+            return false;
+        };
+
+        self.redo.push(restore(snapshot, value, cursor));
+        self.last = None;
+
+        true
+    }
+
+    /// Restores the value and the cursor after the last undone step.
+    ///
+    /// Returns `true` if there was anything to redo.
+    pub fn redo(&mut self, value: &mut Value, cursor: &mut Cursor) -> bool {
+        let Some(snapshot) = self.redo.pop() else {
+            return false;
+        };
+
+        self.undo.push(restore(snapshot, value, cursor));
+        self.last = None;
+
+        true
+    }
+
+    /// Forgets every recorded edit.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl Kind {
+    fn merges_with(self, next: Self) -> bool {
+        match (self, next) {
+            (Kind::Insert(a), Kind::Insert(b)) => {
+                a.is_whitespace() == b.is_whitespace()
+            }
+            (Kind::Backspace, Kind::Backspace) => true,
+            (Kind::Delete, Kind::Delete) => true,
+            _ => false,
+        }
+    }
+}
+
+fn restore(
+    snapshot: Snapshot,
+    value: &mut Value,
+    cursor: &mut Cursor,
+) -> Snapshot {
+    Snapshot {
+        value: std::mem::replace(value, snapshot.value),
+        cursor: std::mem::replace(cursor, snapshot.cursor),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_text(
+        history: &mut History,
+        value: &mut Value,
+        cursor: &mut Cursor,
+        text: &str,
+    ) {
+        for c in text.chars() {
+            history.edit(Kind::Insert(c), value, cursor, |mut editor| {
+                editor.insert(c);
+            });
+        }
+    }
+
+    #[test]
+    fn undo_and_redo() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        type_text(&mut history, &mut value, &mut cursor, "hello world");
+
+        assert!(history.undo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "hello ");
+        assert_eq!(cursor, {
+            let mut cursor = Cursor::default();
+            cursor.move_to(6);
+            cursor
+        });
+
+        assert!(history.undo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "hello");
+
+        assert!(history.redo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "hello ");
+
+        assert!(history.redo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "hello world");
+        assert!(!history.redo(&mut value, &mut cursor));
+    }
+
+    #[test]
+    fn redo_is_cleared_after_an_edit() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        type_text(&mut history, &mut value, &mut cursor, "ab");
+
+        assert!(history.undo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "");
+
+        type_text(&mut history, &mut value, &mut cursor, "c");
+
+        assert!(!history.redo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "c");
+    }
+
+    #[test]
+    fn keeps_a_limited_amount_of_edits() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        for _ in 0..History::LIMIT + 50 {
+            history.edit(Kind::Other, &mut value, &mut cursor, |mut editor| {
+                editor.insert('a');
+            });
+        }
+
+        let mut undone = 0;
+
+        while history.undo(&mut value, &mut cursor) {
+            undone += 1;
+        }
+
+        assert_eq!(undone, History::LIMIT);
+        assert_eq!(value.len(), 50);
+    }
+
+    #[test]
+    fn clear_forgets_every_edit() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        type_text(&mut history, &mut value, &mut cursor, "ab cd");
+        assert!(history.undo(&mut value, &mut cursor));
+
+        history.clear();
+
+        assert!(!history.undo(&mut value, &mut cursor));
+        assert!(!history.redo(&mut value, &mut cursor));
+        assert_eq!(value.to_string(), "ab ");
+    }
+}
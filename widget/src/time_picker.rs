@@ -0,0 +1,786 @@
+//! Pick a time of the day from a panel anchored to a trigger element.
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
+};
+use crate::picker::{self, fill_text, Action, Popup};
+
+use std::fmt;
+
+/// The padding of the panel.
+const PADDING: f32 = 8.0;
+
+/// A time of the day, with minute precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Time {
+    hour: u32,
+    minute: u32,
+}
+
+impl Time {
+    /// Midnight.
+    pub const MIDNIGHT: Self = Self { hour: 0, minute: 0 };
+
+    /// Creates a new [`Time`] from the given hour (`0..24`) and minute
+    /// (`0..60`).
+    ///
+    /// Returns `None` if the time does not exist.
+    pub fn new(hour: u32, minute: u32) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    /// Returns the hour of the [`Time`], from `0` to `23`.
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Returns the minute of the [`Time`], from `0` to `59`.
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// A panel to pick a time of the day that is shown below a trigger element
+/// when clicked.
+///
+/// Every field can be changed with its arrows or the mouse wheel. Once
+/// open, the left and right arrow keys focus a field and the up and down
+/// arrow keys change it. `Enter` or `Escape` close the panel.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// # use iced_widget::{button, text, time_picker};
+/// use iced_widget::time_picker::Time;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TimeChanged(Time),
+///     Noop,
+/// }
+///
+/// fn view<'a>(time: Time) -> Element<'a, Message> {
+///     time_picker(
+///         button(text(time.to_string())).on_press(Message::Noop),
+///         time,
+///         Message::TimeChanged,
+///     )
+///     .step(15)
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TimePicker<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    trigger: Element<'a, Message, Theme, Renderer>,
+    time: Time,
+    on_change: Box<dyn Fn(Time) -> Message + 'a>,
+    step: u32,
+    use_24h: bool,
+    text_size: Option<Pixels>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`TimePicker`] with the given trigger, the current
+    /// [`Time`] and a function producing a message when it changes.
+    pub fn new(
+        trigger: impl Into<Element<'a, Message, Theme, Renderer>>,
+        time: Time,
+        on_change: impl Fn(Time) -> Message + 'a,
+    ) -> Self {
+        Self {
+            trigger: trigger.into(),
+            time,
+            on_change: Box::new(on_change),
+            step: 1,
+            use_24h: true,
+            text_size: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the number of minutes the minutes change by at a time.
+    pub fn step(mut self, minutes: u32) -> Self {
+        self.step = minutes.clamp(1, 30);
+        self
+    }
+
+    /// Sets whether the hours are shown in 24-hour format; otherwise, a
+    /// 12-hour clock with an AM/PM field is used.
+    pub fn use_24h(mut self, use_24h: bool) -> Self {
+        self.use_24h = use_24h;
+        self
+    }
+
+    /// Sets the text size of the panel.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the style of the panel.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the panel.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_open: bool,
+    focused: Field,
+}
+
+/// A field of the [`Clock`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Field {
+    #[default]
+    Hour,
+    Minute,
+    Period,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.trigger)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.trigger));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.trigger.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.trigger
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.trigger.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.trigger.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if cursor.is_over(layout.bounds()) {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.is_open = !state.is_open;
+                state.focused = Field::Hour;
+
+                shell.invalidate_layout();
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.trigger.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.trigger.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (state, trigger) = (&mut tree.state, &mut tree.children[0]);
+        let State { is_open, focused } = state.downcast_mut::<State>();
+
+        if !*is_open {
+            return self.trigger.as_widget_mut().overlay(
+                trigger,
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        Some(overlay::Element::new(Box::new(Popup::new(
+            is_open,
+            Clock {
+                focused,
+                time: self.time,
+                on_change: &self.on_change,
+                step: self.step,
+                use_24h: self.use_24h,
+                text_size: self.text_size,
+                class: &self.class,
+            },
+            layout.bounds() + translation,
+        ))))
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<TimePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(time_picker: TimePicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(time_picker)
+    }
+}
+
+struct Clock<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    focused: &'b mut Field,
+    time: Time,
+    on_change: &'b dyn Fn(Time) -> Message,
+    step: u32,
+    use_24h: bool,
+    text_size: Option<Pixels>,
+    class: &'b Theme::Class<'a>,
+}
+
+/// A region of the [`Clock`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Region {
+    Increment(Field),
+    Value(Field),
+    Decrement(Field),
+}
+
+impl<'a, 'b, Message, Theme> Clock<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fn cell<Renderer>(&self, renderer: &Renderer) -> f32
+    where
+        Renderer: text::Renderer,
+    {
+        (self.text_size.unwrap_or_else(|| renderer.default_size()).0 * 2.0)
+            .round()
+    }
+
+    fn fields(&self) -> &'static [Field] {
+        if self.use_24h {
+            &[Field::Hour, Field::Minute]
+        } else {
+            &[Field::Hour, Field::Minute, Field::Period]
+        }
+    }
+
+    /// Returns the bounds of the column of the given field.
+    fn column(&self, bounds: Rectangle, cell: f32, field: Field) -> Rectangle {
+        let index = match field {
+            Field::Hour => 0.0,
+            Field::Minute => 1.0,
+            Field::Period => 2.0,
+        };
+
+        Rectangle {
+            x: bounds.x + PADDING + index * cell * 2.0,
+            y: bounds.y + PADDING,
+            width: cell * 1.5,
+            height: cell * 3.0,
+        }
+    }
+
+    fn region(
+        &self,
+        bounds: Rectangle,
+        cell: f32,
+        position: Point,
+    ) -> Option<Region> {
+        self.fields().iter().find_map(|field| {
+            let column = self.column(bounds, cell, *field);
+
+            if !column.contains(position) {
+                return None;
+            }
+
+            Some(match ((position.y - column.y) / cell) as u32 {
+                0 => Region::Increment(*field),
+                1 => Region::Value(*field),
+                _ => Region::Decrement(*field),
+            })
+        })
+    }
+
+    /// Returns the [`Time`] with the given field changed by one step.
+    fn change(&self, field: Field, forward: bool) -> Time {
+        let Time { hour, minute } = self.time;
+
+        match field {
+            Field::Hour => Time {
+                hour: if forward {
+                    (hour + 1) % 24
+                } else {
+                    (hour + 23) % 24
+                },
+                minute,
+            },
+            Field::Minute => {
+                let minute = minute / self.step * self.step;
+
+                Time {
+                    hour,
+                    minute: if forward {
+                        (minute + self.step) % 60
+                    } else {
+                        (minute + 60 - self.step) % 60
+                    },
+                }
+            }
+            Field::Period => Time {
+                hour: (hour + 12) % 24,
+                minute,
+            },
+        }
+    }
+
+    fn label(&self, field: Field) -> String {
+        let Time { hour, minute } = self.time;
+
+        match field {
+            Field::Hour if self.use_24h => format!("{hour:02}"),
+            Field::Hour => format!("{:02}", (hour + 11) % 12 + 1),
+            Field::Minute => format!("{minute:02}"),
+            Field::Period if hour < 12 => String::from("AM"),
+            Field::Period => String::from("PM"),
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> picker::Panel<Message, Theme, Renderer>
+    for Clock<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn size(&self, renderer: &Renderer) -> Size {
+        let cell = self.cell(renderer);
+        let fields = self.fields().len() as f32;
+
+        Size::new(
+            cell * (fields * 2.0 - 0.5) + PADDING * 2.0,
+            cell * 3.0 + PADDING * 2.0,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> Action {
+        let cell = self.cell(renderer);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return Action::Ignored;
+                };
+
+                match self.region(bounds, cell, position) {
+                    Some(Region::Increment(field)) => {
+                        *self.focused = field;
+                        shell.publish((self.on_change)(
+                            self.change(field, true),
+                        ));
+                    }
+                    Some(Region::Decrement(field)) => {
+                        *self.focused = field;
+                        shell.publish((self.on_change)(
+                            self.change(field, false),
+                        ));
+                    }
+                    Some(Region::Value(field)) => {
+                        *self.focused = field;
+                    }
+                    None => {}
+                }
+
+                Action::Captured
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return Action::Ignored;
+                };
+
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => *y,
+                };
+
+                if let Some(
+                    Region::Increment(field)
+                    | Region::Value(field)
+                    | Region::Decrement(field),
+                ) = self.region(bounds, cell, position)
+                {
+                    if y != 0.0 {
+                        *self.focused = field;
+                        shell.publish((self.on_change)(
+                            self.change(field, y > 0.0),
+                        ));
+                    }
+                }
+
+                Action::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) => {
+                let fields = self.fields();
+                let index = fields
+                    .iter()
+                    .position(|field| field == self.focused)
+                    .unwrap_or_default();
+
+                match named {
+                    key::Named::ArrowLeft => {
+                        *self.focused =
+                            fields[(index + fields.len() - 1) % fields.len()];
+                    }
+                    key::Named::ArrowRight => {
+                        *self.focused = fields[(index + 1) % fields.len()];
+                    }
+                    key::Named::ArrowUp | key::Named::ArrowDown => {
+                        shell.publish((self.on_change)(self.change(
+                            *self.focused,
+                            *named == key::Named::ArrowUp,
+                        )));
+                    }
+                    key::Named::Enter => return Action::Close,
+                    _ => return Action::Ignored,
+                }
+
+                Action::Captured
+            }
+            _ => Action::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let region = cursor.position_over(bounds).and_then(|position| {
+            self.region(bounds, self.cell(renderer), position)
+        });
+
+        match region {
+            Some(Region::Increment(_) | Region::Decrement(_)) => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let cell = self.cell(renderer);
+
+        let hovered = cursor
+            .position_over(bounds)
+            .and_then(|position| self.region(bounds, cell, position));
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+            },
+            style.background,
+        );
+
+        let hour = self.column(bounds, cell, Field::Hour);
+
+        fill_text(
+            renderer,
+            ":",
+            size,
+            Point::new(hour.x + cell * 1.75, hour.center_y()),
+            alignment::Horizontal::Center,
+            style.text_color,
+            bounds,
+        );
+
+        for field in self.fields() {
+            let column = self.column(bounds, cell, *field);
+
+            for (row, region, label) in [
+                (0.0, Region::Increment(*field), "▲"),
+                (1.0, Region::Value(*field), ""),
+                (2.0, Region::Decrement(*field), "▼"),
+            ] {
+                let cell_bounds = Rectangle {
+                    y: column.y + row * cell,
+                    height: cell,
+                    ..column
+                };
+
+                let is_value = matches!(region, Region::Value(_));
+                let is_focused = is_value && self.focused == field;
+
+                let background = if is_focused {
+                    Some(style.selected_background)
+                } else if hovered == Some(region) {
+                    Some(style.hovered_background)
+                } else {
+                    None
+                };
+
+                if let Some(background) = background {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: cell_bounds,
+                            border: border::rounded(4),
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+                }
+
+                let (content, text_size, color) = if is_value {
+                    (
+                        self.label(*field),
+                        size,
+                        if is_focused {
+                            style.selected_text_color
+                        } else {
+                            style.text_color
+                        },
+                    )
+                } else {
+                    (label.to_owned(), Pixels(size.0 * 0.6), style.arrow_color)
+                };
+
+                fill_text(
+                    renderer,
+                    content,
+                    text_size,
+                    cell_bounds.center(),
+                    alignment::Horizontal::Center,
+                    color,
+                    bounds,
+                );
+            }
+        }
+    }
+}
+
+/// The appearance of the panel of a [`TimePicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the panel.
+    pub background: Background,
+    /// The [`Border`] of the panel.
+    pub border: Border,
+    /// The [`Shadow`] of the panel.
+    pub shadow: Shadow,
+    /// The text [`Color`] of the fields.
+    pub text_color: Color,
+    /// The [`Color`] of the arrows.
+    pub arrow_color: Color,
+    /// The [`Background`] of a hovered arrow or field.
+    pub hovered_background: Background,
+    /// The [`Background`] of the focused field.
+    pub selected_background: Background,
+    /// The text [`Color`] of the focused field.
+    pub selected_text_color: Color,
+}
+
+/// The theme catalog of a [`TimePicker`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`TimePicker`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`TimePicker`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: palette.background.strong.color,
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        text_color: palette.background.base.text,
+        arrow_color: palette.background.base.text.scale_alpha(0.6),
+        hovered_background: palette.background.weak.color.into(),
+        selected_background: palette.primary.strong.color.into(),
+        selected_text_color: palette.primary.strong.text,
+    }
+}
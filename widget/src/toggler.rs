@@ -1,17 +1,25 @@
 //! Show toggle controls using togglers.
+use crate::core::accessibility;
 use crate::core::alignment;
+use crate::core::animation::Interpolable;
 use crate::core::event;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::time::Duration;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Border, Clipboard, Color, Element, Event, Layout, Length, Pixels,
     Rectangle, Shell, Size, Theme, Widget,
 };
+use crate::transition::Transition;
 
 /// A toggler widget.
 ///
@@ -47,8 +55,10 @@ pub struct Toggler<
     text_line_height: text::LineHeight,
     text_alignment: alignment::Horizontal,
     text_shaping: text::Shaping,
+    text_wrapping: text::Wrapping,
     spacing: f32,
     font: Option<Renderer::Font>,
+    transition: Option<Duration>,
     class: Theme::Class<'a>,
 }
 
@@ -86,8 +96,10 @@ where
             text_line_height: text::LineHeight::default(),
             text_alignment: alignment::Horizontal::Left,
             text_shaping: text::Shaping::Basic,
+            text_wrapping: text::Wrapping::default(),
             spacing: Self::DEFAULT_SIZE / 2.0,
             font: None,
+            transition: None,
             class: Theme::default(),
         }
     }
@@ -131,6 +143,12 @@ where
         self
     }
 
+    /// Sets the [`text::Wrapping`] strategy of the [`Toggler`].
+    pub fn text_wrapping(mut self, wrapping: text::Wrapping) -> Self {
+        self.text_wrapping = wrapping;
+        self
+    }
+
     /// Sets the spacing between the [`Toggler`] and the text.
     pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
         self.spacing = spacing.into().0;
@@ -145,6 +163,15 @@ where
         self
     }
 
+    /// Sets the duration of the transition between the styles of the
+    /// [`Toggler`] when its [`Status`] changes.
+    ///
+    /// By default, the style changes instantly.
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
     /// Sets the style of the [`Toggler`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -162,6 +189,23 @@ where
         self.class = class.into();
         self
     }
+
+    fn status(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Status {
+        let is_toggled = self.is_toggled;
+
+        if state.is_focused {
+            Status::Focused { is_toggled }
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered { is_toggled }
+        } else {
+            Status::Active { is_toggled }
+        }
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -171,11 +215,11 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -199,12 +243,11 @@ where
             |_| layout::Node::new(Size::new(2.0 * self.size, self.size)),
             |limits| {
                 if let Some(label) = self.label.as_deref() {
-                    let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                    let state =
+                        tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
-                    widget::text::layout(
-                        state,
+                    widget::text::layout_with(
+                        &mut state.label,
                         renderer,
                         limits,
                         self.width,
@@ -216,6 +259,10 @@ where
                         self.text_alignment,
                         alignment::Vertical::Top,
                         self.text_shaping,
+                        widget::text::Settings {
+                            wrapping: self.text_wrapping,
+                            ..widget::text::Settings::default()
+                        },
                     )
                 } else {
                     layout::Node::new(Size::ZERO)
@@ -224,9 +271,36 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable_with_bounds(state, None, layout.bounds());
+
+        let node = || {
+            let mut node =
+                accessibility::Node::new(accessibility::Role::Switch)
+                    .toggled(self.is_toggled)
+                    .action(accessibility::Action::Click);
+
+            if let Some(label) = &self.label {
+                node = node.label(label.clone());
+            }
+
+            node
+        };
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -235,11 +309,15 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let mouse_over = cursor.is_over(layout.bounds());
 
+                state.is_focused = false;
+
                 if mouse_over {
                     shell.publish((self.on_toggle)(!self.is_toggled));
 
@@ -248,6 +326,27 @@ where
                     event::Status::Ignored
                 }
             }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(duration) = self.transition {
+                    let status = self.status(state, layout, cursor);
+
+                    if let Some(request) =
+                        state.transition.update(status, duration, now)
+                    {
+                        shell.request_redraw(request);
+                    }
+                }
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Space | key::Named::Enter),
+                ..
+            }) if state.is_focused => {
+                shell.publish((self.on_toggle)(!self.is_toggled));
+
+                event::Status::Captured
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -284,39 +383,35 @@ where
         /// between the background Quad and foreground Quad.
         const SPACE_RATIO: f32 = 0.05;
 
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
         let mut children = layout.children();
         let toggler_layout = children.next().unwrap();
 
         if self.label.is_some() {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> =
-                tree.state.downcast_ref();
 
             crate::text::draw(
                 renderer,
                 style,
                 label_layout,
-                state.0.raw(),
+                state.label.0.raw(),
                 crate::text::Style::default(),
                 viewport,
             );
         }
 
         let bounds = toggler_layout.bounds();
-        let is_mouse_over = cursor.is_over(layout.bounds());
 
-        let status = if is_mouse_over {
-            Status::Hovered {
-                is_toggled: self.is_toggled,
-            }
+        let status = self.status(state, layout, cursor);
+        let style = if self.transition.is_some() {
+            state
+                .transition
+                .style(status, |status| theme.style(&self.class, status))
         } else {
-            Status::Active {
-                is_toggled: self.is_toggled,
-            }
+            theme.style(&self.class, status)
         };
 
-        let style = theme.style(&self.class, status);
-
         let border_radius = bounds.height / BORDER_RADIUS_RATIO;
         let space = SPACE_RATIO * bounds.height;
 
@@ -394,6 +489,11 @@ pub enum Status {
         /// Indicates whether the [`Toggler`] is toggled.
         is_toggled: bool,
     },
+    /// The [`Toggler`] has keyboard focus.
+    Focused {
+        /// Indicates whether the [`Toggler`] is toggled.
+        is_toggled: bool,
+    },
 }
 
 /// The appearance of a toggler.
@@ -413,6 +513,27 @@ pub struct Style {
     pub foreground_border_color: Color,
 }
 
+impl Interpolable for Style {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, ratio),
+            background_border_width: self
+                .background_border_width
+                .interpolate(&other.background_border_width, ratio),
+            background_border_color: self
+                .background_border_color
+                .interpolate(&other.background_border_color, ratio),
+            foreground: self.foreground.interpolate(&other.foreground, ratio),
+            foreground_border_width: self
+                .foreground_border_width
+                .interpolate(&other.foreground_border_width, ratio),
+            foreground_border_color: self
+                .foreground_border_color
+                .interpolate(&other.foreground_border_color, ratio),
+        }
+    }
+}
+
 /// The theme catalog of a [`Toggler`].
 pub trait Catalog: Sized {
     /// The item class of the [`Catalog`].
@@ -447,7 +568,9 @@ pub fn default(theme: &Theme, status: Status) -> Style {
     let palette = theme.extended_palette();
 
     let background = match status {
-        Status::Active { is_toggled } | Status::Hovered { is_toggled } => {
+        Status::Active { is_toggled }
+        | Status::Hovered { is_toggled }
+        | Status::Focused { is_toggled } => {
             if is_toggled {
                 palette.primary.strong.color
             } else {
@@ -457,7 +580,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
     };
 
     let foreground = match status {
-        Status::Active { is_toggled } => {
+        Status::Active { is_toggled } | Status::Focused { is_toggled } => {
             if is_toggled {
                 palette.primary.strong.text
             } else {
@@ -476,12 +599,40 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         }
     };
 
+    let (background_border_width, background_border_color) = match status {
+        Status::Focused { .. } => (2.0, palette.background.base.text),
+        Status::Active { .. } | Status::Hovered { .. } => {
+            (0.0, Color::TRANSPARENT)
+        }
+    };
+
     Style {
         background,
         foreground,
         foreground_border_width: 0.0,
         foreground_border_color: Color::TRANSPARENT,
-        background_border_width: 0.0,
-        background_border_color: Color::TRANSPARENT,
+        background_border_width,
+        background_border_color,
+    }
+}
+
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    is_focused: bool,
+    transition: Transition<Status>,
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
     }
 }
@@ -0,0 +1,100 @@
+//! Animate the style of a widget when its status changes.
+use crate::core::animation::{Animation, Interpolable};
+use crate::core::time::{Duration, Instant};
+use crate::core::window;
+
+/// The transition between the styles of the consecutive statuses of a
+/// widget.
+///
+/// The style of a widget is only known while drawing, since it depends on
+/// the theme. Therefore, the [`Transition`] remembers the statuses it is
+/// blending and produces the style from them when drawing.
+#[derive(Debug, Clone)]
+pub(crate) struct Transition<Status> {
+    layers: Vec<(Status, Animation<f32>)>,
+    now: Option<Instant>,
+}
+
+impl<Status> Transition<Status>
+where
+    Status: Copy + PartialEq,
+{
+    /// Updates the [`Transition`] with the current status of the widget at
+    /// the given [`Instant`].
+    ///
+    /// It returns the [`window::RedrawRequest`] needed to keep the
+    /// [`Transition`] going, if any.
+    pub fn update(
+        &mut self,
+        status: Status,
+        duration: Duration,
+        now: Instant,
+    ) -> Option<window::RedrawRequest> {
+        self.now = Some(now);
+
+        match self.layers.last() {
+            Some((last, _)) if *last == status => {}
+            Some(_) => {
+                self.layers.push((
+                    status,
+                    Animation::new(0.0).duration(duration).go(1.0, now),
+                ));
+            }
+            None => {
+                self.layers.push((status, Animation::new(1.0)));
+            }
+        }
+
+        // A finished layer covers all the layers below it
+        if let Some(finished) = self
+            .layers
+            .iter()
+            .rposition(|(_, progress)| !progress.is_animating(now))
+        {
+            let _ = self.layers.drain(..finished);
+        }
+
+        self.layers
+            .iter()
+            .find_map(|(_, progress)| progress.redraw_request(now))
+    }
+
+    /// Returns the style to draw for the given status, given the style of
+    /// each status.
+    pub fn style<Style>(
+        &self,
+        status: Status,
+        style: impl Fn(Status) -> Style,
+    ) -> Style
+    where
+        Style: Interpolable,
+    {
+        let (Some(now), Some((last, _))) = (self.now, self.layers.last())
+        else {
+            return style(status);
+        };
+
+        if *last != status {
+            return style(status);
+        }
+
+        let mut layers = self.layers.iter();
+
+        let Some((first, _)) = layers.next() else {
+            return style(status);
+        };
+
+        layers.fold(style(*first), |blended, (status, progress)| {
+            blended.interpolate(&style(*status), progress.value(now))
+        })
+    }
+}
+
+impl<Status> Default for Transition<Status> {
+    fn default() -> Self {
+        Self {
+            layers: Vec::new(),
+            now: None,
+        }
+    }
+}
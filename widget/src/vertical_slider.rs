@@ -5,6 +5,7 @@ pub use crate::slider::{
     default, Catalog, Handle, HandleShape, Status, Style, StyleFn,
 };
 
+use crate::core::accessibility;
 use crate::core::border::{self, Border};
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
@@ -13,6 +14,7 @@ use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     self, Clipboard, Element, Length, Pixels, Point, Rectangle, Shell, Size,
@@ -200,6 +202,33 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable_with_bounds(state, None, layout.bounds());
+
+        let node = || {
+            accessibility::Node::new(accessibility::Role::Slider)
+                .value(accessibility::Value::Numeric {
+                    value: self.value.into(),
+                    min: (*self.range.start()).into(),
+                    max: (*self.range.end()).into(),
+                    step: self.step.into(),
+                })
+                .action(accessibility::Action::SetValue)
+                .action(accessibility::Action::Increment)
+                .action(accessibility::Action::Decrement)
+        };
+
+        operation.accessible(None, layout.bounds(), &node, &mut |_| {});
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -293,6 +322,8 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = false;
+
                 if let Some(cursor_position) =
                     cursor.position_over(layout.bounds())
                 {
@@ -330,18 +361,26 @@ where
                 }
             }
             Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
-                if cursor.position_over(layout.bounds()).is_some() {
+                if state.is_focused
+                    || cursor.position_over(layout.bounds()).is_some()
+                {
                     match key {
-                        Key::Named(key::Named::ArrowUp) => {
+                        Key::Named(
+                            key::Named::ArrowUp | key::Named::ArrowRight,
+                        ) => {
                             let _ = increment(current_value).map(change);
+
+                            return event::Status::Captured;
                         }
-                        Key::Named(key::Named::ArrowDown) => {
+                        Key::Named(
+                            key::Named::ArrowDown | key::Named::ArrowLeft,
+                        ) => {
                             let _ = decrement(current_value).map(change);
+
+                            return event::Status::Captured;
                         }
                         _ => (),
                     }
-
-                    return event::Status::Captured;
                 }
             }
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
@@ -371,6 +410,8 @@ where
             &self.class,
             if state.is_dragging {
                 Status::Dragged
+            } else if state.is_focused {
+                Status::Focused
             } else if is_mouse_over {
                 Status::Hovered
             } else {
@@ -493,5 +534,20 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct State {
     is_dragging: bool,
+    is_focused: bool,
     keyboard_modifiers: keyboard::Modifiers,
 }
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
@@ -0,0 +1,953 @@
+//! Display very large lists by only building the rows in view.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use crate::scrollable::{self, AbsoluteOffset, Scrollable, Viewport};
+
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+/// A scrollable list that only builds the rows intersecting its viewport.
+///
+/// Unlike a [`Column`] inside a [`Scrollable`], a [`VirtualList`] calls its
+/// view closure exclusively for the rows that are visible (plus a viewport
+/// worth of rows above and below), which makes it suitable for lists with
+/// hundreds of thousands of items.
+///
+/// Rows can have a fixed height or be measured as they come into view. The
+/// widget state of every built row is kept by key, and the scroll position
+/// stays anchored to the first visible row when rows are inserted or removed
+/// above it.
+///
+/// [`Column`]: crate::Column
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// # use iced_widget::{text, virtual_list};
+/// #
+/// struct Log {
+///     lines: Vec<String>,
+/// }
+///
+/// enum Message {}
+///
+/// fn view(log: &Log) -> Element<'_, Message> {
+///     virtual_list(&log.lines, |_index, line| text(line).into())
+///         .row_height(20)
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: scrollable::Catalog,
+    Renderer: crate::core::Renderer,
+{
+    items: &'a [T],
+    view:
+        Box<dyn Fn(usize, &'a T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    key: Box<dyn Fn(usize, &T) -> u64 + 'a>,
+    height: Height,
+    id: Option<scrollable::Id>,
+    width: Length,
+    list_height: Length,
+    on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Height {
+    Fixed(f32),
+    Estimated(f32),
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    VirtualList<'a, T, Message, Theme, Renderer>
+where
+    Theme: scrollable::Catalog,
+    Renderer: crate::core::Renderer,
+{
+    /// The default estimated height of a row.
+    pub const DEFAULT_ROW_HEIGHT: f32 = 30.0;
+
+    /// Creates a new [`VirtualList`] of the given items, using the `view`
+    /// closure to build the rows in view.
+    ///
+    /// By default, rows are measured as they come into view and identified
+    /// by their index.
+    pub fn new(
+        items: &'a [T],
+        view: impl Fn(usize, &'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            items,
+            view: Box::new(view),
+            key: Box::new(|index, _| index as u64),
+            height: Height::Estimated(Self::DEFAULT_ROW_HEIGHT),
+            id: None,
+            width: Length::Fill,
+            list_height: Length::Fill,
+            on_scroll: None,
+        }
+    }
+
+    /// Sets a fixed height for every row of the [`VirtualList`].
+    ///
+    /// Rows will not be measured, which makes scrolling cheaper.
+    pub fn row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = Height::Fixed(height.into().0);
+        self
+    }
+
+    /// Sets the estimated height of the rows of the [`VirtualList`] that
+    /// have not been measured yet.
+    ///
+    /// Rows will be measured as they come into view.
+    pub fn estimated_row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = Height::Estimated(height.into().0);
+        self
+    }
+
+    /// Sets the function used to identify each item of the [`VirtualList`].
+    ///
+    /// The widget state and the measured height of a row follow its key,
+    /// instead of its index. The scroll position is anchored to the key of
+    /// the first visible row.
+    pub fn key<K>(mut self, key: impl Fn(&T) -> K + 'a) -> Self
+    where
+        K: Hash,
+    {
+        self.key = Box::new(move |_, item| {
+            let mut hasher = DefaultHasher::new();
+            key(item).hash(&mut hasher);
+
+            hasher.finish()
+        });
+        self
+    }
+
+    /// Sets the [`scrollable::Id`] of the [`VirtualList`].
+    pub fn id(mut self, id: scrollable::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`VirtualList`].
+    ///
+    /// Only the rows inside this height are built; a [`VirtualList`] with an
+    /// unbounded height builds all of its rows.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.list_height = height.into();
+        self
+    }
+
+    /// Sets a function to call when the [`VirtualList`] is scrolled.
+    ///
+    /// The function takes the [`Viewport`] of the [`VirtualList`].
+    pub fn on_scroll(mut self, f: impl Fn(Viewport) -> Message + 'a) -> Self {
+        self.on_scroll = Some(Box::new(f));
+        self
+    }
+}
+
+struct List<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    height: Length,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for List<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let tree = &mut tree.children[0];
+        let viewport = limits.height(self.height).max();
+
+        {
+            let scrollable = tree.state.downcast_ref::<scrollable::State>();
+            let rows = tree.children[0].state.downcast_mut::<State>();
+
+            rows.offset = scrollable
+                .absolute_offset(
+                    viewport,
+                    Size::new(viewport.width, rows.content_height()),
+                )
+                .y;
+            rows.viewport = viewport.height;
+        }
+
+        let node = self.content.as_widget().layout(tree, renderer, limits);
+
+        let rows = tree.children[0].state.downcast_mut::<State>();
+
+        if std::mem::take(&mut rows.is_anchored) {
+            tree.state.downcast_mut::<scrollable::State>().scroll_to(
+                AbsoluteOffset {
+                    x: 0.0,
+                    y: rows.offset,
+                },
+            );
+        }
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let tree = &mut tree.children[0];
+
+        let status = self.content.as_widget_mut().on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        );
+
+        let scrollable = tree.state.downcast_ref::<scrollable::State>();
+        let rows = tree.children[0].state.downcast_mut::<State>();
+
+        let bounds = layout.bounds().size();
+        let offset = scrollable
+            .absolute_offset(
+                bounds,
+                Size::new(bounds.width, rows.content_height()),
+            )
+            .y;
+
+        rows.anchor(offset);
+
+        // Rebuild the rows when the viewport goes past the built ones
+        if offset < rows.built.0 || offset + bounds.height > rows.built.1 {
+            rows.offset = offset;
+            rows.viewport = bounds.height;
+
+            self.content.as_widget().diff(tree);
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+/// The rows of a [`VirtualList`], laid out inside its [`Scrollable`].
+struct Rows<'a, T, Message, Theme, Renderer> {
+    items: &'a [T],
+    view:
+        Box<dyn Fn(usize, &'a T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    key: Box<dyn Fn(usize, &T) -> u64 + 'a>,
+    height: Height,
+    /// The rows in view, built on `diff` once the state is available.
+    rows: RefCell<Vec<Element<'a, Message, Theme, Renderer>>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    offset: f32,
+    viewport: f32,
+    source: Option<(usize, usize, Height)>,
+    keys: Vec<u64>,
+    tops: Vec<f32>,
+    heights: HashMap<u64, f32>,
+    range: Range<usize>,
+    rows: Vec<Row>,
+    built: (f32, f32),
+    anchor: Option<Anchor>,
+    is_anchored: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Row {
+    key: u64,
+    index: usize,
+    top: f32,
+    height: f32,
+}
+
+/// The first visible row, used to keep the scroll position in place.
+#[derive(Debug, Clone, Copy)]
+struct Anchor {
+    key: u64,
+    index: usize,
+    top: f32,
+}
+
+impl State {
+    /// Anchors the scroll position to the first built row visible at the
+    /// given offset.
+    fn anchor(&mut self, offset: f32) {
+        if let Some(row) =
+            self.rows.iter().find(|row| row.top + row.height > offset)
+        {
+            self.anchor = Some(Anchor {
+                key: row.key,
+                index: row.index,
+                top: row.top,
+            });
+        }
+    }
+
+    /// Returns the current index of the anchor row and its previous top.
+    fn find_anchor(&self) -> Option<(usize, f32)> {
+        let anchor = self.anchor?;
+
+        let index = if self.keys.get(anchor.index) == Some(&anchor.key) {
+            anchor.index
+        } else {
+            self.keys.iter().position(|key| *key == anchor.key)?
+        };
+
+        Some((index, anchor.top))
+    }
+
+    /// Replaces the keys of the items and recomputes the top of every row,
+    /// forgetting the heights of the keys that are gone.
+    fn reset(&mut self, keys: Vec<u64>, height: Height) {
+        let present: HashSet<u64> = keys.iter().copied().collect();
+
+        self.heights.retain(|key, _| present.contains(key));
+        self.keys = keys;
+        self.tops = vec![0.0];
+
+        self.update_tops(0, height);
+    }
+
+    /// Recomputes the top of every row after the given index.
+    fn update_tops(&mut self, from: usize, height: Height) {
+        self.tops.truncate(from + 1);
+
+        let mut top = self.tops[from];
+
+        for key in &self.keys[from..] {
+            top += match height {
+                Height::Fixed(height) => height,
+                Height::Estimated(height) => {
+                    self.heights.get(key).copied().unwrap_or(height)
+                }
+            };
+
+            self.tops.push(top);
+        }
+    }
+
+    fn content_height(&self) -> f32 {
+        self.tops.last().copied().unwrap_or(0.0)
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Rows<'a, T, Message, Theme, Renderer> {
+    fn keys(&self) -> Vec<u64> {
+        self.items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (self.key)(index, item))
+            .collect()
+    }
+
+    /// Returns the range of rows that must be built for the current offset
+    /// and viewport of the given [`State`].
+    fn visible(&self, state: &mut State) -> Range<usize> {
+        let count = self.items.len();
+
+        // Keys and tops are only recomputed when the items change
+        let source = Some((self.items.as_ptr() as usize, count, self.height));
+
+        if state.source != source {
+            state.reset(self.keys(), self.height);
+            state.source = source;
+        }
+
+        loop {
+            // Keep the anchor row in place before picking the rows to build
+            let offset = match state.find_anchor() {
+                Some((index, top)) => state.offset + state.tops[index] - top,
+                None => state.offset,
+            };
+
+            let start = state.tops[1..]
+                .partition_point(|bottom| *bottom <= offset - state.viewport);
+            let end = state.tops[..count]
+                .partition_point(|top| *top < offset + 2.0 * state.viewport)
+                .max(start);
+
+            // Items may also change in place, which is noticed once the
+            // changed rows come into view
+            if (start..end).all(|index| {
+                state.keys[index] == (self.key)(index, &self.items[index])
+            }) {
+                return start..end;
+            }
+
+            state.reset(self.keys(), self.height);
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Rows<'a, T, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut state = State::default();
+        let _ = self.visible(&mut state);
+
+        tree::State::new(state)
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let range = self.visible(state);
+
+        let rows: Vec<_> = range
+            .clone()
+            .map(|index| (self.view)(index, &self.items[index]))
+            .collect();
+
+        let mut trees: HashMap<u64, Tree> = state
+            .rows
+            .drain(..)
+            .map(|row| row.key)
+            .zip(tree.children.drain(..))
+            .collect();
+
+        for (index, row) in range.clone().zip(&rows) {
+            let key = state.keys[index];
+
+            let mut row_tree = trees
+                .remove(&key)
+                .unwrap_or_else(|| Tree::new(row.as_widget()));
+
+            row_tree.diff(row.as_widget());
+
+            tree.children.push(row_tree);
+            state.rows.push(Row {
+                key,
+                index,
+                top: 0.0,
+                height: 0.0,
+            });
+        }
+
+        state.range = range;
+
+        *self.rows.borrow_mut() = rows;
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        let width = limits.max().width;
+        let anchor = state.find_anchor();
+
+        let row_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                width,
+                match self.height {
+                    Height::Fixed(height) => height,
+                    Height::Estimated(_) => f32::INFINITY,
+                },
+            ),
+        );
+
+        let mut y = state.tops[state.range.start];
+        let mut changed = None;
+        let mut nodes = Vec::with_capacity(state.rows.len());
+
+        for ((row, row_tree), built) in self
+            .rows
+            .borrow()
+            .iter()
+            .zip(&mut tree.children)
+            .zip(&mut state.rows)
+        {
+            let node = row.as_widget().layout(row_tree, renderer, &row_limits);
+
+            let height = match self.height {
+                Height::Fixed(height) => height,
+                Height::Estimated(_) => {
+                    let height = node.size().height;
+
+                    if state.heights.insert(built.key, height) != Some(height)
+                        && changed.is_none()
+                    {
+                        changed = Some(built.index);
+                    }
+
+                    height
+                }
+            };
+
+            nodes.push(node.move_to(Point::new(0.0, y)));
+
+            built.top = y;
+            built.height = height;
+
+            y += height;
+        }
+
+        if let Some(index) = changed {
+            state.update_tops(index, self.height);
+        }
+
+        let content_height = state.content_height();
+
+        // Measured rows above the anchor may have moved it again
+        let offset = match anchor {
+            Some((index, top)) => state.offset + state.tops[index] - top,
+            None => state.offset,
+        }
+        .min(content_height - state.viewport)
+        .max(0.0);
+
+        state.is_anchored = offset != state.offset;
+        state.offset = offset;
+        state.built = (
+            if state.range.start == 0 {
+                f32::NEG_INFINITY
+            } else {
+                state.tops[state.range.start]
+            },
+            if state.range.end == self.items.len() {
+                f32::INFINITY
+            } else {
+                y
+            },
+        );
+        state.anchor(offset);
+
+        layout::Node::with_children(Size::new(width, content_height), nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.rows
+                .borrow()
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((row, state), layout)| {
+                    row.as_widget().operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.rows
+            .get_mut()
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((row, state), layout)| {
+                row.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.rows
+            .borrow()
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((row, state), layout)| {
+                row.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((row, state), layout) in self
+            .rows
+            .borrow()
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            if layout.bounds().intersects(viewport) {
+                row.as_widget().draw(
+                    state, renderer, theme, style, layout, cursor, viewport,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            self.rows.get_mut(),
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<VirtualList<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a,
+    Message: 'a,
+    Theme: scrollable::Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(list: VirtualList<'a, T, Message, Theme, Renderer>) -> Self {
+        let rows = Rows {
+            items: list.items,
+            view: list.view,
+            key: list.key,
+            height: list.height,
+            rows: RefCell::new(Vec::new()),
+        };
+
+        let mut content = Scrollable::new(Element::new(rows))
+            .width(list.width)
+            .height(list.list_height);
+
+        if let Some(id) = list.id {
+            content = content.id(id);
+        }
+
+        if let Some(on_scroll) = list.on_scroll {
+            content = content.on_scroll(on_scroll);
+        }
+
+        Element::new(List {
+            content: content.into(),
+            height: list.list_height,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::clipboard;
+    use crate::core::Theme;
+
+    const ROW_HEIGHT: f32 = 20.0;
+    const VIEWPORT: Size = Size::new(100.0, 100.0);
+
+    type Element<'a> = crate::core::Element<'a, (), Theme, ()>;
+
+    fn list(items: &[usize]) -> Element<'_> {
+        VirtualList::new(items, |_, _| {
+            crate::Space::new(Length::Fill, Length::Fixed(ROW_HEIGHT)).into()
+        })
+        .row_height(ROW_HEIGHT)
+        .key(|item| *item)
+        .into()
+    }
+
+    fn layout(list: &Element<'_>, tree: &mut Tree) -> layout::Node {
+        list.as_widget().layout(
+            tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, VIEWPORT),
+        )
+    }
+
+    /// Sends the given event to the list, laying it out again if needed.
+    fn update(
+        list: &mut Element<'_>,
+        tree: &mut Tree,
+        node: &mut layout::Node,
+        event: Event,
+    ) {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let _ = list.as_widget_mut().on_event(
+            tree,
+            event,
+            Layout::new(node),
+            mouse::Cursor::Available(Point::new(50.0, 50.0)),
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(VIEWPORT),
+        );
+
+        if shell.is_layout_invalid() {
+            *node = layout(list, tree);
+        }
+    }
+
+    fn redraw(
+        list: &mut Element<'_>,
+        tree: &mut Tree,
+        node: &mut layout::Node,
+    ) {
+        update(
+            list,
+            tree,
+            node,
+            Event::Window(crate::core::window::Event::RedrawRequested(
+                crate::core::time::Instant::now(),
+            )),
+        );
+    }
+
+    fn scroll(
+        list: &mut Element<'_>,
+        tree: &mut Tree,
+        node: &mut layout::Node,
+        y: f32,
+    ) {
+        update(
+            list,
+            tree,
+            node,
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -y },
+            }),
+        );
+    }
+
+    /// Returns the indices of the built rows.
+    fn built(node: &layout::Node) -> Vec<usize> {
+        node.children()[0]
+            .children()
+            .iter()
+            .map(|row| (row.bounds().y / ROW_HEIGHT) as usize)
+            .collect()
+    }
+
+    #[test]
+    fn builds_the_rows_in_view() {
+        let items: Vec<usize> = (0..1_000).collect();
+        let mut list = list(&items);
+        let mut tree = Tree::new(&list);
+        let mut node = layout(&list, &mut tree);
+
+        redraw(&mut list, &mut tree, &mut node);
+
+        // The viewport plus a viewport worth of rows below
+        assert_eq!(built(&node), (0..10).collect::<Vec<_>>());
+        assert_eq!(node.children()[0].size().height, 1_000.0 * ROW_HEIGHT);
+    }
+
+    #[test]
+    fn builds_the_rows_scrolled_into_view() {
+        let items: Vec<usize> = (0..1_000).collect();
+        let mut list = list(&items);
+        let mut tree = Tree::new(&list);
+        let mut node = layout(&list, &mut tree);
+
+        redraw(&mut list, &mut tree, &mut node);
+
+        // Scrolling within the built rows keeps them
+        scroll(&mut list, &mut tree, &mut node, 60.0);
+        assert_eq!(built(&node), (0..10).collect::<Vec<_>>());
+
+        // A viewport worth of rows above and below
+        scroll(&mut list, &mut tree, &mut node, 5_000.0 - 60.0);
+        assert_eq!(built(&node), (245..260).collect::<Vec<_>>());
+
+        scroll(&mut list, &mut tree, &mut node, f32::INFINITY);
+        assert_eq!(built(&node), (990..1_000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn keeps_rows_in_view_when_rebuilt() {
+        let items: Vec<usize> = (0..1_000).collect();
+        let mut list = list(&items);
+        let mut tree = Tree::new(&list);
+        let mut node = layout(&list, &mut tree);
+
+        redraw(&mut list, &mut tree, &mut node);
+        scroll(&mut list, &mut tree, &mut node, 5_000.0);
+
+        // Inserting items above the viewport keeps the first visible row in
+        // place
+        let items: Vec<usize> = (1_000..1_010).chain(0..1_000).collect();
+        let mut list = self::list(&items);
+
+        tree.diff(&list);
+        node = layout(&list, &mut tree);
+        redraw(&mut list, &mut tree, &mut node);
+
+        assert_eq!(built(&node), (255..270).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,614 @@
+//! Expose the widgets of a window to assistive technologies.
+use crate::core::accessibility::{self, Role, Value};
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::widget::operation::{
+    Focusable, Operation, Scrollable, TextInput,
+};
+use crate::core::widget::Id;
+use crate::core::{Event, Point, Rectangle, Vector};
+
+use accesskit::{ActionData, ActionRequest, NodeBuilder, NodeId, TreeUpdate};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::{Arc, Mutex};
+
+/// The [`NodeId`] of the root node of a window.
+const ROOT: NodeId = NodeId(0);
+
+/// Publishes the widget tree of a window to assistive technologies and
+/// collects their action requests.
+#[allow(missing_debug_implementations)]
+pub struct Adapter {
+    raw: accesskit_winit::Adapter,
+    requests: Arc<Mutex<Vec<ActionRequest>>>,
+    targets: Vec<Target>,
+    is_outdated: Arc<AtomicBool>,
+}
+
+impl Adapter {
+    /// Creates a new [`Adapter`] for the given window.
+    ///
+    /// The window must not have been made visible yet; so the runtime
+    /// creates every window invisible and only shows it once its [`Adapter`]
+    /// exists.
+    pub fn new(window: &Arc<winit::window::Window>) -> Self {
+        debug_assert!(
+            !window.is_visible().unwrap_or(false),
+            "The accessibility adapter of a window must be created before \
+            the window is visible"
+        );
+
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let is_outdated = Arc::new(AtomicBool::new(true));
+
+        let handler = Handler {
+            window: window.clone(),
+            requests: requests.clone(),
+            is_outdated: is_outdated.clone(),
+        };
+
+        Self {
+            raw: accesskit_winit::Adapter::with_direct_handlers(
+                window,
+                handler.clone(),
+                handler.clone(),
+                handler,
+            ),
+            requests,
+            targets: Vec::new(),
+            is_outdated,
+        }
+    }
+
+    /// Marks the published widget tree as outdated, so it is published
+    /// again on the next [`update`](Self::update).
+    ///
+    /// It must be called whenever the user interface of the window changes.
+    pub fn invalidate(&self) {
+        self.is_outdated.store(true, atomic::Ordering::Relaxed);
+    }
+
+    /// Lets the [`Adapter`] react to a window event.
+    pub fn process_event(
+        &mut self,
+        window: &winit::window::Window,
+        event: &winit::event::WindowEvent,
+    ) {
+        self.raw.process_event(window, event);
+    }
+
+    /// Publishes the widget tree of the window, if any assistive technology
+    /// is listening and the tree is outdated.
+    ///
+    /// The `operate` function must run the given [`Operation`] on the user
+    /// interface of the window.
+    pub fn update(
+        &mut self,
+        title: &str,
+        scale_factor: f64,
+        operate: impl FnOnce(&mut dyn Operation<()>),
+    ) {
+        if !self.is_outdated.swap(false, atomic::Ordering::Relaxed) {
+            return;
+        }
+
+        let targets = &mut self.targets;
+
+        self.raw.update_if_active(|| {
+            let mut root = NodeBuilder::new(accesskit::Role::Window);
+            root.set_name(title);
+            root.set_transform(accesskit::Affine::scale(scale_factor));
+
+            let mut builder = Builder {
+                nodes: vec![(ROOT, root)],
+                targets: Vec::new(),
+                path: Path::default(),
+                parent: 0,
+                translation: Vector::ZERO,
+                scrolled: None,
+                focus: None,
+            };
+
+            operate(&mut builder);
+
+            *targets = builder.targets;
+
+            let mut tree = accesskit::Tree::new(ROOT);
+            tree.toolkit_name = Some(String::from("iced"));
+            tree.toolkit_version = Some(env!("CARGO_PKG_VERSION").to_owned());
+
+            TreeUpdate {
+                nodes: builder
+                    .nodes
+                    .into_iter()
+                    .map(|(id, node)| (id, node.build()))
+                    .collect(),
+                tree: Some(tree),
+                focus: builder.focus.unwrap_or(ROOT),
+            }
+        });
+    }
+
+    /// Returns the pending action requests of assistive technologies,
+    /// translated into events or operations.
+    pub fn requests(&mut self, modifiers: keyboard::Modifiers) -> Vec<Request> {
+        let requests = std::mem::take(
+            &mut *self.requests.lock().expect("Lock accessibility requests"),
+        );
+
+        requests
+            .into_iter()
+            .filter_map(|request| {
+                self.targets
+                    .iter()
+                    .find(|target| target.id == request.target)?
+                    .request(request, modifiers)
+            })
+            .collect()
+    }
+}
+
+/// An action request of an assistive technology, translated into something
+/// the user interface of the window can process.
+#[allow(missing_debug_implementations)]
+pub enum Request {
+    /// The requested action is performed by processing some events.
+    Events {
+        /// The position of the mouse cursor while processing the events.
+        position: Point,
+
+        /// The events that perform the requested action.
+        events: Vec<Event>,
+    },
+    /// The requested action is performed by running an [`Operation`].
+    Operation(Box<dyn Operation<()>>),
+}
+
+#[derive(Clone)]
+struct Handler {
+    window: Arc<winit::window::Window>,
+    requests: Arc<Mutex<Vec<ActionRequest>>>,
+    is_outdated: Arc<AtomicBool>,
+}
+
+impl accesskit::ActivationHandler for Handler {
+    fn request_initial_tree(&mut self) -> Option<TreeUpdate> {
+        // The tree is published on the next redraw
+        self.is_outdated.store(true, atomic::Ordering::Relaxed);
+        self.window.request_redraw();
+
+        None
+    }
+}
+
+impl accesskit::ActionHandler for Handler {
+    fn do_action(&mut self, request: ActionRequest) {
+        self.requests
+            .lock()
+            .expect("Lock accessibility requests")
+            .push(request);
+
+        // Wake up the event loop, so the request is processed
+        self.window.request_redraw();
+    }
+}
+
+impl accesskit::DeactivationHandler for Handler {
+    fn deactivate_accessibility(&mut self) {}
+}
+
+/// An accessible widget in the last published tree.
+struct Target {
+    id: NodeId,
+    bounds: Rectangle,
+    node: accessibility::Node,
+}
+
+impl Target {
+    fn request(
+        &self,
+        request: ActionRequest,
+        modifiers: keyboard::Modifiers,
+    ) -> Option<Request> {
+        use accesskit::Action;
+
+        let center = self.bounds.center();
+        let supports = |action| self.node.supports(action);
+
+        let (position, events) = match request.action {
+            Action::Default if supports(accessibility::Action::Click) => {
+                (center, click())
+            }
+            Action::Focus if supports(accessibility::Action::Focus) => {
+                return Some(Request::Operation(Box::new(Apply {
+                    path: Path::default(),
+                    target: self.id,
+                    change: Change::Focus,
+                    is_target: false,
+                })));
+            }
+            Action::Increment if supports(accessibility::Action::Increment) => {
+                (center, tap(keyboard::key::Named::ArrowUp, modifiers))
+            }
+            Action::Decrement if supports(accessibility::Action::Decrement) => {
+                (center, tap(keyboard::key::Named::ArrowDown, modifiers))
+            }
+            Action::ScrollUp if supports(accessibility::Action::ScrollUp) => {
+                (center, scroll(0.0, self.bounds.height))
+            }
+            Action::ScrollDown
+                if supports(accessibility::Action::ScrollDown) =>
+            {
+                (center, scroll(0.0, -self.bounds.height))
+            }
+            Action::ScrollLeft
+                if supports(accessibility::Action::ScrollLeft) =>
+            {
+                (center, scroll(self.bounds.width, 0.0))
+            }
+            Action::ScrollRight
+                if supports(accessibility::Action::ScrollRight) =>
+            {
+                (center, scroll(-self.bounds.width, 0.0))
+            }
+            Action::SetValue if supports(accessibility::Action::SetValue) => {
+                match (request.data?, &self.node.value) {
+                    (
+                        ActionData::NumericValue(value),
+                        Some(Value::Numeric { min, max, .. }),
+                    ) => {
+                        let ratio = if max > min {
+                            ((value - min) / (max - min)).clamp(0.0, 1.0)
+                        } else {
+                            0.0
+                        };
+
+                        let position = Point::new(
+                            self.bounds.x + self.bounds.width * ratio as f32,
+                            center.y,
+                        );
+
+                        (position, click())
+                    }
+                    (ActionData::Value(value), _) => {
+                        return Some(Request::Operation(Box::new(Apply {
+                            path: Path::default(),
+                            target: self.id,
+                            change: Change::SetValue(value.into()),
+                            is_target: false,
+                        })));
+                    }
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        Some(Request::Events { position, events })
+    }
+}
+
+/// Derives stable [`NodeId`]s for the accessible widgets of a user interface.
+///
+/// A widget with an [`Id`] is identified by it. Any other widget is identified
+/// by its position among the children of its ancestors, so its [`NodeId`] is
+/// kept as long as the widgets around it do not change.
+#[derive(Default)]
+struct Path {
+    key: u64,
+    index: u64,
+    used: HashSet<NodeId>,
+}
+
+/// The position of a [`Path`] before entering a widget.
+struct Level {
+    key: u64,
+    index: u64,
+}
+
+impl Path {
+    fn enter(&mut self, id: Option<&Id>) -> Level {
+        let level = Level {
+            key: self.key,
+            index: self.index + 1,
+        };
+
+        self.key = match id {
+            Some(id) => hash(id),
+            None => hash(&(self.key, self.index)),
+        };
+        self.index = 0;
+
+        level
+    }
+
+    fn leave(&mut self, level: Level) {
+        self.key = level.key;
+        self.index = level.index;
+    }
+
+    /// Returns the [`NodeId`] of the widget that was just entered.
+    fn node(&mut self) -> NodeId {
+        let mut key = self.key;
+
+        // Widgets may share an `Id` by mistake, so we make sure every node
+        // is unique and never clashes with the root
+        while key == ROOT.0 || !self.used.insert(NodeId(key)) {
+            key = hash(&key);
+        }
+
+        self.key = key;
+
+        NodeId(key)
+    }
+}
+
+fn hash(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+/// Applies a [`Change`] to the state of the accessible widget with the given
+/// [`NodeId`].
+struct Apply {
+    path: Path,
+    target: NodeId,
+    change: Change,
+    is_target: bool,
+}
+
+/// A change requested by an assistive technology.
+enum Change {
+    /// Focuses the widget, unfocusing any other.
+    Focus,
+    /// Replaces the contents of the text input of the widget.
+    SetValue(String),
+}
+
+impl Operation<()> for Apply {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<()>),
+    ) {
+        let level = self.path.enter(id);
+        operate_on_children(self);
+        self.path.leave(level);
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        _node: &dyn Fn() -> accessibility::Node,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<()>),
+    ) {
+        let level = self.path.enter(id);
+        let is_target = std::mem::replace(
+            &mut self.is_target,
+            self.path.node() == self.target,
+        );
+
+        operate_on_children(self);
+
+        self.is_target = is_target;
+        self.path.leave(level);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if let Change::Focus = self.change {
+            if self.is_target {
+                state.focus();
+            } else {
+                state.unfocus();
+            }
+        }
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, _id: Option<&Id>) {
+        if let Change::SetValue(value) = &self.change {
+            if self.is_target {
+                state.set_value(value);
+            }
+        }
+    }
+}
+
+/// Collects the accessible widgets of a user interface into AccessKit nodes.
+struct Builder {
+    nodes: Vec<(NodeId, NodeBuilder)>,
+    targets: Vec<Target>,
+    path: Path,
+    parent: usize,
+    translation: Vector,
+    scrolled: Option<Vector>,
+    focus: Option<NodeId>,
+}
+
+impl Builder {
+    fn enter(&mut self) -> Vector {
+        let translation = self.translation;
+
+        // The contents of a scrollable are laid out without any scrolling
+        // applied, so we translate them while operating on them
+        if let Some(scrolled) = self.scrolled.take() {
+            self.translation = translation + scrolled;
+        }
+
+        translation
+    }
+}
+
+impl Operation<()> for Builder {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<()>),
+    ) {
+        let level = self.path.enter(id);
+        let translation = self.enter();
+
+        operate_on_children(self);
+
+        self.translation = translation;
+        self.path.leave(level);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.scrolled = Some(translation);
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        node: &dyn Fn() -> accessibility::Node,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<()>),
+    ) {
+        let level = self.path.enter(id);
+        let id = self.path.node();
+        let node = node();
+        let bounds = bounds - self.translation;
+
+        self.nodes[self.parent].1.push_child(id);
+        self.nodes.push((id, to_accesskit(&node, bounds)));
+
+        if node.is_focused {
+            self.focus = Some(id);
+        }
+
+        self.targets.push(Target { id, bounds, node });
+
+        let parent = std::mem::replace(&mut self.parent, self.nodes.len() - 1);
+        let translation = self.enter();
+
+        operate_on_children(self);
+
+        self.translation = translation;
+        self.parent = parent;
+        self.path.leave(level);
+    }
+}
+
+fn to_accesskit(node: &accessibility::Node, bounds: Rectangle) -> NodeBuilder {
+    let mut builder = NodeBuilder::new(match node.role {
+        Role::Button => accesskit::Role::Button,
+        Role::CheckBox => accesskit::Role::CheckBox,
+        Role::Switch => accesskit::Role::Switch,
+        Role::TextInput => accesskit::Role::TextInput,
+        Role::Slider => accesskit::Role::Slider,
+        Role::ComboBox => accesskit::Role::ComboBox,
+        Role::Label => accesskit::Role::Label,
+        Role::ScrollView => accesskit::Role::ScrollView,
+    });
+
+    builder.set_bounds(accesskit::Rect::new(
+        f64::from(bounds.x),
+        f64::from(bounds.y),
+        f64::from(bounds.x + bounds.width),
+        f64::from(bounds.y + bounds.height),
+    ));
+
+    if let Some(label) = &node.label {
+        builder.set_name(label.as_str());
+    }
+
+    match &node.value {
+        Some(Value::Text(text)) => {
+            builder.set_value(text.as_str());
+        }
+        Some(Value::Numeric {
+            value,
+            min,
+            max,
+            step,
+        }) => {
+            builder.set_numeric_value(*value);
+            builder.set_min_numeric_value(*min);
+            builder.set_max_numeric_value(*max);
+            builder.set_numeric_value_step(*step);
+        }
+        None => {}
+    }
+
+    if let Some(toggled) = node.toggled {
+        builder.set_toggled(if toggled {
+            accesskit::Toggled::True
+        } else {
+            accesskit::Toggled::False
+        });
+    }
+
+    if let Some(expanded) = node.expanded {
+        builder.set_expanded(expanded);
+    }
+
+    if node.is_disabled {
+        builder.set_disabled();
+    }
+
+    for action in &node.actions {
+        builder.add_action(match action {
+            accessibility::Action::Click => accesskit::Action::Default,
+            accessibility::Action::Focus => accesskit::Action::Focus,
+            accessibility::Action::SetValue => accesskit::Action::SetValue,
+            accessibility::Action::Increment => accesskit::Action::Increment,
+            accessibility::Action::Decrement => accesskit::Action::Decrement,
+            accessibility::Action::ScrollUp => accesskit::Action::ScrollUp,
+            accessibility::Action::ScrollDown => accesskit::Action::ScrollDown,
+            accessibility::Action::ScrollLeft => accesskit::Action::ScrollLeft,
+            accessibility::Action::ScrollRight => {
+                accesskit::Action::ScrollRight
+            }
+        });
+    }
+
+    builder
+}
+
+fn click() -> Vec<Event> {
+    vec![
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+    ]
+}
+
+fn scroll(x: f32, y: f32) -> Vec<Event> {
+    vec![Event::Mouse(mouse::Event::WheelScrolled {
+        delta: mouse::ScrollDelta::Pixels { x, y },
+    })]
+}
+
+fn tap(
+    key: impl Into<keyboard::Key>,
+    modifiers: keyboard::Modifiers,
+) -> Vec<Event> {
+    let key = key.into();
+
+    vec![
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: key.clone(),
+            location: keyboard::Location::Standard,
+            modifiers,
+            text: None,
+        }),
+        Event::Keyboard(keyboard::Event::KeyReleased {
+            key,
+            location: keyboard::Location::Standard,
+            modifiers,
+        }),
+    ]
+}
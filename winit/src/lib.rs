@@ -34,6 +34,9 @@ pub mod program;
 #[cfg(feature = "system")]
 pub mod system;
 
+#[cfg(feature = "accessibility")]
+pub mod accessibility;
+
 mod error;
 mod proxy;
 
@@ -1,4 +1,5 @@
 //! Create interactive, native cross-platform applications for WGPU.
+mod drag_and_drop;
 mod state;
 mod window_manager;
 
@@ -493,6 +494,9 @@ where
                                 let target =
                                     settings.platform_specific.target.clone();
 
+                                // Windows are only shown once they are
+                                // managed by the runtime, since their
+                                // accessibility adapter requires it
                                 let window = event_loop
                                     .create_window(
                                         conversion::window_attributes(
@@ -845,6 +849,9 @@ async fn run_instance<P, C>(
                                 id,
                                 ui.relayout(logical_size, &mut window.renderer),
                             );
+
+                            #[cfg(feature = "accessibility")]
+                            window.accessibility.invalidate();
                             debug.layout_finished();
 
                             debug.draw_started();
@@ -883,6 +890,21 @@ async fn run_instance<P, C>(
                                 window.state.viewport_version();
                         }
 
+                        #[cfg(feature = "accessibility")]
+                        {
+                            let ui = user_interfaces
+                                .get_mut(&id)
+                                .expect("Get user interface");
+
+                            window.accessibility.update(
+                                window.state.title(),
+                                window.state.scale_factor(),
+                                |operation| {
+                                    ui.operate(&window.renderer, operation);
+                                },
+                            );
+                        }
+
                         debug.render_started();
                         match compositor.present(
                             &mut window.renderer,
@@ -938,6 +960,11 @@ async fn run_instance<P, C>(
                             continue;
                         };
 
+                        #[cfg(feature = "accessibility")]
+                        window
+                            .accessibility
+                            .process_event(&window.raw, &window_event);
+
                         if matches!(
                             window_event,
                             winit::event::WindowEvent::CloseRequested
@@ -958,6 +985,11 @@ async fn run_instance<P, C>(
                                 &mut debug,
                             );
 
+                            let drag_and_drop = window.drag_and_drop.update(
+                                &window_event,
+                                window.state.cursor().position(),
+                            );
+
                             if let Some(event) = conversion::window_event(
                                 window_event,
                                 window.state.scale_factor(),
@@ -965,9 +997,65 @@ async fn run_instance<P, C>(
                             ) {
                                 events.push((id, event));
                             }
+
+                            if let Some(event) = drag_and_drop {
+                                events.push((id, core::Event::Window(event)));
+                            }
                         }
                     }
                     event::Event::AboutToWait => {
+                        for (id, window) in window_manager.iter_mut() {
+                            if let Some(event) = window
+                                .drag_and_drop
+                                .flush(window.state.cursor().position())
+                            {
+                                events.push((id, core::Event::Window(event)));
+                            }
+                        }
+
+                        #[cfg(feature = "accessibility")]
+                        for (id, window) in window_manager.iter_mut() {
+                            let requests = window.accessibility.requests(
+                                conversion::modifiers(window.state.modifiers()),
+                            );
+
+                            if requests.is_empty() {
+                                continue;
+                            }
+
+                            let ui = user_interfaces
+                                .get_mut(&id)
+                                .expect("Get user interface");
+
+                            for request in requests {
+                                match request {
+                                    crate::accessibility::Request::Events {
+                                        position,
+                                        events,
+                                    } => {
+                                        let _ = ui.update(
+                                            &events,
+                                            mouse::Cursor::Available(position),
+                                            &mut window.renderer,
+                                            &mut clipboard,
+                                            &mut messages,
+                                        );
+                                    }
+                                    crate::accessibility::Request::Operation(
+                                        mut operation,
+                                    ) => {
+                                        ui.operate(
+                                            &window.renderer,
+                                            operation.as_mut(),
+                                        );
+                                    }
+                                }
+                            }
+
+                            window.accessibility.invalidate();
+                            window.raw.request_redraw();
+                        }
+
                         if events.is_empty() && messages.is_empty() {
                             continue;
                         }
@@ -1004,6 +1092,9 @@ async fn run_instance<P, C>(
 
                             window.raw.request_redraw();
 
+                            #[cfg(feature = "accessibility")]
+                            window.accessibility.invalidate();
+
                             if !uis_stale {
                                 uis_stale = matches!(
                                     ui_state,
@@ -1203,6 +1294,15 @@ fn run_action<P, C>(
                     let _ = window.raw.drag_window();
                 }
             }
+            window::Action::DragAndDrop(_id, data, channel) => {
+                log::warn!(
+                    "Dragging {} data out of a window is not supported by \
+                    winit yet",
+                    data.mime_type
+                );
+
+                let _ = channel.send(false);
+            }
             window::Action::Resize(id, size) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     let _ = window.raw.request_inner_size(
@@ -1392,6 +1492,9 @@ fn run_action<P, C>(
                 for (id, ui) in interfaces.iter_mut() {
                     if let Some(window) = window_manager.get_mut(*id) {
                         ui.operate(&window.renderer, operation.as_mut());
+
+                        #[cfg(feature = "accessibility")]
+                        window.accessibility.invalidate();
                     }
                 }
 
@@ -1434,6 +1537,9 @@ where
         .filter_map(|(id, cache)| {
             let window = window_manager.get_mut(id)?;
 
+            #[cfg(feature = "accessibility")]
+            window.accessibility.invalidate();
+
             Some((
                 id,
                 build_user_interface(
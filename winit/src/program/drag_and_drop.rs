@@ -0,0 +1,134 @@
+use crate::core::drag_and_drop::Data;
+use crate::core::window;
+use crate::core::Point;
+
+use std::path::PathBuf;
+
+/// The drag-and-drop state of a window.
+///
+/// [`winit`] reports every hovered or dropped file separately; so the files
+/// of a single drop are gathered here until the event loop is about to wait,
+/// and then delivered together in a single [`window::Event::DragDropped`].
+#[derive(Debug, Default)]
+pub struct DragAndDrop {
+    is_hovered: bool,
+    dropped: Vec<PathBuf>,
+}
+
+impl DragAndDrop {
+    /// Processes a [`winit`] window event, returning the drag-and-drop
+    /// event that must be delivered right away, if any.
+    pub fn update(
+        &mut self,
+        event: &winit::event::WindowEvent,
+        position: Option<Point>,
+    ) -> Option<window::Event> {
+        use winit::event::WindowEvent;
+
+        match event {
+            WindowEvent::HoveredFile(_) if !self.is_hovered => {
+                self.is_hovered = true;
+
+                Some(window::Event::DragEntered {
+                    position,
+                    mime_types: vec![Data::URI_LIST.to_owned()],
+                })
+            }
+            WindowEvent::DroppedFile(path) => {
+                self.is_hovered = false;
+                self.dropped.push(path.clone());
+
+                None
+            }
+            WindowEvent::HoveredFileCancelled => {
+                self.is_hovered = false;
+
+                Some(window::Event::DragLeft)
+            }
+            _ => None,
+        }
+    }
+
+    /// Takes the pending drop, if any.
+    pub fn flush(&mut self, position: Option<Point>) -> Option<window::Event> {
+        if self.dropped.is_empty() {
+            return None;
+        }
+
+        Some(window::Event::DragDropped {
+            position,
+            data: Data::files(std::mem::take(&mut self.dropped)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use winit::event::WindowEvent;
+
+    #[test]
+    fn batches_dropped_files() {
+        let paths: Vec<_> = ["/a.txt", "/b.txt", "/c.txt", "/d.txt"]
+            .into_iter()
+            .map(PathBuf::from)
+            .collect();
+
+        let position = Some(Point::new(10.0, 20.0));
+        let mut drag_and_drop = DragAndDrop::default();
+
+        let hovered: Vec<_> = paths
+            .iter()
+            .filter_map(|path| {
+                drag_and_drop
+                    .update(&WindowEvent::HoveredFile(path.clone()), position)
+            })
+            .collect();
+
+        assert_eq!(
+            hovered,
+            [window::Event::DragEntered {
+                position,
+                mime_types: vec![Data::URI_LIST.to_owned()],
+            }]
+        );
+
+        for path in &paths {
+            assert_eq!(
+                drag_and_drop
+                    .update(&WindowEvent::DroppedFile(path.clone()), position),
+                None
+            );
+        }
+
+        assert_eq!(
+            drag_and_drop.flush(position),
+            Some(window::Event::DragDropped {
+                position,
+                data: Data::files(paths),
+            })
+        );
+        assert_eq!(drag_and_drop.flush(position), None);
+    }
+
+    #[test]
+    fn enters_again_after_leaving() {
+        let path = PathBuf::from("/a.txt");
+        let mut drag_and_drop = DragAndDrop::default();
+
+        for _ in 0..2 {
+            assert!(matches!(
+                drag_and_drop
+                    .update(&WindowEvent::HoveredFile(path.clone()), None),
+                Some(window::Event::DragEntered { .. })
+            ));
+            assert_eq!(
+                drag_and_drop.update(&WindowEvent::HoveredFileCancelled, None),
+                Some(window::Event::DragLeft)
+            );
+        }
+
+        assert_eq!(drag_and_drop.flush(None), None);
+    }
+}
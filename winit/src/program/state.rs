@@ -92,6 +92,11 @@ where
         self.viewport.physical_size()
     }
 
+    /// Returns the current title of the [`State`].
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
     /// Returns the logical [`Size`] of the [`Viewport`] of the [`State`].
     pub fn logical_size(&self) -> Size<f32> {
         self.viewport.logical_size()
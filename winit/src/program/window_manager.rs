@@ -2,6 +2,7 @@ use crate::core::mouse;
 use crate::core::window::Id;
 use crate::core::{Point, Size};
 use crate::graphics::Compositor;
+use crate::program::drag_and_drop::DragAndDrop;
 use crate::program::{DefaultStyle, Program, State};
 
 use std::collections::BTreeMap;
@@ -52,6 +53,9 @@ where
 
         let _ = self.aliases.insert(window.id(), id);
 
+        #[cfg(feature = "accessibility")]
+        let accessibility = crate::accessibility::Adapter::new(&window);
+
         let _ = self.entries.insert(
             id,
             Window {
@@ -62,6 +66,9 @@ where
                 surface,
                 renderer,
                 mouse_interaction: mouse::Interaction::None,
+                drag_and_drop: DragAndDrop::default(),
+                #[cfg(feature = "accessibility")]
+                accessibility,
             },
         );
 
@@ -128,8 +135,11 @@ where
     pub viewport_version: u64,
     pub exit_on_close_request: bool,
     pub mouse_interaction: mouse::Interaction,
+    pub drag_and_drop: DragAndDrop,
     pub surface: C::Surface,
     pub renderer: P::Renderer,
+    #[cfg(feature = "accessibility")]
+    pub accessibility: crate::accessibility::Adapter,
 }
 
 impl<P, C> Window<P, C>